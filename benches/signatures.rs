@@ -0,0 +1,60 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::crypto::signatures::verify_batch;
+
+fn keys(c: &mut Criterion) {
+    c.bench_function("from_seed", |b| {
+        b.iter(|| KeyPair::from_seed(black_box(&[1; 32])))
+    });
+
+    let alice = KeyPair::from_seed(&[1; 32]);
+    let bob = KeyPair::from_seed(&[2; 32]);
+    c.bench_function("diffie_hellman", |b| {
+        b.iter(|| alice.diffie_hellman(black_box(&bob.pubk)))
+    });
+}
+
+fn signatures(c: &mut Criterion) {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let message = [7u8; 256];
+    let signature = keys.fast_signature(&message);
+
+    c.bench_function("fast_signature", |b| {
+        b.iter(|| keys.fast_signature(black_box(&message)))
+    });
+    c.bench_function("sign_deterministic", |b| {
+        b.iter(|| keys.prvk.sign_deterministic(black_box(&message)))
+    });
+    c.bench_function("verify", |b| {
+        b.iter(|| keys.pubk.verify(black_box(&message), black_box(&signature)))
+    });
+    c.bench_function("verify_strict", |b| {
+        b.iter(|| {
+            keys.pubk
+                .verify_strict(black_box(&message), black_box(&signature))
+        })
+    });
+}
+
+fn batch(c: &mut Criterion) {
+    let signers: Vec<KeyPair> = (0..64u8).map(|i| KeyPair::from_seed(&[i; 32])).collect();
+    let message = [7u8; 256];
+    let items: Vec<_> = signers
+        .iter()
+        .map(|keys| (keys.pubk, &message[..], keys.fast_signature(&message)))
+        .collect();
+
+    c.bench_function("verify 64 one by one", |b| {
+        b.iter(|| {
+            items
+                .iter()
+                .all(|(key, message, signature)| key.validate_signature(message, signature))
+        })
+    });
+    c.bench_function("verify_batch 64", |b| {
+        b.iter(|| verify_batch(black_box(&items)))
+    });
+}
+
+criterion_group!(benches, keys, signatures, batch);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+#!/usr/bin/env python3
+"""Generate src/utils/extras/base.rs, the basepoint table of scalarbase.
+
+Entry j of row i is (j + 1) 256^i B in affine coordinates, stored as
+(y + x, y - x, 2dxy) modulo p in five limbs of 51 bits.
+
+    python3 scripts/base_table.py > src/utils/extras/base.rs
+"""
+
+p = 2**255 - 19
+d = -121665 * pow(121666, -1, p) % p
+
+# Basepoint of Ed25519, y = 4/5 with x even.
+by = 4 * pow(5, -1, p) % p
+bx = pow((by * by - 1) * pow(d * by * by + 1, -1, p), (p + 3) // 8, p)
+if (bx * bx - (by * by - 1) * pow(d * by * by + 1, -1, p)) % p:
+    bx = bx * pow(2, (p - 1) // 4, p) % p
+if bx % 2:
+    bx = p - bx
+
+
+def add(P, Q):
+    (x1, y1), (x2, y2) = P, Q
+    t = d * x1 * x2 * y1 * y2 % p
+    x = (x1 * y2 + y1 * x2) * pow(1 + t, -1, p) % p
+    y = (y1 * y2 + x1 * x2) * pow(1 - t, -1, p) % p
+    return x, y
+
+
+def limbs(n):
+    return "[" + ", ".join(
+        "0x%013x" % ((n >> (51 * i)) & (2**51 - 1)) for i in range(5)
+    ) + "]"
+
+
+print("// (j + 1) 256^i B for the fixed base comb of scalarbase, as (y + x, y - x, 2dxy).")
+print("// Generated by scripts/base_table.py from the basepoint coordinates, do not edit.")
+print("use super::Niels;")
+print()
+print("#[rustfmt::skip]")
+print("pub(super) static BASE_TABLE: [[Niels; 8]; 32] = [")
+
+row = (bx, by)
+for i in range(32):
+    print("    [")
+    point = row
+    for j in range(8):
+        x, y = point
+        print("        Niels {")
+        print("            y_plus_x: %s," % limbs((y + x) % p))
+        print("            y_minus_x: %s," % limbs((y - x) % p))
+        print("            xy2d: %s," % limbs(2 * d * x * y % p))
+        print("        },")
+        point = add(point, row)
+    print("    ],")
+    for _ in range(8):
+        row = add(row, row)
+print("];")
@@ -0,0 +1,81 @@
+use crate::crypto::keys::{KeyPair, PublicKey, SecretKey};
+use crate::Error;
+use zeroize::Zeroize;
+
+/// # Shared secret of a Diffie-Hellman key agreement
+///
+/// - 32 bytes of a X25519 output, wiped from memory on drop
+pub struct SharedSecret(pub(crate) [u8; 32]);
+
+impl SharedSecret {
+    /// ## Copy the secret into a byte array
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// ## Borrow the secret as a byte array
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for SharedSecret {
+    fn from(bytes: [u8; 32]) -> SharedSecret {
+        SharedSecret(bytes)
+    }
+}
+
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl SecretKey {
+    /**
+    # Diffie-Hellman
+    ## Agree a shared secret with the public key of someone else
+
+    - X25519 as specified in RFC 7748, the same key also signs messages
+    - Fails when the public key is a low order point, since every secret key
+      would agree the same all-zero secret
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let alice = KeyPair::generate();
+    let bob = KeyPair::generate();
+
+    let alice_secret = alice.prvk.diffie_hellman(&bob.pubk).unwrap();
+    let bob_secret = bob.prvk.diffie_hellman(&alice.pubk).unwrap();
+
+    assert_eq!(alice_secret.as_bytes(), bob_secret.as_bytes());
+    ```
+    */
+    pub fn diffie_hellman(&self, public_key: &PublicKey) -> Result<SharedSecret, Error> {
+        use crate::utils::extras::shared_key;
+
+        let shared = SharedSecret(shared_key(self.expose_secret(), public_key.as_bytes()));
+
+        // Accumulate without branching so the check runs in constant time.
+        if shared.0.iter().fold(0, |acc, x| acc | x) == 0 {
+            return Err(Error::LowOrderPoint);
+        }
+        Ok(shared)
+    }
+}
+
+impl KeyPair {
+    /// ## Same as [`SecretKey::diffie_hellman`] with the pair private key
+    pub fn diffie_hellman(&self, public_key: &PublicKey) -> Result<SharedSecret, Error> {
+        self.prvk.diffie_hellman(public_key)
+    }
+}
@@ -1,20 +1,331 @@
+use crate::crypto::array;
+#[cfg(feature = "alloc")]
+use crate::encoding::{base58, hex};
+use crate::utils::ct::ConstantTimeEq;
+use crate::utils::random::{CryptoRng, RngCore};
+use crate::Error;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+use zeroize::Zeroize;
+
+/// # Secret key for sign messages
+///
+/// - 32 bytes of a Curve25519 private key, wiped from memory on drop
+/// - Never printed, read it with [`SecretKey::expose_secret`] or [`SecretKey::to_base58`]
+/// - Not `AsRef<[u8]>`, so it can't reach a generic byte API without being asked for
+#[derive(Clone)]
+pub struct SecretKey([u8; 32]);
+
+/// # Public key for validate signatures
+///
+/// - 32 bytes of a Curve25519 (X25519) public key
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PublicKey([u8; 32]);
+
+/**
+# Secret key opted in for serialization
+
+- [`SecretKey`] and [`KeyPair`] do not implement `Serialize`, wrap them to store them
+- Serialized like a [`PublicKey`], a *KeyPair* as its secret key only
+
+```rust
+use ed25519_axolotl::crypto::keys::{Exposed, KeyPair};
+
+let keys = KeyPair::from_seed(&[1; 32]);
+let json = serde_json::to_string(&Exposed(keys)).unwrap();
+
+let Exposed(keys): Exposed<KeyPair> = serde_json::from_str(&json).unwrap();
+assert_eq!(KeyPair::from_seed(&[1; 32]).pubk, keys.pubk);
+```
+*/
+#[cfg(feature = "serde")]
+pub struct Exposed<T>(pub T);
+
+impl SecretKey {
+    /// ## Create a *SecretKey* from raw bytes
+    pub fn from_bytes(bytes: [u8; 32]) -> SecretKey {
+        SecretKey(bytes)
+    }
+
+    /**
+    ## Borrow the secret bytes of the key
+
+    - The only way to read the key, so every use of it stands out
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::{KeyPair, SecretKey};
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let copy = SecretKey::from_bytes(*keys.prvk.expose_secret());
+    assert_eq!(keys.prvk, copy);
+    ```
+    */
+    pub fn expose_secret(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /**
+    ## Encode the secret bytes of the key in Base58
+
+    - The text form of the key, `Display` stays redacted
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::{KeyPair, SecretKey};
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let text = keys.prvk.to_base58();
+    assert_eq!(keys.prvk, SecretKey::from_base58(&text).unwrap());
+    assert_eq!("SecretKey(<redacted>)", keys.prvk.to_string());
+    ```
+    */
+    #[cfg(feature = "alloc")]
+    pub fn to_base58(&self) -> String {
+        base58::encode(&self.0)
+    }
+
+    /// ## Decode a *SecretKey* from Base58
+    ///
+    /// - Fails on a character outside of the alphabet or when it is not 32 bytes
+    #[cfg(feature = "alloc")]
+    pub fn from_base58(text: &str) -> Result<SecretKey, Error> {
+        let mut bytes = base58::decode(text)?;
+        let secret_key = array(&bytes).map(SecretKey);
+        bytes.zeroize();
+        secret_key
+    }
+
+    /// ## Copy the key into a byte array
+    #[deprecated(note = "use `SecretKey::expose_secret`")]
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// ## Borrow the key as a byte array
+    #[deprecated(note = "use `SecretKey::expose_secret`")]
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /**
+    ## Derive the *PublicKey* of this secret key
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    assert_eq!(keys.pubk, keys.prvk.public_key());
+    ```
+    */
+    pub fn public_key(&self) -> PublicKey {
+        use crate::utils::extras::crypto_scalarmult_base;
+
+        let mut pk = [0u8; 32];
+        crypto_scalarmult_base(&mut pk, &self.0);
+
+        // Remove sign bit from public key.
+        pk[31] &= 127;
+
+        PublicKey(pk)
+    }
+}
+
+impl PublicKey {
+    /// ## Create a *PublicKey* from raw bytes
+    pub fn from_bytes(bytes: [u8; 32]) -> PublicKey {
+        PublicKey(bytes)
+    }
+
+    /**
+    ## Short name of the key
+
+    - First 8 bytes of the SHA-512 of the key, in hexadecimal
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    assert_eq!(16, keys.pubk.fingerprint().len());
+    assert_eq!(format!("KeyPair({})", keys.pubk.fingerprint()), keys.to_string());
+    ```
+    */
+    #[cfg(feature = "alloc")]
+    pub fn fingerprint(&self) -> String {
+        hex::encode(&Fingerprint::of(self).0)
+    }
+
+    /**
+    ## Encode the key in Base58, as Lunes addresses and wallets show it
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let text = keys.pubk.to_base58();
+    assert_eq!(text, keys.pubk.to_string());
+    assert_eq!(keys.pubk, text.parse::<PublicKey>().unwrap());
+    ```
+    */
+    #[cfg(feature = "alloc")]
+    pub fn to_base58(&self) -> String {
+        base58::encode(&self.0)
+    }
+
+    /// ## Decode a *PublicKey* from Base58
+    ///
+    /// - Fails on a character outside of the alphabet or when it is not 32 bytes
+    #[cfg(feature = "alloc")]
+    pub fn from_base58(text: &str) -> Result<PublicKey, Error> {
+        Ok(PublicKey(array(&base58::decode(text)?)?))
+    }
+
+    /// ## Copy the key into a byte array
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// ## Borrow the key as a byte array
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for SecretKey {
+    fn from(bytes: [u8; 32]) -> SecretKey {
+        SecretKey(bytes)
+    }
+}
+
+impl From<[u8; 32]> for PublicKey {
+    fn from(bytes: [u8; 32]) -> PublicKey {
+        PublicKey(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for SecretKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<SecretKey, Error> {
+        Ok(SecretKey(array(bytes)?))
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<PublicKey, Error> {
+        Ok(PublicKey(array(bytes)?))
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &SecretKey) -> bool {
+        self.0[..].ct_eq(&other.0[..]).into()
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
+}
+
+impl fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_base58())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for SecretKey {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<SecretKey, Error> {
+        SecretKey::from_base58(text)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for PublicKey {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<PublicKey, Error> {
+        PublicKey::from_base58(text)
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 /// # Keys for sign and validate signature
 pub struct KeyPair {
     /// ## private keys for sign messages
-    pub prvk: Vec<u32>,
+    pub prvk: SecretKey,
     /// ## public keys for validate messages
-    pub pubk: Vec<u32>,
+    pub pubk: PublicKey,
+}
+
+/// Same as [`PublicKey::fingerprint`], written without allocating
+struct Fingerprint([u8; 8]);
+
+impl Fingerprint {
+    fn of(public_key: &PublicKey) -> Fingerprint {
+        use crate::hash::Sha512;
+
+        let mut fingerprint = [0u8; 8];
+        fingerprint.copy_from_slice(&Sha512::digest(&public_key.0)[..8]);
+        Fingerprint(fingerprint)
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+impl fmt::Debug for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+/// Shows the fingerprint of the public key only
+impl fmt::Display for KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KeyPair({})", Fingerprint::of(&self.pubk))
+    }
 }
 
-impl std::fmt::Display for KeyPair {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(
-            f,
-            "\x1b[1mKeyPair {{\x1b[0m
-                \r\x1b[91mprivate_key: {:?}\x1b[0m
-            \n\x1b[92mpublic_key: {:?}\n\x1b[0m\x1b[1m}}\x1b[0m",
-            self.prvk, self.pubk
-        )
+/// Shows the fingerprint of the public key only
+impl fmt::Debug for KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("KeyPair")
+            .field("prvk", &self.prvk)
+            .field("fingerprint", &Fingerprint::of(&self.pubk))
+            .finish()
     }
 }
 
@@ -24,44 +335,119 @@ impl KeyPair {
     ```rust
     use ed25519_axolotl::crypto::keys::KeyPair;
 
-    let keys = KeyPair::new(None);
+    let keys = KeyPair::generate();
     println!("{}", keys);
     ```
-    ## Generate *KeyPair* from existent *Seed*
+    */
+    #[cfg(feature = "getrandom")]
+    pub fn generate() -> KeyPair {
+        use crate::utils::random::OsRng;
+
+        KeyPair::generate_with(&mut OsRng)
+    }
+
+    /**
+    ## Generate new *KeyPair* with your own random generator
     ```rust
     use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::utils::random::OsRng;
 
-    let keys = KeyPair::new(Some([1;32].to_vec()));
+    let keys = KeyPair::generate_with(&mut OsRng);
     println!("{}", keys);
     ```
     */
-    pub fn new(seed: Option<Vec<u32>>) -> KeyPair {
-        use crate::utils::extras::crypto_scalarmult_base;
-        use crate::utils::random::random_bytes;
-
-        let seed = match seed {
-            Some(vec) => vec,
-            None => random_bytes(32),
-        };
-        let mut sk: Vec<u32> = vec![0; 32];
-        let mut pk: Vec<u32> = vec![0; 32];
-        for i in 0..32 {
-            sk[i] = seed.to_vec()[i];
-        }
+    pub fn generate_with<R: CryptoRng + RngCore>(rng: &mut R) -> KeyPair {
+        use crate::utils::random::random_array_with;
 
-        crypto_scalarmult_base(&mut pk, &sk);
+        KeyPair::from_seed(&random_array_with(rng))
+    }
+
+    /**
+    ## Generate *KeyPair* from existent *Seed*
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    assert_eq!(keys.prvk.expose_secret()[31], 65);
+    ```
+    */
+    pub fn from_seed(seed: &[u8; 32]) -> KeyPair {
+        let mut sk = *seed;
 
         // Turn secret key into the correct format.
-        sk[0] = sk[0] & 248;
-        sk[31] = sk[31] & 127;
-        sk[31] = sk[31] | 64;
+        sk[0] &= 248;
+        sk[31] &= 127;
+        sk[31] |= 64;
 
-        // Remove sign bit from public key.
-        pk[31] = pk[31] & 127;
+        let prvk = SecretKey(sk);
+        let pubk = prvk.public_key();
+        sk.zeroize();
+
+        KeyPair { prvk, pubk }
+    }
+
+    /**
+    ## Generate *KeyPair* from a *Seed* slice
+
+    - Fails when the seed does not have 32 bytes
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::Error;
+
+    assert!(KeyPair::try_from_seed(&[1; 32]).is_ok());
+    assert_eq!(
+        Some(Error::InvalidLength { expected: 32, actual: 16 }),
+        KeyPair::try_from_seed(&[1; 16]).err()
+    );
+    ```
+    */
+    pub fn try_from_seed(seed: &[u8]) -> Result<KeyPair, Error> {
+        Ok(KeyPair::from_seed(&array(seed)?))
+    }
+
+    /**
+    ## Generate *KeyPair* from a legacy `Vec<u32>` *Seed*
+
+    - Kept for compatibility, use [`KeyPair::generate`] or [`KeyPair::from_seed`]
+    - Panics on a malformed seed, see [`KeyPair::try_new`]
+    */
+    #[deprecated(note = "use `KeyPair::generate` or `KeyPair::from_seed`")]
+    #[allow(deprecated)]
+    #[cfg(feature = "alloc")]
+    pub fn new(seed: Option<Vec<u32>>) -> KeyPair {
+        match KeyPair::try_new(seed) {
+            Ok(keys) => keys,
+            Err(error) => panic!("invalid seed: {error}"),
+        }
+    }
+
+    /**
+    ## Generate *KeyPair* from a legacy `Vec<u32>` *Seed*
+
+    - Kept for compatibility, use [`KeyPair::generate`] or [`KeyPair::try_from_seed`]
+    - Like the legacy function only the first 32 bytes of a longer seed are used
+    - Fails when the seed has less than 32 bytes or they are not in the range 0 to 255
+    - Without a seed a new one is generated, without the `getrandom` feature
+      that fails with `InvalidLength`
+    */
+    #[deprecated(note = "use `KeyPair::generate` or `KeyPair::try_from_seed`")]
+    #[cfg(feature = "alloc")]
+    pub fn try_new(seed: Option<Vec<u32>>) -> Result<KeyPair, Error> {
+        use crate::crypto::narrow;
 
-        KeyPair {
-            prvk: sk.clone(),
-            pubk: pk.clone(),
+        match seed {
+            Some(vec) if vec.len() >= 32 => KeyPair::try_from_seed(&narrow(&vec[..32])?),
+            Some(vec) => Err(Error::InvalidLength {
+                expected: 32,
+                actual: vec.len(),
+            }),
+            #[cfg(feature = "getrandom")]
+            None => Ok(KeyPair::generate()),
+            #[cfg(not(feature = "getrandom"))]
+            None => Err(Error::InvalidLength {
+                expected: 32,
+                actual: 0,
+            }),
         }
     }
 }
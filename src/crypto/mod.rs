@@ -1,4 +1,44 @@
+/// Agree shared secrets between key pairs
+pub mod agreement;
 /// Create struct KeyPair
 pub mod keys;
+/// Serde support of keys and signatures
+#[cfg(feature = "serde")]
+mod serialize;
 /// Sign and validate messages
 pub mod signatures;
+/// XEdDSA and VXEdDSA signatures of Signal
+pub mod xeddsa;
+
+use crate::Error;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Copy exactly `N` bytes into an array
+pub(crate) fn array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], Error> {
+    <[u8; N]>::try_from(bytes).map_err(|_| Error::InvalidLength {
+        expected: N,
+        actual: bytes.len(),
+    })
+}
+
+/// Narrow a legacy `Vec<u32>` byte vector into real bytes
+#[cfg(feature = "alloc")]
+pub(crate) fn narrow(bytes: &[u32]) -> Result<Vec<u8>, Error> {
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            u8::try_from(*value).map_err(|_| Error::ByteOutOfRange {
+                index,
+                value: *value,
+            })
+        })
+        .collect()
+}
+
+/// Widen bytes back into a legacy `Vec<u32>` byte vector
+#[cfg(feature = "alloc")]
+pub(crate) fn widen(bytes: &[u8]) -> Vec<u32> {
+    bytes.iter().map(|x| *x as u32).collect()
+}
@@ -0,0 +1,185 @@
+use crate::crypto::keys::{Exposed, KeyPair, PublicKey, SecretKey};
+use crate::crypto::signatures::{Signature, SignedMessage};
+use crate::encoding::base58;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+// Base58 in human readable formats, as Display and FromStr, raw bytes otherwise.
+
+/// Base58 string or tuple of `N` bytes
+fn serialize_array<S: Serializer, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let mut text = base58::encode(bytes);
+        let result = serializer.serialize_str(&text);
+        text.zeroize();
+        return result;
+    }
+
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for b in bytes {
+        tuple.serialize_element(b)?;
+    }
+    tuple.end()
+}
+
+struct ArrayVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for ArrayVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{N} bytes or their Base58 string")
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<[u8; N], E> {
+        let mut bytes =
+            base58::decode(text).map_err(|_| E::invalid_value(de::Unexpected::Str(text), &self))?;
+        let array = self.visit_bytes(&bytes);
+        bytes.zeroize();
+        array
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<[u8; N], E> {
+        <[u8; N]>::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u8; N], A::Error> {
+        let mut array = [0u8; N];
+        for (i, b) in array.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(array)
+    }
+}
+
+fn deserialize_array<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    match deserializer.is_human_readable() {
+        true => deserializer.deserialize_str(ArrayVisitor),
+        false => deserializer.deserialize_tuple(N, ArrayVisitor),
+    }
+}
+
+impl Serialize for PublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(self.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PublicKey, D::Error> {
+        deserialize_array(deserializer).map(PublicKey::from_bytes)
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(self.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Signature, D::Error> {
+        deserialize_array(deserializer).map(Signature::from_bytes)
+    }
+}
+
+/// Base58 string or bytes, of at least 64 bytes
+impl Serialize for SignedMessage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.serialize_str(&base58::encode(self.as_bytes())),
+            false => serializer.serialize_bytes(self.as_bytes()),
+        }
+    }
+}
+
+struct SignedMessageVisitor;
+
+impl<'de> Visitor<'de> for SignedMessageVisitor {
+    type Value = SignedMessage;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("64 bytes or more, or their Base58 string")
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<SignedMessage, E> {
+        let bytes =
+            base58::decode(text).map_err(|_| E::invalid_value(de::Unexpected::Str(text), &self))?;
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<SignedMessage, E> {
+        SignedMessage::from_bytes(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<SignedMessage, A::Error> {
+        // The hint comes from the input, trust it no further than serde's cautious 1 MiB.
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(64).min(1 << 20));
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignedMessage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<SignedMessage, D::Error> {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_str(SignedMessageVisitor),
+            false => deserializer.deserialize_byte_buf(SignedMessageVisitor),
+        }
+    }
+}
+
+impl Serialize for Exposed<SecretKey> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(self.0.expose_secret(), serializer)
+    }
+}
+
+impl Serialize for Exposed<&SecretKey> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(self.0.expose_secret(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Exposed<SecretKey> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Exposed<SecretKey>, D::Error> {
+        let mut bytes = deserialize_array(deserializer)?;
+        let secret_key = SecretKey::from_bytes(bytes);
+        bytes.zeroize();
+        Ok(Exposed(secret_key))
+    }
+}
+
+impl Serialize for Exposed<KeyPair> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(self.0.prvk.expose_secret(), serializer)
+    }
+}
+
+impl Serialize for Exposed<&KeyPair> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(self.0.prvk.expose_secret(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Exposed<KeyPair> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Exposed<KeyPair>, D::Error> {
+        let mut bytes = deserialize_array(deserializer)?;
+        let keys = KeyPair::from_seed(&bytes);
+        bytes.zeroize();
+        Ok(Exposed(keys))
+    }
+}
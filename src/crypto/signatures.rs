@@ -1,196 +1,855 @@
-/**
-# Full Signature
-## Sign a message with your private key
+use crate::crypto::array;
+use crate::crypto::keys::{KeyPair, PublicKey, SecretKey};
+#[cfg(feature = "alloc")]
+use crate::crypto::{narrow, widen};
+#[cfg(feature = "alloc")]
+use crate::encoding::base58;
+use crate::utils::random::{CryptoRng, RngCore};
+#[cfg(feature = "alloc")]
+use crate::BatchError;
+use crate::Error;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+/// # Signature of a message
+///
+/// - 64 bytes produced by *fast_signature*
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Signature([u8; 64]);
+
+/// # Signed message
+///
+/// - 64 bytes of signature followed by the message, produced by *full_signature*
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SignedMessage(Vec<u8>);
+
+impl Signature {
+    /// ## Create a *Signature* from raw bytes
+    pub fn from_bytes(bytes: [u8; 64]) -> Signature {
+        Signature(bytes)
+    }
 
-- Receive a message like bytes and return a signature like bytes
-- It is possible to decode the signature back to the message
-- (64 + message length) byte signature
-- Slow to sign and verify
+    /// ## Copy the signature into a byte array
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.0
+    }
 
-## Example
+    /// ## Borrow the signature as a byte array
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        &self.0
+    }
 
-```rust
-use ed25519_axolotl::crypto::signatures::validate_signature;
-use ed25519_axolotl::crypto::signatures::full_signature;
-use ed25519_axolotl::utils::random::random_bytes;
-use ed25519_axolotl::crypto::keys::KeyPair;
+    /**
+    ## Encode the signature in Base58
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::Signature;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.sign_deterministic(b"Lunes");
+    let text = signature.to_base58();
+    assert_eq!(text, signature.to_string());
+    assert_eq!(signature, text.parse::<Signature>().unwrap());
+    ```
+    */
+    #[cfg(feature = "alloc")]
+    pub fn to_base58(&self) -> String {
+        base58::encode(&self.0)
+    }
 
-let keys = KeyPair::new(Some(vec![1; 32]));
-let msg = "Lunes"
-    .as_bytes()
-    .iter()
-    .map(|x| *x as u32)
-    .collect::<Vec<u32>>();
-let signature = full_signature(keys.prvk, msg.clone(), Some(random_bytes(64)));
+    /// ## Decode a *Signature* from Base58
+    ///
+    /// - Fails on a character outside of the alphabet or when it is not 64 bytes
+    #[cfg(feature = "alloc")]
+    pub fn from_base58(text: &str) -> Result<Signature, Error> {
+        Ok(Signature(array(&base58::decode(text)?)?))
+    }
+}
 
-assert_eq!(true, validate_signature(keys.pubk, msg, signature));
-```
-*/
-pub fn full_signature(
-    secret_key: Vec<u32>,
-    message: Vec<u32>,
-    opt_random: Option<Vec<u32>>,
-) -> Vec<u32> {
+#[cfg(feature = "alloc")]
+impl SignedMessage {
+    /// ## Create a *SignedMessage* from raw bytes
+    ///
+    /// - Fails when there are less than 64 bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<SignedMessage, Error> {
+        if bytes.len() < 64 {
+            return Err(Error::InvalidLength {
+                expected: 64,
+                actual: bytes.len(),
+            });
+        }
+        Ok(SignedMessage(bytes.to_vec()))
+    }
+
+    /// ## Copy the signed message into a byte vector
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+
+    /// ## Borrow the signed message as bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// ## The 64 byte signature heading the message
+    pub fn signature(&self) -> Signature {
+        let mut signature = [0u8; 64];
+        signature.copy_from_slice(&self.0[..64]);
+        Signature(signature)
+    }
+
+    /// ## The message carried after the signature
+    pub fn message(&self) -> &[u8] {
+        &self.0[64..]
+    }
+}
+
+impl From<[u8; 64]> for Signature {
+    fn from(bytes: [u8; 64]) -> Signature {
+        Signature(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Signature, Error> {
+        Ok(Signature(array(bytes)?))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&[u8]> for SignedMessage {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<SignedMessage, Error> {
+        SignedMessage::from_bytes(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_base58())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for Signature {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Signature, Error> {
+        Signature::from_base58(text)
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for SignedMessage {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Sign `message` with `random` as nonce suffix, without copying the message
+///
+/// - An empty `random` signs deterministically
+fn sign(secret_key: &[u8], message: &[u8], random: &[u8]) -> Signature {
+    use crate::utils::extras::curve25519_sign_detached;
+
+    let mut signature = [0u8; 64];
+    curve25519_sign_detached(&mut signature, message, secret_key, random);
+    Signature(signature)
+}
+
+/// Sign `message` with `random` as nonce suffix, returning signature and message
+#[cfg(feature = "alloc")]
+fn sign_full(secret_key: &[u8], message: &[u8], random: &[u8]) -> Vec<u8> {
     use crate::utils::extras::curve25519_sign;
-    use crate::utils::random::random_bytes;
 
-    match opt_random {
-        Some(random) => {
-            let mut buf: Vec<u32> = vec![0; 128 + message.len()];
-            curve25519_sign(&mut buf, message.clone(), secret_key, random);
-            (buf[0..64 + message.len()]).to_vec()
-        }
-        None => {
-            let mut signed_msg: Vec<u32> = vec![0; 64 + message.len()];
-            curve25519_sign(&mut signed_msg, message, secret_key, random_bytes(64));
-            signed_msg
-        }
+    let mut buf: Vec<u8> = vec![0; 64 + message.len()];
+    curve25519_sign(&mut buf, message, secret_key, random);
+    buf
+}
+
+/// Open a signed message, returning the message when the signature is valid
+#[cfg(feature = "alloc")]
+fn open(public_key: &[u8], signed_msg: &[u8]) -> Result<Vec<u8>, Error> {
+    use crate::utils::extras::curve25519_sign_open;
+
+    let mut message: Vec<u8> = vec![0; signed_msg.len().saturating_sub(64)];
+    curve25519_sign_open(&mut message, signed_msg, public_key)?;
+    Ok(message)
+}
+
+impl SecretKey {
+    /**
+    # Full Signature
+    ## Sign a message with your private key
+
+    - Receive a message like bytes and return a signed message
+    - It is possible to decode the signature back to the message
+    - (64 + message length) byte signature
+    - Slow to sign and verify
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signed_msg = keys.prvk.full_signature(b"Lunes");
+
+    assert_eq!(Ok(b"Lunes".to_vec()), keys.pubk.decode_signature(&signed_msg));
+    ```
+    */
+    #[cfg(all(feature = "alloc", feature = "getrandom"))]
+    pub fn full_signature(&self, message: &[u8]) -> SignedMessage {
+        use crate::utils::random::OsRng;
+
+        self.full_signature_with_rng(message, &mut OsRng)
+    }
+
+    /**
+    # Full Signature
+    ## Sign a message with your private key and random generator
+
+    - Same as [`SecretKey::full_signature`], with the 64 random bytes of the
+      nonce taken from *`rng`*
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::utils::random::OsRng;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signed_msg = keys.prvk.full_signature_with_rng(b"Lunes", &mut OsRng);
+
+    assert_eq!(Ok(b"Lunes".to_vec()), keys.pubk.decode_signature(&signed_msg));
+    ```
+    */
+    #[cfg(feature = "alloc")]
+    pub fn full_signature_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        message: &[u8],
+        rng: &mut R,
+    ) -> SignedMessage {
+        use crate::utils::random::random_array_with;
+
+        let random: [u8; 64] = random_array_with(rng);
+        SignedMessage(sign_full(self.expose_secret(), message, &random))
+    }
+
+    /**
+    # Fast Signature
+    ## Sign a message with your private key
+
+    - Receive a message like bytes and return a signature
+    - Don't possible to decode signature back to message
+    - Quick to sign and verify
+    - 64 byte signature
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.prvk.fast_signature(b"Lunes");
+
+    assert!(keys.pubk.validate_signature(b"Lunes", &signature));
+    ```
+    */
+    #[cfg(feature = "getrandom")]
+    pub fn fast_signature(&self, message: &[u8]) -> Signature {
+        use crate::utils::random::OsRng;
+
+        self.fast_signature_with_rng(message, &mut OsRng)
+    }
+
+    /**
+    # Fast Signature
+    ## Sign a message with your private key and random generator
+
+    - Same as [`SecretKey::fast_signature`], with the 64 random bytes of the
+      nonce taken from *`rng`*
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::utils::random::OsRng;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.prvk.fast_signature_with_rng(b"Lunes", &mut OsRng);
+
+    assert!(keys.pubk.validate_signature(b"Lunes", &signature));
+    ```
+    */
+    pub fn fast_signature_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Signature {
+        use crate::utils::random::random_array_with;
+
+        let random: [u8; 64] = random_array_with(rng);
+        sign(self.expose_secret(), message, &random)
+    }
+
+    /**
+    # Deterministic Signature
+    ## Sign a message with your private key and no random nonce
+
+    - The nonce is derived from the private key and the message only, so the
+      same key and message always give the same 64 byte signature
+    - Useful for golden-file tests and for environments without a
+      trustworthy random generator
+    - Validated like any other signature
+    - The nonce is SHA-512 of the clamped private scalar followed by the message, as
+      `sign(secretKey, message)` of curve25519-js without `opt_random`, not the
+      RFC 8032 nonce, so RFC 8032 vectors do not apply
+
+    ## Known answers
+
+    Generated with this function and reproduced by a separate Python model of the
+    curve25519-js signing above; the third private key and its public key are those
+    of Alice in RFC 7748. `tests/crypto/signatures.rs` checks all of them.
+
+    | private key | message | signature |
+    |---|---|---|
+    | `01` × 32 | empty | `ee6fd8fdaa4515d5e03b58eef1bbf783cb63afa33454506d6d868866dba9d7a301c1851e0c03da401d31feab88898992ef1e1dbb0990d39c7c9af32cb1b54503` |
+    | `02` × 32 | `Lunes` | `dca408aa6a6504b4fea3f40c3736dbe39b3299af82a089997401320a7f7e8bb4d88be5c7b6385d846e8f8eca0fe078c80dbe52004ce2d7621a84439ee06a1203` |
+    | `77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a` | `abc` | `3efc66f3ff33b3eada1835585bb9e8367666bfb4bccb5d79ff02f9694c6ca0373bf276640c558ec4502e19960a9766d8e280a345c1635bb9f84760d96231478c` |
+    | `42` × 32 | bytes 0 to 255 | `7c862ea797ce51b5ea2c592179c03360bb5c4642210401fdafb0640d6826a5e2d6486508c2fc96efa470416c3800c8f7d3c46266bacb9ef300c96403d7560503` |
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::encoding::hex;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.prvk.sign_deterministic(b"");
+    assert_eq!(
+        "ee6fd8fdaa4515d5e03b58eef1bbf783cb63afa33454506d6d868866dba9d7a3",
+        hex::encode(&signature.as_bytes()[..32])
+    );
+
+    let signature = keys.prvk.sign_deterministic(b"Lunes");
+
+    assert_eq!(signature, keys.prvk.sign_deterministic(b"Lunes"));
+    assert!(keys.pubk.validate_signature(b"Lunes", &signature));
+    ```
+    */
+    pub fn sign_deterministic(&self, message: &[u8]) -> Signature {
+        sign(self.expose_secret(), message, &[])
     }
 }
 
-/**
-# Fast Signature
-## Sign a message with your private key
+impl KeyPair {
+    /// ## Same as [`SecretKey::full_signature`] with the pair private key
+    #[cfg(all(feature = "alloc", feature = "getrandom"))]
+    pub fn full_signature(&self, message: &[u8]) -> SignedMessage {
+        self.prvk.full_signature(message)
+    }
+
+    /// ## Same as [`SecretKey::fast_signature`] with the pair private key
+    #[cfg(feature = "getrandom")]
+    pub fn fast_signature(&self, message: &[u8]) -> Signature {
+        self.prvk.fast_signature(message)
+    }
+
+    /// ## Same as [`SecretKey::sign_deterministic`] with the pair private key
+    pub fn sign_deterministic(&self, message: &[u8]) -> Signature {
+        self.prvk.sign_deterministic(message)
+    }
+
+    /// ## Same as [`SecretKey::full_signature_with_rng`] with the pair private key
+    #[cfg(feature = "alloc")]
+    pub fn full_signature_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        message: &[u8],
+        rng: &mut R,
+    ) -> SignedMessage {
+        self.prvk.full_signature_with_rng(message, rng)
+    }
+
+    /// ## Same as [`SecretKey::fast_signature_with_rng`] with the pair private key
+    pub fn fast_signature_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Signature {
+        self.prvk.fast_signature_with_rng(message, rng)
+    }
+}
+
+impl PublicKey {
+    /**
+    # Verify
+    ## Verify a signature with a message and your public key
+
+    - Receive a message and signature and return the reason of a failure
+    - Permissive, as every previous release: accepts any `R || S` with `[S]B = R + [h]A`
+    - Accepts a scalar `S` above the group order, so `S + L` is a second valid signature
+    - Accepts a public key with the top bit set or a `u` above p, read modulo p
+    - Accepts low order public keys and `R`, for which one signature can hold for many messages
+    - Use [`PublicKey::verify_strict`] when signatures must be unique, like transaction ids
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.fast_signature(b"Lunes");
+
+    assert_eq!(Ok(()), keys.pubk.verify(b"Lunes", &signature));
+    assert_eq!(Err(Error::InvalidSignature), keys.pubk.verify(b"Lunis", &signature));
+    ```
+    */
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), Error> {
+        use crate::utils::extras::curve25519_verify;
+
+        curve25519_verify(message, signature.as_bytes(), self.as_bytes())
+    }
+
+    /**
+    # Verify Strict
+    ## Verify a signature, rejecting every input that makes it malleable
+
+    - Accepts exactly what [`PublicKey::verify`] accepts, except:
+    - A public key `u` with the top bit set or not below p - 1: [`Error::InvalidPublicKey`]
+    - A scalar `S`, without the sign bit of the key, not below the group order: [`Error::NonCanonicalScalar`]
+    - An `R` with `y` not below p: [`Error::InvalidSignature`]
+    - A low order public key: [`Error::LowOrderPoint`]
+    - A low order `R`: [`Error::InvalidSignature`]
+    - Signatures from this crate always pass both verifiers
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::Signature;
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.fast_signature(b"Lunes");
+    assert_eq!(Ok(()), keys.pubk.verify_strict(b"Lunes", &signature));
+
+    // Add the group order to S
+    let order = [
+        0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+        0xde, 0x14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+    ];
+    let mut bytes = signature.to_bytes();
+    let mut carry = 0;
+    for i in 0..32 {
+        let sum = bytes[32 + i] as u16 + order[i] + carry;
+        bytes[32 + i] = sum as u8;
+        carry = sum >> 8;
+    }
+    let malleated = Signature::from_bytes(bytes);
+
+    assert_eq!(Ok(()), keys.pubk.verify(b"Lunes", &malleated));
+    assert_eq!(
+        Err(Error::NonCanonicalScalar),
+        keys.pubk.verify_strict(b"Lunes", &malleated)
+    );
+    ```
+    */
+    pub fn verify_strict(&self, message: &[u8], signature: &Signature) -> Result<(), Error> {
+        use crate::utils::extras::curve25519_verify_strict;
+
+        curve25519_verify_strict(message, signature.as_bytes(), self.as_bytes())
+    }
 
-- Receive a message like bytes and return a signature like bytes
-- Don't possible to decode signature back to message
-- Quick to sign and verify
-- 64 byte signature
+    /**
+    # Validate Signature
+    ## Validate a signature with a message and your public key
 
-# Example
+    - Receive a message and signature and return bool
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.fast_signature(b"Lunes");
+
+    assert!(keys.pubk.validate_signature(b"Lunes", &signature));
+    assert!(!keys.pubk.validate_signature(b"Lunis", &signature));
+    ```
+    */
+    pub fn validate_signature(&self, message: &[u8], signature: &Signature) -> bool {
+        self.verify(message, signature).is_ok()
+    }
+
+    /**
+    # Validate Signature Strict
+    ## Validate a signature with [`PublicKey::verify_strict`]
+
+    - Receive a message and signature and return bool
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.fast_signature(b"Lunes");
+
+    assert!(keys.pubk.validate_signature_strict(b"Lunes", &signature));
+    assert!(!keys.pubk.validate_signature_strict(b"Lunis", &signature));
+    ```
+    */
+    pub fn validate_signature_strict(&self, message: &[u8], signature: &Signature) -> bool {
+        self.verify_strict(message, signature).is_ok()
+    }
+
+    /**
+    # Decode Signature
+    ## Decoded a signed message using your public key
+
+    - Receive a signed message and return the message decoded
+    - Possible only for *full_signature* function
+    - Fails when the signature is not valid
+
+    ## Example
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signed_msg = keys.full_signature(b"Lunes");
+
+    assert_eq!(Ok(b"Lunes".to_vec()), keys.pubk.decode_signature(&signed_msg));
+    ```
+    */
+    #[cfg(feature = "alloc")]
+    pub fn decode_signature(&self, signed_msg: &SignedMessage) -> Result<Vec<u8>, Error> {
+        open(self.as_bytes(), signed_msg.as_bytes())
+    }
+}
+
+/**
+# Verify Batch
+## Verify many signatures at once, like the transactions of a block
+
+- Every entry passes the checks of [`PublicKey::verify_strict`] first
+- Then a random linear combination of all the equations is checked with a single
+  multi-scalar multiplication, with one inversion shared by all the public keys
+- About 2.4 times as fast as one [`PublicKey::verify`] each for 64 signatures, see the
+  benchmarks: every `R` still costs a square root to decompress
+- When it fails, each entry is checked alone to list every failing index in [`BatchError`]
+- The equations are multiplied by the cofactor 8, unlike [`PublicKey::verify`] and
+  [`PublicKey::verify_strict`]: an `R` with a low order component added by the signer is
+  accepted here and rejected by both, so a validator must not mix them on the same entries
+- Signatures made by this crate are accepted by all three; a cofactorless combination
+  would instead let such an `R` pass one batch in 8 or more
+
+## Example
 
 ```rust
-use ed25519_axolotl::crypto::signatures::validate_signature;
-use ed25519_axolotl::crypto::signatures::fast_signature;
-use ed25519_axolotl::utils::random::random_bytes;
 use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::crypto::signatures::verify_batch;
+
+let alice = KeyPair::from_seed(&[1; 32]);
+let bob = KeyPair::from_seed(&[2; 32]);
 
-let keys = KeyPair::new(Some(vec![1; 32]));
-let msg = "Lunes"
-    .as_bytes()
-    .iter()
-    .map(|x| *x as u32)
-    .collect::<Vec<u32>>();
-let signature = fast_signature(keys.prvk, msg.clone(), Some(random_bytes(64)));
+let batch = [
+    (alice.pubk, &b"Lunes"[..], alice.fast_signature(b"Lunes")),
+    (bob.pubk, &b"Lunes"[..], bob.fast_signature(b"Lunes")),
+    (bob.pubk, &b"Lunis"[..], alice.fast_signature(b"Lunis")),
+];
 
-assert_eq!(true, validate_signature(keys.pubk, msg, signature));
+assert_eq!(Ok(()), verify_batch(&batch[..2]));
+assert_eq!(vec![2], verify_batch(&batch).unwrap_err().indices());
 ```
 */
-pub fn fast_signature(
-    secret_key: Vec<u32>,
-    message: Vec<u32>,
-    opt_random: Option<Vec<u32>>,
-) -> Vec<u32> {
-    use crate::utils::extras::curve25519_sign;
-    use crate::utils::random::random_bytes;
-
-    match opt_random {
-        Some(random) => {
-            let mut buf: Vec<u32> = vec![0; 128 + message.len()];
-            curve25519_sign(&mut buf, message, secret_key, random);
-
-            let mut signature: Vec<u32> = vec![0; 64];
-            for i in 0..signature.len() {
-                signature[i] = buf[i];
-            }
-            signature
-        }
-        None => {
-            let mut buf: Vec<u32> = vec![0; 64 + message.len()];
-            curve25519_sign(&mut buf, message, secret_key, random_bytes(64));
-
-            let mut signature: Vec<u32> = vec![0; 64];
-            for i in 0..signature.len() {
-                signature[i] = buf[i];
-            }
-            signature
-        }
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+pub fn verify_batch(items: &[(PublicKey, &[u8], Signature)]) -> Result<(), BatchError> {
+    use crate::utils::random::OsRng;
+
+    verify_batch_with(items, &mut OsRng)
+}
+
+/// ## Same as [`verify_batch`], with the 128 bit coefficients taken from *`rng`*
+#[cfg(feature = "alloc")]
+pub fn verify_batch_with<R: CryptoRng + RngCore>(
+    items: &[(PublicKey, &[u8], Signature)],
+    rng: &mut R,
+) -> Result<(), BatchError> {
+    use crate::utils::extras::curve25519_verify_batch;
+
+    let mut z = vec![0u8; 16 * items.len()];
+    rng.fill_bytes(&mut z);
+
+    let entries: Vec<(&[u8], &[u8], &[u8])> = items
+        .iter()
+        .map(|(key, message, signature)| (*message, &signature.as_bytes()[..], &key.as_bytes()[..]))
+        .collect();
+
+    let failures = curve25519_verify_batch(&entries, &z);
+    match failures.is_empty() {
+        true => Ok(()),
+        false => Err(BatchError { failures }),
     }
 }
 
-/**
-# Validate Signature
-## Validate a signature with a message and your public key
+impl From<Signature> for [u8; 64] {
+    fn from(signature: Signature) -> [u8; 64] {
+        signature.0
+    }
+}
 
-- Receive a public key, message, signature end return bool
+impl signature::SignatureEncoding for Signature {
+    type Repr = [u8; 64];
+}
 
-## Example
+/**
+# Signer
+## Sign with [`SecretKey::sign_deterministic`] through the `signature` traits
 
 ```rust
-use ed25519_axolotl::crypto::signatures::validate_signature;
-use ed25519_axolotl::crypto::signatures::fast_signature;
-use ed25519_axolotl::utils::random::random_bytes;
 use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::signature::{Keypair, Signer, Verifier};
 
-let keys = KeyPair::new(Some(vec![1; 32]));
-let msg = "Lunes"
-    .as_bytes()
-    .iter()
-    .map(|x| *x as u32)
-    .collect::<Vec<u32>>();
-let signature = fast_signature(keys.prvk, msg.clone(), Some(random_bytes(64)));
+fn roundtrip<S: Signer<T> + Keypair<VerifyingKey = V>, V: Verifier<T>, T>(keys: &S) -> bool {
+    let signature = keys.sign(b"Lunes");
+    keys.verifying_key().verify(b"Lunes", &signature).is_ok()
+}
 
-assert_eq!(true, validate_signature(keys.pubk, msg, signature));
+assert!(roundtrip(&KeyPair::from_seed(&[1; 32])));
 ```
 */
-pub fn validate_signature(public_key: Vec<u32>, message: Vec<u32>, signature: Vec<u32>) -> bool {
-    use crate::utils::extras::curve25519_sign_open;
+impl signature::Signer<Signature> for SecretKey {
+    fn try_sign(&self, message: &[u8]) -> Result<Signature, signature::Error> {
+        Ok(self.sign_deterministic(message))
+    }
+}
 
-    let mut sm: Vec<u32> = vec![0; 64 + message.len()];
-    let mut m: Vec<u32> = vec![0; 64 + message.len()];
+/// Same as the [`Signer`](signature::Signer) of the pair private key
+impl signature::Signer<Signature> for KeyPair {
+    fn try_sign(&self, message: &[u8]) -> Result<Signature, signature::Error> {
+        Ok(self.sign_deterministic(message))
+    }
+}
 
-    for i in 0..64 {
-        sm[i] = signature[i];
+/// Signs with [`SecretKey::fast_signature_with_rng`]
+impl signature::RandomizedSigner<Signature> for SecretKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl signature::rand_core::CryptoRngCore,
+        message: &[u8],
+    ) -> Result<Signature, signature::Error> {
+        Ok(self.fast_signature_with_rng(message, rng))
     }
+}
 
-    for i in 0..message.len() {
-        sm[i + 64] = message[i]
+/// Same as the [`RandomizedSigner`](signature::RandomizedSigner) of the pair private key
+impl signature::RandomizedSigner<Signature> for KeyPair {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl signature::rand_core::CryptoRngCore,
+        message: &[u8],
+    ) -> Result<Signature, signature::Error> {
+        Ok(self.fast_signature_with_rng(message, rng))
     }
+}
 
-    if curve25519_sign_open(&mut m, &mut sm, public_key) < 0 {
-        false
-    } else {
-        true
+/// Verifies with [`PublicKey::verify`], the opaque error hides the reason
+impl signature::Verifier<Signature> for PublicKey {
+    fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), signature::Error> {
+        PublicKey::verify(self, message, signature).map_err(|_| signature::Error::new())
+    }
+}
+
+impl signature::Keypair for KeyPair {
+    type VerifyingKey = PublicKey;
+
+    fn verifying_key(&self) -> PublicKey {
+        self.pubk
     }
 }
 
 /**
-# Decode Signature
-## Decoded a Signature using your public key
+# Full Signature
+## Sign a legacy `Vec<u32>` message with your private key
 
-- Receive a public key and signature and return a message decoded
-- Possible only for full_signature function
+- Kept for compatibility, use [`SecretKey::full_signature`]
+- Panics on malformed input, see [`try_full_signature`]
+*/
+#[deprecated(note = "use `SecretKey::full_signature`")]
+#[allow(deprecated)]
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+pub fn full_signature(
+    secret_key: Vec<u32>,
+    message: Vec<u32>,
+    opt_random: Option<Vec<u32>>,
+) -> Vec<u32> {
+    match try_full_signature(secret_key, message, opt_random) {
+        Ok(signed_msg) => signed_msg,
+        Err(error) => panic!("cannot sign: {error}"),
+    }
+}
 
-Example
+/**
+# Full Signature
+## Sign a legacy `Vec<u32>` message with your private key
 
-```rust
-use ed25519_axolotl::crypto::keys::KeyPair;
-use ed25519_axolotl::crypto::signatures::{decode_signature, full_signature};
-use ed25519_axolotl::utils::random::random_bytes;
+- Kept for compatibility, use [`SecretKey::full_signature`]
+- Fails when the key does not have 32 bytes, the random has less than 64 bytes
+  or some value is above 255
+*/
+#[deprecated(note = "use `SecretKey::full_signature`")]
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+pub fn try_full_signature(
+    secret_key: Vec<u32>,
+    message: Vec<u32>,
+    opt_random: Option<Vec<u32>>,
+) -> Result<Vec<u32>, Error> {
+    use crate::utils::random::random_array;
+
+    let secret_key = SecretKey::try_from(&narrow(&secret_key)?[..])?;
+    let random = match opt_random {
+        Some(random) => narrow(&random)?,
+        None => random_array::<64>().to_vec(),
+    };
+    if random.len() < 64 {
+        return Err(Error::InvalidLength {
+            expected: 64,
+            actual: random.len(),
+        });
+    }
+    Ok(widen(&sign_full(
+        secret_key.expose_secret(),
+        &narrow(&message)?,
+        &random,
+    )))
+}
 
-let keys = KeyPair::new(Some(vec![1; 32]));
-let msg = "Lunes"
-    .as_bytes()
-    .iter()
-    .map(|x| *x as u32)
-    .collect::<Vec<u32>>();
+/**
+# Fast Signature
+## Sign a legacy `Vec<u32>` message with your private key
 
-let signature = full_signature(keys.prvk, msg.clone(), Some(random_bytes(64)));
+- Kept for compatibility, use [`SecretKey::fast_signature`]
+- Panics on malformed input, see [`try_fast_signature`]
+*/
+#[deprecated(note = "use `SecretKey::fast_signature`")]
+#[allow(deprecated)]
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+pub fn fast_signature(
+    secret_key: Vec<u32>,
+    message: Vec<u32>,
+    opt_random: Option<Vec<u32>>,
+) -> Vec<u32> {
+    let mut signature = full_signature(secret_key, message, opt_random);
+    signature.truncate(64);
+    signature
+}
 
-assert_eq!(msg, decode_signature(keys.pubk, signature))
-```
+/**
+# Fast Signature
+## Sign a legacy `Vec<u32>` message with your private key
+
+- Kept for compatibility, use [`SecretKey::fast_signature`]
+- Fails like [`try_full_signature`]
 */
-pub fn decode_signature(public_key: Vec<u32>, signed_msg: Vec<u32>) -> Vec<u32> {
-    use crate::utils::extras::curve25519_sign_open;
+#[deprecated(note = "use `SecretKey::fast_signature`")]
+#[allow(deprecated)]
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+pub fn try_fast_signature(
+    secret_key: Vec<u32>,
+    message: Vec<u32>,
+    opt_random: Option<Vec<u32>>,
+) -> Result<Vec<u32>, Error> {
+    let mut signature = try_full_signature(secret_key, message, opt_random)?;
+    signature.truncate(64);
+    Ok(signature)
+}
 
-    let mut tmp: Vec<u32> = vec![0; signed_msg.len()];
-    let mut ref_signed_msg = signed_msg.clone();
+/**
+# Validate Signature
+## Validate a legacy `Vec<u32>` signature with a message and your public key
 
-    let message_len = curve25519_sign_open(&mut tmp, &mut ref_signed_msg, public_key) as usize;
-    let mut message: Vec<u32> = vec![0; message_len as usize];
-    for i in 0..message_len {
-        message[i] = tmp[i]
-    }
+- Kept for compatibility, use [`PublicKey::validate_signature`]
+- Malformed input is reported as an invalid signature
+*/
+#[deprecated(note = "use `PublicKey::validate_signature`")]
+#[allow(deprecated)]
+#[cfg(feature = "alloc")]
+pub fn validate_signature(public_key: Vec<u32>, message: Vec<u32>, signature: Vec<u32>) -> bool {
+    try_validate_signature(public_key, message, signature).is_ok()
+}
 
-    message
+/**
+# Validate Signature
+## Validate a legacy `Vec<u32>` signature with a message and your public key
+
+- Kept for compatibility, use [`PublicKey::verify`]
+- Fails when the input is malformed or the signature is not valid
+- The signature must have at least 64 bytes
+*/
+#[deprecated(note = "use `PublicKey::verify`")]
+#[cfg(feature = "alloc")]
+pub fn try_validate_signature(
+    public_key: Vec<u32>,
+    message: Vec<u32>,
+    signature: Vec<u32>,
+) -> Result<(), Error> {
+    let public_key = PublicKey::try_from(&narrow(&public_key)?[..])?;
+    // Full signatures are accepted too, only their first 64 bytes are read.
+    let signature = SignedMessage::from_bytes(&narrow(&signature)?)?.signature();
+    public_key.verify(&narrow(&message)?, &signature)
+}
+
+/**
+# Decode Signature
+## Decoded a legacy `Vec<u32>` signature using your public key
+
+- Kept for compatibility, use [`PublicKey::decode_signature`]
+- Return an empty message when the input is malformed or the signature is not valid
+*/
+#[deprecated(note = "use `PublicKey::decode_signature`")]
+#[allow(deprecated)]
+#[cfg(feature = "alloc")]
+pub fn decode_signature(public_key: Vec<u32>, signed_msg: Vec<u32>) -> Vec<u32> {
+    try_decode_signature(public_key, signed_msg).unwrap_or_default()
+}
+
+/**
+# Decode Signature
+## Decoded a legacy `Vec<u32>` signature using your public key
+
+- Kept for compatibility, use [`PublicKey::decode_signature`]
+- Fails when the input is malformed or the signature is not valid
+*/
+#[deprecated(note = "use `PublicKey::decode_signature`")]
+#[cfg(feature = "alloc")]
+pub fn try_decode_signature(public_key: Vec<u32>, signed_msg: Vec<u32>) -> Result<Vec<u32>, Error> {
+    let public_key = PublicKey::try_from(&narrow(&public_key)?[..])?;
+    let signed_msg = SignedMessage::from_bytes(&narrow(&signed_msg)?)?;
+    public_key
+        .decode_signature(&signed_msg)
+        .map(|message| widen(&message))
 }
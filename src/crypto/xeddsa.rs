@@ -0,0 +1,236 @@
+use crate::crypto::array;
+use crate::crypto::keys::{PublicKey, SecretKey};
+use crate::crypto::signatures::Signature;
+use crate::utils::random::{CryptoRng, RngCore};
+use crate::Error;
+
+/// # VXEdDSA signature
+///
+/// - 96 bytes `V || h || s` proving the *VrfOutput* of a message
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct VrfSignature([u8; 96]);
+
+/// # VXEdDSA output
+///
+/// - 32 bytes unique to the key and message, random to anyone without the secret key
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct VrfOutput([u8; 32]);
+
+impl VrfSignature {
+    /// ## Create a *VrfSignature* from raw bytes
+    pub fn from_bytes(bytes: [u8; 96]) -> VrfSignature {
+        VrfSignature(bytes)
+    }
+
+    /// ## Copy the signature into a byte array
+    pub fn to_bytes(&self) -> [u8; 96] {
+        self.0
+    }
+
+    /// ## Borrow the signature as a byte array
+    pub fn as_bytes(&self) -> &[u8; 96] {
+        &self.0
+    }
+}
+
+impl VrfOutput {
+    /// ## Copy the output into a byte array
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// ## Borrow the output as a byte array
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<[u8; 96]> for VrfSignature {
+    fn from(bytes: [u8; 96]) -> VrfSignature {
+        VrfSignature(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for VrfSignature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<VrfSignature, Error> {
+        Ok(VrfSignature(array(bytes)?))
+    }
+}
+
+impl AsRef<[u8]> for VrfSignature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for VrfOutput {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/**
+# XEdDSA
+## Sign a message with your private key, as specified by Signal
+
+- The Edwards key is taken with sign bit zero, negating the private key when
+  needed, so signatures are plain Ed25519 signatures of that key
+- 64 byte signature, with the 64 random bytes of the nonce from the operating system
+
+## Example
+
+```rust
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::crypto::xeddsa::{xeddsa_sign, xeddsa_verify};
+
+let keys = KeyPair::from_seed(&[1; 32]);
+let signature = xeddsa_sign(&keys.prvk, b"Lunes");
+
+assert_eq!(Ok(()), xeddsa_verify(&keys.pubk, b"Lunes", &signature));
+```
+*/
+#[cfg(feature = "getrandom")]
+pub fn xeddsa_sign(secret_key: &SecretKey, message: &[u8]) -> Signature {
+    use crate::utils::random::OsRng;
+
+    xeddsa_sign_with_rng(secret_key, message, &mut OsRng)
+}
+
+/// ## Same as [`xeddsa_sign`], with the 64 random bytes of the nonce taken from *`rng`*
+pub fn xeddsa_sign_with_rng<R: CryptoRng + RngCore>(
+    secret_key: &SecretKey,
+    message: &[u8],
+    rng: &mut R,
+) -> Signature {
+    use crate::utils::extras::xeddsa_sign;
+    use crate::utils::random::random_array_with;
+
+    let random: [u8; 64] = random_array_with(rng);
+    let mut signature = [0u8; 64];
+    xeddsa_sign(&mut signature, message, secret_key.expose_secret(), &random);
+    Signature::from_bytes(signature)
+}
+
+/**
+# XEdDSA
+## Verify the signature of a message with the public key of someone else
+
+- Rejects a public key that is not a reduced Montgomery u below p - 1
+- Like libsignal, the top bit of the signature may carry the Edwards sign of the
+  key, so signatures from [`SecretKey::fast_signature`] verify too
+
+## Example
+
+```rust
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::crypto::xeddsa::{xeddsa_sign, xeddsa_verify};
+use ed25519_axolotl::Error;
+
+let keys = KeyPair::from_seed(&[1; 32]);
+let signature = xeddsa_sign(&keys.prvk, b"Lunes");
+
+assert_eq!(
+    Err(Error::InvalidSignature),
+    xeddsa_verify(&keys.pubk, b"Lunas", &signature)
+);
+```
+*/
+pub fn xeddsa_verify(
+    public_key: &PublicKey,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<(), Error> {
+    use crate::utils::extras::xeddsa_verify;
+
+    xeddsa_verify(message, signature.as_bytes(), public_key.as_bytes())
+}
+
+/**
+# VXEdDSA
+## Sign a message with your private key, proving a verifiable random output
+
+- Returns the 96 byte signature and the 32 byte *VrfOutput*, which is the
+  same for every signature of the same key and message
+- The 64 random bytes of the nonce come from the operating system
+
+## Example
+
+```rust
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::crypto::xeddsa::{vxeddsa_sign, vxeddsa_verify};
+
+let keys = KeyPair::from_seed(&[1; 32]);
+let (signature, output) = vxeddsa_sign(&keys.prvk, b"Lunes");
+
+assert_eq!(Ok(output), vxeddsa_verify(&keys.pubk, b"Lunes", &signature));
+```
+*/
+#[cfg(feature = "getrandom")]
+pub fn vxeddsa_sign(secret_key: &SecretKey, message: &[u8]) -> (VrfSignature, VrfOutput) {
+    use crate::utils::random::OsRng;
+
+    vxeddsa_sign_with_rng(secret_key, message, &mut OsRng)
+}
+
+/// ## Same as [`vxeddsa_sign`], with the 64 random bytes of the nonce taken from *`rng`*
+pub fn vxeddsa_sign_with_rng<R: CryptoRng + RngCore>(
+    secret_key: &SecretKey,
+    message: &[u8],
+    rng: &mut R,
+) -> (VrfSignature, VrfOutput) {
+    use crate::utils::extras::vxeddsa_sign;
+    use crate::utils::random::random_array_with;
+
+    let random: [u8; 64] = random_array_with(rng);
+    let mut signature = [0u8; 96];
+    let mut output = [0u8; 32];
+    vxeddsa_sign(
+        &mut signature,
+        &mut output,
+        message,
+        secret_key.expose_secret(),
+        &random,
+    );
+    (VrfSignature(signature), VrfOutput(output))
+}
+
+/**
+# VXEdDSA
+## Verify the signature of a message, returning its *VrfOutput*
+
+- Fails with [`Error::LowOrderPoint`] when the public key is a low order point
+
+## Example
+
+```rust
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::crypto::xeddsa::{vxeddsa_sign, vxeddsa_verify};
+use ed25519_axolotl::Error;
+
+let keys = KeyPair::from_seed(&[1; 32]);
+let (signature, _) = vxeddsa_sign(&keys.prvk, b"Lunes");
+
+assert_eq!(
+    Err(Error::InvalidSignature),
+    vxeddsa_verify(&keys.pubk, b"Lunas", &signature)
+);
+```
+*/
+pub fn vxeddsa_verify(
+    public_key: &PublicKey,
+    message: &[u8],
+    signature: &VrfSignature,
+) -> Result<VrfOutput, Error> {
+    use crate::utils::extras::vxeddsa_verify;
+
+    let mut output = [0u8; 32];
+    vxeddsa_verify(
+        &mut output,
+        message,
+        signature.as_bytes(),
+        public_key.as_bytes(),
+    )?;
+    Ok(VrfOutput(output))
+}
@@ -0,0 +1,86 @@
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/**
+# Encode *`bytes`* in Base58
+
+- Every leading zero byte becomes a leading `1`
+
+## Example
+
+```rust
+use ed25519_axolotl::encoding::base58;
+
+assert_eq!("112", base58::encode(&[0, 0, 1]));
+assert_eq!("9dKViWW", base58::encode(b"Lunes"));
+```
+*/
+pub fn encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|b| **b == 0).count();
+
+    // Base 58 digits, least significant first.
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for b in &bytes[zeros..] {
+        let mut carry = *b as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut text = String::with_capacity(zeros + digits.len());
+    text.extend(core::iter::repeat_n('1', zeros));
+    text.extend(digits.iter().rev().map(|d| ALPHABET[*d as usize] as char));
+    text
+}
+
+/**
+# Decode the Base58 *`text`*
+
+- Fails on a character outside of the alphabet, like `0`, `O`, `I` or `l`
+
+## Example
+
+```rust
+use ed25519_axolotl::encoding::base58;
+use ed25519_axolotl::Error;
+
+assert_eq!(Ok(b"Lunes".to_vec()), base58::decode("9dKViWW"));
+assert_eq!(
+    Err(Error::InvalidCharacter { index: 2, character: '0' }),
+    base58::decode("9d0ViWW")
+);
+```
+*/
+pub fn decode(text: &str) -> Result<Vec<u8>, Error> {
+    // Bytes, least significant first.
+    let mut bytes: Vec<u8> = Vec::with_capacity(text.len() * 733 / 1000 + 1);
+    for (index, character) in text.char_indices() {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|c| *c as char == character)
+            .ok_or(Error::InvalidCharacter { index, character })? as u32;
+        for b in bytes.iter_mut() {
+            carry += (*b as u32) * 58;
+            *b = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let zeros = text.chars().take_while(|c| *c == '1').count();
+    bytes.extend(core::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Ok(bytes)
+}
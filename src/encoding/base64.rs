@@ -0,0 +1,87 @@
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/**
+# Encode *`bytes`* in Base64 as specified in RFC 4648
+
+- Standard alphabet, padded with `=` to a multiple of 4 characters
+
+## Example
+
+```rust
+use ed25519_axolotl::encoding::base64;
+
+assert_eq!("THVuZXM=", base64::encode(b"Lunes"));
+```
+*/
+pub fn encode(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let n = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => text.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char),
+                false => text.push('='),
+            }
+        }
+    }
+    text
+}
+
+/**
+# Decode the Base64 *`text`*
+
+- Fails on a character outside of the alphabet, and on missing or extra padding
+  or unused bits set, so every byte string has a single encoding
+
+## Example
+
+```rust
+use ed25519_axolotl::encoding::base64;
+use ed25519_axolotl::Error;
+
+assert_eq!(Ok(b"Lunes".to_vec()), base64::decode("THVuZXM="));
+assert_eq!(Err(Error::InvalidEncoding), base64::decode("THVuZXM"));
+assert_eq!(Err(Error::InvalidEncoding), base64::decode("THVuZXN="));
+assert_eq!(
+    Err(Error::InvalidCharacter { index: 3, character: '-' }),
+    base64::decode("THV-ZXM=")
+);
+```
+*/
+pub fn decode(text: &str) -> Result<Vec<u8>, Error> {
+    if !text.len().is_multiple_of(4) {
+        return Err(Error::InvalidEncoding);
+    }
+    let padding = text.bytes().rev().take_while(|c| *c == b'=').count();
+    if padding > 2 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for (index, character) in text[..text.len() - padding].char_indices() {
+        let value = ALPHABET
+            .iter()
+            .position(|c| *c as char == character)
+            .ok_or(Error::InvalidCharacter { index, character })?;
+        acc = (acc << 6 | value as u32) & 0xfff;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+        }
+    }
+
+    match acc & ((1 << bits) - 1) {
+        0 => Ok(bytes),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
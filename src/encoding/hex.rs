@@ -0,0 +1,63 @@
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/**
+# Encode *`bytes`* in lower case hexadecimal
+
+## Example
+
+```rust
+use ed25519_axolotl::encoding::hex;
+
+assert_eq!("4c756e6573", hex::encode(b"Lunes"));
+```
+*/
+pub fn encode(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(2 * bytes.len());
+    for b in bytes {
+        text.push(DIGITS[(b >> 4) as usize] as char);
+        text.push(DIGITS[(b & 15) as usize] as char);
+    }
+    text
+}
+
+/**
+# Decode the hexadecimal *`text`*, in lower or upper case
+
+- Fails on a character that is not a digit, or an odd number of digits
+
+## Example
+
+```rust
+use ed25519_axolotl::encoding::hex;
+use ed25519_axolotl::Error;
+
+assert_eq!(Ok(b"Lunes".to_vec()), hex::decode("4C756E6573"));
+assert_eq!(Err(Error::InvalidEncoding), hex::decode("4c7"));
+assert_eq!(
+    Err(Error::InvalidCharacter { index: 1, character: 'x' }),
+    hex::decode("4x")
+);
+```
+*/
+pub fn decode(text: &str) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::with_capacity(text.len() / 2);
+    let mut high: Option<u8> = None;
+    for (index, character) in text.char_indices() {
+        let digit = character
+            .to_digit(16)
+            .ok_or(Error::InvalidCharacter { index, character })? as u8;
+        match high.take() {
+            Some(h) => bytes.push(h << 4 | digit),
+            None => high = Some(digit),
+        }
+    }
+
+    match high {
+        Some(_) => Err(Error::InvalidEncoding),
+        None => Ok(bytes),
+    }
+}
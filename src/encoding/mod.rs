@@ -0,0 +1,6 @@
+/// Base58 with the Bitcoin alphabet, as used by Lunes and Waves
+pub mod base58;
+/// Base64 with the standard alphabet and padding
+pub mod base64;
+/// Hexadecimal in lower case
+pub mod hex;
@@ -0,0 +1,140 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// # Errors of keys and signatures functions
+///
+/// - New variants may be added in minor releases, matches need a wildcard arm
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// ## Input does not have the expected number of bytes
+    ///
+    /// - For signed messages `expected` is the minimum length
+    InvalidLength { expected: usize, actual: usize },
+    /// ## Public key is not a point of the curve
+    InvalidPublicKey,
+    /// ## Signature does not match the message and public key
+    InvalidSignature,
+    /// ## Scalar is not reduced modulo the group order
+    NonCanonicalScalar,
+    /// ## Public key is a low order point
+    ///
+    /// - It gives an all-zero shared secret and cannot bind a VXEdDSA output
+    LowOrderPoint,
+    /// ## Legacy `Vec<u32>` byte holds a value above 255
+    ByteOutOfRange { index: usize, value: u32 },
+    /// ## Message names a prekey that was not given
+    UnknownPreKey { id: u32 },
+    /// ## Message is forged, replayed or corrupted
+    DecryptionFailed,
+    /// ## Message skips more messages than the session allows
+    TooManySkipped { limit: u32 },
+    /// ## Session cannot send before receiving a first message
+    NoSendingChain,
+    /// ## Bytes do not follow the expected encoding
+    InvalidEncoding,
+    /// ## Text holds a character outside of the alphabet of its encoding
+    ///
+    /// - `index` is the byte offset of the character in the text
+    InvalidCharacter { index: usize, character: char },
+    /// ## Checksum of an address does not match its content
+    InvalidChecksum,
+    /// ## Chain byte of an address is neither mainnet nor testnet
+    UnknownChainId { id: u8 },
+    /// ## Seed phrase does not have the expected number of words
+    InvalidWordCount { expected: usize, actual: usize },
+    /// ## Word of a seed phrase is not in the word list
+    ///
+    /// - `index` is the position of the word, the word itself is kept secret
+    UnknownWord { index: usize },
+    /// ## Derivation path is not `m` followed by `/index'` levels
+    InvalidDerivationPath,
+    /// ## Curve25519 keys only have hardened children
+    ///
+    /// - `index` is the index lacking the hardened bit
+    NonHardenedIndex { index: u32 },
+    /// ## Transaction type or version is not supported
+    UnsupportedTransaction { type_id: u8, version: u8 },
+    /// ## Key pair signing a transaction is not its sender
+    SignerMismatch,
+}
+
+/// # Errors of a batch verification
+///
+/// - Index of every entry that failed, in order, with the reason
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BatchError {
+    /// ## Index in the batch and reason of each failure
+    pub failures: Vec<(usize, Error)>,
+}
+
+#[cfg(feature = "alloc")]
+impl BatchError {
+    /// ## Indices of the entries that failed
+    pub fn indices(&self) -> Vec<usize> {
+        self.failures.iter().map(|(index, _)| *index).collect()
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::InvalidLength { expected, actual } => {
+                write!(f, "invalid length: expected {expected} bytes, got {actual}")
+            }
+            Error::InvalidPublicKey => write!(f, "invalid public key"),
+            Error::InvalidSignature => write!(f, "invalid signature"),
+            Error::NonCanonicalScalar => write!(f, "non canonical scalar"),
+            Error::LowOrderPoint => write!(f, "low order point"),
+            Error::ByteOutOfRange { index, value } => {
+                write!(f, "byte out of range: {value} at index {index}")
+            }
+            Error::UnknownPreKey { id } => write!(f, "unknown prekey: {id}"),
+            Error::DecryptionFailed => write!(f, "decryption failed"),
+            Error::TooManySkipped { limit } => {
+                write!(f, "too many skipped messages: limit is {limit}")
+            }
+            Error::NoSendingChain => write!(f, "no sending chain"),
+            Error::InvalidEncoding => write!(f, "invalid encoding"),
+            Error::InvalidCharacter { index, character } => {
+                write!(f, "invalid character: {character:?} at index {index}")
+            }
+            Error::InvalidChecksum => write!(f, "invalid checksum"),
+            Error::UnknownChainId { id } => write!(f, "unknown chain id: {id}"),
+            Error::InvalidWordCount { expected, actual } => {
+                write!(
+                    f,
+                    "invalid word count: expected {expected} words, got {actual}"
+                )
+            }
+            Error::UnknownWord { index } => write!(f, "unknown word at index {index}"),
+            Error::InvalidDerivationPath => write!(f, "invalid derivation path"),
+            Error::NonHardenedIndex { index } => write!(f, "non hardened index: {index}"),
+            Error::UnsupportedTransaction { type_id, version } => {
+                write!(
+                    f,
+                    "unsupported transaction: type {type_id} version {version}"
+                )
+            }
+            Error::SignerMismatch => write!(f, "signer is not the sender"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for BatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "batch verification failed: {} invalid",
+            self.failures.len()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
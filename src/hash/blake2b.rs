@@ -0,0 +1,169 @@
+/// Initial state, the same words as the SHA-512 initial state
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// Message word order of each round, the last two rounds repeat the first two
+const SIGMA: [[usize; 16]; 12] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+/**
+# BLAKE2b-256
+## Hash messages of any length into 32 bytes
+
+- As specified in RFC 7693, unkeyed with a 32 byte output
+- First of the two hashes of Lunes addresses
+
+## Example
+
+```rust
+use ed25519_axolotl::hash::Blake2b256;
+
+let mut hasher = Blake2b256::new();
+hasher.update(b"Lu");
+hasher.update(b"nes");
+
+assert_eq!(Blake2b256::digest(b"Lunes"), hasher.finalize());
+```
+*/
+#[derive(Clone, Debug)]
+pub struct Blake2b256 {
+    state: [u64; 8],
+    buffer: [u8; 128],
+    buffered: usize,
+    length: u128,
+}
+
+impl Blake2b256 {
+    /// ## Start a new hash
+    pub fn new() -> Blake2b256 {
+        let mut state = IV;
+        // Parameter block: 32 byte digest, no key, fanout and depth of 1.
+        state[0] ^= 0x01010000 ^ 32;
+
+        Blake2b256 {
+            state,
+            buffer: [0; 128],
+            buffered: 0,
+            length: 0,
+        }
+    }
+
+    /// ## Hash a whole message at once
+    pub fn digest(message: &[u8]) -> [u8; 32] {
+        let mut hasher = Blake2b256::new();
+        hasher.update(message);
+        hasher.finalize()
+    }
+
+    /// ## Feed the next piece of the message
+    pub fn update(&mut self, mut data: &[u8]) {
+        // The last block is compressed apart, so a full buffer waits for more data.
+        while !data.is_empty() {
+            if self.buffered == 128 {
+                self.length += 128;
+                compress(&mut self.state, &self.buffer, self.length, false);
+                self.buffered = 0;
+            }
+
+            let taken = data.len().min(128 - self.buffered);
+            self.buffer[self.buffered..self.buffered + taken].copy_from_slice(&data[..taken]);
+            self.buffered += taken;
+            data = &data[taken..];
+        }
+    }
+
+    /// ## Compress the last block and return the 32 byte hash
+    pub fn finalize(mut self) -> [u8; 32] {
+        self.length += self.buffered as u128;
+        self.buffer[self.buffered..].fill(0);
+        compress(&mut self.state, &self.buffer, self.length, true);
+
+        let mut out = [0u8; 32];
+        for (chunk, word) in out.chunks_exact_mut(8).zip(self.state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        out
+    }
+}
+
+impl Default for Blake2b256 {
+    fn default() -> Blake2b256 {
+        Blake2b256::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for Blake2b256 {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Mix two message words into four words of the working vector
+fn mix(v: &mut [u64; 16], [a, b, c, d]: [usize; 4], x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+/// Process one 128 byte block, *`length`* bytes into the message
+fn compress(state: &mut [u64; 8], block: &[u8; 128], length: u128, last: bool) {
+    let mut m = [0u64; 16];
+    for (word, chunk) in m.iter_mut().zip(block.chunks_exact(8)) {
+        *word = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(state);
+    v[8..].copy_from_slice(&IV);
+    v[12] ^= length as u64;
+    v[13] ^= (length >> 64) as u64;
+    if last {
+        v[14] = !v[14];
+    }
+
+    for s in &SIGMA {
+        mix(&mut v, [0, 4, 8, 12], m[s[0]], m[s[1]]);
+        mix(&mut v, [1, 5, 9, 13], m[s[2]], m[s[3]]);
+        mix(&mut v, [2, 6, 10, 14], m[s[4]], m[s[5]]);
+        mix(&mut v, [3, 7, 11, 15], m[s[6]], m[s[7]]);
+        mix(&mut v, [0, 5, 10, 15], m[s[8]], m[s[9]]);
+        mix(&mut v, [1, 6, 11, 12], m[s[10]], m[s[11]]);
+        mix(&mut v, [2, 7, 8, 13], m[s[12]], m[s[13]]);
+        mix(&mut v, [3, 4, 9, 14], m[s[14]], m[s[15]]);
+    }
+
+    for (i, word) in state.iter_mut().enumerate() {
+        *word ^= v[i] ^ v[i + 8];
+    }
+}
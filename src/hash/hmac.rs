@@ -0,0 +1,78 @@
+use crate::hash::Sha512;
+use core::fmt;
+use zeroize::Zeroize;
+
+/**
+# HMAC-SHA-512
+## Authenticate messages of any length with a secret key
+
+- As specified in RFC 2104 and RFC 4231, keys above 128 bytes are hashed first
+- Cloning a keyed *HmacSha512* skips hashing the key again
+
+## Example
+
+```rust
+use ed25519_axolotl::hash::HmacSha512;
+
+let mut mac = HmacSha512::new(b"key");
+mac.update(b"Lu");
+mac.update(b"nes");
+
+assert_eq!(HmacSha512::mac(b"key", b"Lunes"), mac.finalize());
+```
+*/
+#[derive(Clone)]
+pub struct HmacSha512 {
+    inner: Sha512,
+    outer: Sha512,
+}
+
+impl HmacSha512 {
+    /// ## Start a new authentication with *`key`*
+    pub fn new(key: &[u8]) -> HmacSha512 {
+        let mut k = [0u8; 128];
+        if key.len() > 128 {
+            k[..64].copy_from_slice(&Sha512::digest(key));
+        } else {
+            k[..key.len()].copy_from_slice(key);
+        }
+
+        let mut pad = k.map(|x| x ^ 0x36);
+        let mut inner = Sha512::new();
+        inner.update(&pad);
+
+        pad = k.map(|x| x ^ 0x5c);
+        let mut outer = Sha512::new();
+        outer.update(&pad);
+
+        k.zeroize();
+        pad.zeroize();
+        HmacSha512 { inner, outer }
+    }
+
+    /// ## Authenticate a whole message at once
+    pub fn mac(key: &[u8], message: &[u8]) -> [u8; 64] {
+        let mut mac = HmacSha512::new(key);
+        mac.update(message);
+        mac.finalize()
+    }
+
+    /// ## Feed the next piece of the message
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// ## Return the 64 byte tag
+    pub fn finalize(mut self) -> [u8; 64] {
+        let mut inner = self.inner.finalize();
+        self.outer.update(&inner);
+        inner.zeroize();
+        self.outer.finalize()
+    }
+}
+
+impl fmt::Debug for HmacSha512 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("HmacSha512(<redacted>)")
+    }
+}
@@ -0,0 +1,178 @@
+/// Round constants of Keccak-f[1600]
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation of each lane along the path of the permutation
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// Path of the lanes through the permutation, starting after lane 1
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Bytes absorbed per permutation, 200 minus twice the output
+const RATE: usize = 136;
+
+/**
+# Keccak-256
+## Hash messages of any length into 32 bytes
+
+- The original Keccak submission padding, not the SHA3-256 of FIPS 202,
+  as Ethereum and Lunes use it
+- Second of the two hashes of Lunes addresses
+
+## Example
+
+```rust
+use ed25519_axolotl::hash::Keccak256;
+
+let mut hasher = Keccak256::new();
+hasher.update(b"Lu");
+hasher.update(b"nes");
+
+assert_eq!(Keccak256::digest(b"Lunes"), hasher.finalize());
+```
+*/
+#[derive(Clone, Debug)]
+pub struct Keccak256 {
+    state: [u64; 25],
+    buffer: [u8; RATE],
+    buffered: usize,
+}
+
+impl Keccak256 {
+    /// ## Start a new hash
+    pub fn new() -> Keccak256 {
+        Keccak256 {
+            state: [0; 25],
+            buffer: [0; RATE],
+            buffered: 0,
+        }
+    }
+
+    /// ## Hash a whole message at once
+    pub fn digest(message: &[u8]) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update(message);
+        hasher.finalize()
+    }
+
+    /// ## Feed the next piece of the message
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let taken = data.len().min(RATE - self.buffered);
+            self.buffer[self.buffered..self.buffered + taken].copy_from_slice(&data[..taken]);
+            self.buffered += taken;
+            data = &data[taken..];
+
+            if self.buffered == RATE {
+                let block = self.buffer;
+                absorb(&mut self.state, &block);
+                self.buffered = 0;
+            }
+        }
+    }
+
+    /// ## Pad the message and return its 32 byte hash
+    pub fn finalize(mut self) -> [u8; 32] {
+        self.buffer[self.buffered..].fill(0);
+        self.buffer[self.buffered] ^= 0x01;
+        self.buffer[RATE - 1] ^= 0x80;
+        let block = self.buffer;
+        absorb(&mut self.state, &block);
+
+        let mut out = [0u8; 32];
+        for (chunk, lane) in out.chunks_exact_mut(8).zip(self.state) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        out
+    }
+}
+
+impl Default for Keccak256 {
+    fn default() -> Keccak256 {
+        Keccak256::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for Keccak256 {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Xor one block into the state and permute it
+fn absorb(state: &mut [u64; 25], block: &[u8; RATE]) {
+    for (lane, chunk) in state.iter_mut().zip(block.chunks_exact(8)) {
+        *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    keccak_f(state);
+}
+
+/// Keccak-f[1600] permutation
+fn keccak_f(a: &mut [u64; 25]) {
+    for rc in RC {
+        // θ: xor each lane with the parities of two neighbour columns.
+        let mut c = [0u64; 5];
+        for (x, parity) in c.iter_mut().enumerate() {
+            *parity = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in (0..25).step_by(5) {
+                a[y + x] ^= d;
+            }
+        }
+
+        // ρ and π: rotate the lanes while moving them along their path.
+        let mut last = a[1];
+        for (j, r) in PI.iter().zip(RHO) {
+            let t = a[*j];
+            a[*j] = last.rotate_left(r);
+            last = t;
+        }
+
+        // χ: the only non linear step, along each row.
+        for y in (0..25).step_by(5) {
+            let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+            for x in 0..5 {
+                a[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // ι
+        a[0] ^= rc;
+    }
+}
@@ -0,0 +1,18 @@
+/// BLAKE2b hash function with a 32 byte output
+pub mod blake2b;
+/// HMAC message authentication over SHA-512
+pub mod hmac;
+/// Keccak hash function with a 32 byte output
+pub mod keccak256;
+/// PBKDF2 password based key derivation over HMAC-SHA-512
+pub mod pbkdf2;
+/// SHA-256 hash function
+pub mod sha256;
+/// SHA-512 hash function
+pub mod sha512;
+
+pub use blake2b::Blake2b256;
+pub use hmac::HmacSha512;
+pub use keccak256::Keccak256;
+pub use sha256::Sha256;
+pub use sha512::Sha512;
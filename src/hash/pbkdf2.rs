@@ -0,0 +1,44 @@
+use crate::hash::HmacSha512;
+use zeroize::Zeroize;
+
+/**
+# PBKDF2-HMAC-SHA-512
+## Stretch a password into *`out`*, as specified in RFC 8018
+
+- Every 64 bytes of output cost *`rounds`* HMACs, at least one
+- BIP-39 uses 2048 rounds to turn a mnemonic into a seed
+
+## Example
+
+```rust
+use ed25519_axolotl::hash::pbkdf2::pbkdf2_sha512;
+
+let mut key = [0u8; 32];
+pbkdf2_sha512(b"password", b"salt", 1, &mut key);
+assert_eq!(0x86, key[0]);
+```
+*/
+pub fn pbkdf2_sha512(password: &[u8], salt: &[u8], rounds: u32, out: &mut [u8]) {
+    let prf = HmacSha512::new(password);
+
+    for (i, chunk) in out.chunks_mut(64).enumerate() {
+        let mut mac = prf.clone();
+        mac.update(salt);
+        mac.update(&(i as u32 + 1).to_be_bytes());
+        let mut u = mac.finalize();
+        let mut t = u;
+
+        for _ in 1..rounds {
+            let mut mac = prf.clone();
+            mac.update(&u);
+            u = mac.finalize();
+            for (x, y) in t.iter_mut().zip(&u) {
+                *x ^= y;
+            }
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+
+        u.zeroize();
+        t.zeroize();
+    }
+}
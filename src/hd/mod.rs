@@ -0,0 +1,7 @@
+/// Derivation paths like `m/44'/808'/0'/0'`
+pub mod path;
+/// SLIP-0010 derivation of Curve25519 keys
+pub mod slip10;
+
+pub use path::DerivationPath;
+pub use slip10::ExtendedKey;
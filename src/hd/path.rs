@@ -0,0 +1,100 @@
+use crate::Error;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// Bit of the hardened indices, written with a `'` in paths
+pub const HARDENED: u32 = 1 << 31;
+
+/**
+# Path from a master key to one of its descendants
+
+- `m` followed by one `/index'` per level, `h` may replace `'`
+- Only hardened indices, the only ones SLIP-0010 allows for Curve25519
+
+## Example
+
+```rust
+use ed25519_axolotl::hd::path::{DerivationPath, HARDENED};
+
+let path: DerivationPath = "m/44'/808'/0h/0'".parse().unwrap();
+assert_eq!([44 | HARDENED, 808 | HARDENED, HARDENED, HARDENED], path.indices());
+assert_eq!("m/44'/808'/0'/0'", path.to_string());
+```
+*/
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /**
+    ## Create a *DerivationPath* from indices
+
+    - Fails when an index does not have the [`HARDENED`] bit
+
+    ```rust
+    use ed25519_axolotl::hd::path::{DerivationPath, HARDENED};
+    use ed25519_axolotl::Error;
+
+    assert!(DerivationPath::new(&[44 | HARDENED]).is_ok());
+    assert_eq!(
+        Some(Error::NonHardenedIndex { index: 44 }),
+        DerivationPath::new(&[44]).err()
+    );
+    ```
+    */
+    pub fn new(indices: &[u32]) -> Result<DerivationPath, Error> {
+        match indices.iter().find(|index| *index & HARDENED == 0) {
+            Some(index) => Err(Error::NonHardenedIndex { index: *index }),
+            None => Ok(DerivationPath(indices.to_vec())),
+        }
+    }
+
+    /// ## Indices of every level, with the [`HARDENED`] bit
+    pub fn indices(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// ## Parse a path like `m/44'/808'/0'/0'`
+    ///
+    /// - Fails on a malformed path or index, or on an index without `'` or `h`
+    fn from_str(text: &str) -> Result<DerivationPath, Error> {
+        let mut levels = text.split('/');
+        if levels.next() != Some("m") {
+            return Err(Error::InvalidDerivationPath);
+        }
+
+        let mut indices = Vec::new();
+        for level in levels {
+            let (digits, hardened) = match level.strip_suffix(['\'', 'h']) {
+                Some(digits) => (digits, true),
+                None => (level, false),
+            };
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(Error::InvalidDerivationPath);
+            }
+            let index: u32 = digits.parse().map_err(|_| Error::InvalidDerivationPath)?;
+            if index >= HARDENED {
+                return Err(Error::InvalidDerivationPath);
+            }
+            if !hardened {
+                return Err(Error::NonHardenedIndex { index });
+            }
+            indices.push(index | HARDENED);
+        }
+        Ok(DerivationPath(indices))
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("m")?;
+        for index in &self.0 {
+            write!(f, "/{}'", index & !HARDENED)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use crate::crypto::keys::KeyPair;
+use crate::hash::HmacSha512;
+use crate::hd::path::{DerivationPath, HARDENED};
+use crate::Error;
+use core::fmt;
+use zeroize::Zeroize;
+
+/// HMAC key of the master key of Curve25519 trees
+const CURVE25519: &[u8] = b"curve25519 seed";
+
+/**
+# Node of a SLIP-0010 key tree
+
+- A 32 byte secret and a 32 byte chain code, the children of a node are
+  derived from both, so a node and its secret give no access to its parent
+- Only hardened children, as SLIP-0010 specifies for Curve25519
+- Wiped from memory on drop and never printed
+
+## Example
+
+```rust
+use ed25519_axolotl::hd::ExtendedKey;
+use ed25519_axolotl::mnemonic::bip39::Mnemonic;
+
+let mnemonic = Mnemonic::from_entropy(&[7; 32]).unwrap();
+let master = ExtendedKey::from_seed(&mnemonic.to_seed(""));
+
+let first = master.derive(&"m/44'/808'/0'/0'".parse().unwrap()).key_pair();
+let second = master.derive(&"m/44'/808'/1'/0'".parse().unwrap()).key_pair();
+assert_ne!(first.pubk, second.pubk);
+```
+*/
+#[derive(Clone)]
+pub struct ExtendedKey {
+    secret: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl ExtendedKey {
+    /// ## Split a 64 byte HMAC into secret and chain code
+    fn from_hmac(mut i: [u8; 64]) -> ExtendedKey {
+        let mut key = ExtendedKey {
+            secret: [0; 32],
+            chain_code: [0; 32],
+        };
+        key.secret.copy_from_slice(&i[..32]);
+        key.chain_code.copy_from_slice(&i[32..]);
+        i.zeroize();
+        key
+    }
+
+    /**
+    ## Derive the master key of a seed, of 16 to 64 bytes
+
+    - HMAC-SHA-512 of the seed with the key `"curve25519 seed"`
+    - A BIP-39 [`Mnemonic::to_seed`](crate::mnemonic::bip39::Mnemonic::to_seed)
+      is the usual seed
+    */
+    pub fn from_seed(seed: &[u8]) -> ExtendedKey {
+        ExtendedKey::from_seed_with_curve(seed, CURVE25519)
+    }
+
+    /**
+    ## Derive the master key of a seed for the curve of another HMAC key
+
+    - SLIP-0010 names the curve in the HMAC key, `b"ed25519 seed"` gives
+      the secrets of wallets that derive Ed25519 seeds the same way
+    - Only trees of curves with hardened derivation of the secret as is,
+      like Curve25519 and Ed25519, are derived correctly
+    */
+    pub fn from_seed_with_curve(seed: &[u8], curve: &[u8]) -> ExtendedKey {
+        ExtendedKey::from_hmac(HmacSha512::mac(curve, seed))
+    }
+
+    /**
+    ## Derive the hardened child of *`index`*
+
+    - Fails when *`index`* does not have the [`HARDENED`] bit
+
+    ```rust
+    use ed25519_axolotl::hd::path::HARDENED;
+    use ed25519_axolotl::hd::ExtendedKey;
+    use ed25519_axolotl::Error;
+
+    let master = ExtendedKey::from_seed(&[1; 32]);
+    assert!(master.derive_child(HARDENED).is_ok());
+    assert_eq!(
+        Some(Error::NonHardenedIndex { index: 0 }),
+        master.derive_child(0).err()
+    );
+    ```
+    */
+    pub fn derive_child(&self, index: u32) -> Result<ExtendedKey, Error> {
+        if index & HARDENED == 0 {
+            return Err(Error::NonHardenedIndex { index });
+        }
+
+        let mut mac = HmacSha512::new(&self.chain_code);
+        mac.update(&[0]);
+        mac.update(&self.secret);
+        mac.update(&index.to_be_bytes());
+        Ok(ExtendedKey::from_hmac(mac.finalize()))
+    }
+
+    /// ## Derive the descendant at the end of *`path`*
+    pub fn derive(&self, path: &DerivationPath) -> ExtendedKey {
+        let mut key = self.clone();
+        for index in path.indices() {
+            // Paths only hold hardened indices.
+            key = key.derive_child(*index).unwrap();
+        }
+        key
+    }
+
+    /// ## Borrow the secret of the node, the seed of its [`KeyPair`]
+    pub fn expose_secret(&self) -> &[u8; 32] {
+        &self.secret
+    }
+
+    /// ## Borrow the chain code of the node
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// ## The *KeyPair* of the node, its secret clamped by [`KeyPair::from_seed`]
+    pub fn key_pair(&self) -> KeyPair {
+        KeyPair::from_seed(&self.secret)
+    }
+}
+
+impl fmt::Debug for ExtendedKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ExtendedKey(<redacted>)")
+    }
+}
+
+impl Drop for ExtendedKey {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+        self.chain_code.zeroize();
+    }
+}
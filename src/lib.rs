@@ -19,22 +19,194 @@
 //! For the user guide and further documentation, please read
 //! [Telescope](https://blockchain.lunes.io/telescope)
 //!
+//! ## 🧩 Features
+//!
+//! Without default features the crate is `#![no_std]` and needs no allocator: keys,
+//! agreement, hashes and detached signatures work on the stack, and callers supply
+//! entropy through the `*_with` and `*_with_rng` functions.
+//!
+//! - `std`, default: implements `std::error::Error` for the errors and
+//!   `std::io::Write` for the hashes, and enables `alloc` and `getrandom`
+//! - `alloc`: adds what returns vectors or strings, the message recovery signatures,
+//!   `verify_batch`, the Base58 and hexadecimal text forms, the legacy `Vec<u32>`
+//!   functions and the encoding, hd, lunes, mnemonic and protocol modules
+//! - `getrandom`: re-exports `OsRng` and adds the functions drawing from it,
+//!   `random_bytes`, `fill_bytes`, `KeyPair::generate`, the signatures without
+//!   a generator, and with `alloc` also `verify_batch`, `SignedPreKey::new`,
+//!   `Session::initiate`, `Session::decrypt`, `SeedPhrase::generate`,
+//!   `Transaction::sign` and the legacy `Vec<u32>` functions
+//! - `serde`: serializes public keys, signatures and signed messages as Base58 in
+//!   human readable formats, the text form of `Display` and `FromStr`, and as bytes
+//!   otherwise, secret keys only through the `Exposed` wrapper
+//!
 //! ## 🏗 Archtecture
 //!
 //! - **Utils**
 //!     - random
 //!         - random_bytes *usize* -> *Vec<u32>*
+//!         - fill_bytes *&mut [u8]*
+//!         - fill_bytes_with *&mut (CryptoRng + RngCore), &mut [u8]*
 //!     - extras
 //!         - ...
+//! - **Hash**
+//!     - sha512
+//!         - Sha512::new -> *Sha512*
+//!         - Sha512::update *&[u8]*
+//!         - Sha512::finalize -> *[u8; 64]*
+//!         - Sha512::digest *&[u8]* -> *[u8; 64]*
+//!     - blake2b, keccak256, sha256
+//!         - Blake2b256, Keccak256, Sha256 with the same functions -> *[u8; 32]*
+//!     - hmac
+//!         - HmacSha512::new *&[u8]* -> *HmacSha512*
+//!         - HmacSha512::mac *&[u8], &[u8]* -> *[u8; 64]*
+//!     - pbkdf2
+//!         - pbkdf2_sha512 *&[u8], &[u8], u32, &mut [u8]*
+//! - **Encoding**
+//!     - base58, base64, hex
+//!         - encode *&[u8]* -> *String*
+//!         - decode *&str* -> *Result<Vec<u8>, Error>*
 //! - **Crypto**
+//!     - agreement
+//!         - SecretKey::diffie_hellman *&PublicKey* -> *Result<SharedSecret, Error>*
 //!     - keys
-//!         - KeyPair::new *Option<Vec<u32>>* -> *KeyPair*
-//!             - prvk -> *Vec<u32>*
-//!             - pubk -> *Vec<u32>*
+//!         - KeyPair::generate -> *KeyPair*
+//!         - KeyPair::generate_with *&mut (CryptoRng + RngCore)* -> *KeyPair*
+//!         - KeyPair::from_seed *&[u8; 32]* -> *KeyPair*
+//!             - prvk -> *SecretKey*
+//!             - pubk -> *PublicKey*
+//!         - SecretKey::expose_secret -> *&[u8; 32]*
+//!         - PublicKey::fingerprint -> *String*
+//!         - PublicKey::to_base58, SecretKey::to_base58 -> *String*
+//!         - PublicKey::from_base58, SecretKey::from_base58 *&str* -> *Result<_, Error>*
+//!         - Display and FromStr in Base58 for *PublicKey*, FromStr only for *SecretKey*
+//!         - Exposed *SecretKey or KeyPair*, serializable with the `serde` feature
 //!     - signatures
+//!         - SecretKey::fast_signature *&[u8]* -> *Signature*
+//!         - SecretKey::full_signature *&[u8]* -> *SignedMessage*
+//!         - SecretKey::*_signature_with_rng *&[u8], &mut (CryptoRng + RngCore)*
+//!         - SecretKey::sign_deterministic *&[u8]* -> *Signature*
+//!         - PublicKey::validate_signature *&[u8], &Signature* -> *bool*
+//!         - PublicKey::verify *&[u8], &Signature* -> *Result<(), Error>*
+//!         - PublicKey::verify_strict *&[u8], &Signature* -> *Result<(), Error>*
+//!         - PublicKey::validate_signature_strict *&[u8], &Signature* -> *bool*
+//!         - PublicKey::decode_signature *&SignedMessage* -> *Result<Vec<u8>, Error>*
+//!         - Signature::to_base58 -> *String*, Signature::from_base58 *&str* -> *Result<Signature, Error>*
+//!         - verify_batch *&[(PublicKey, &[u8], Signature)]* -> *Result<(), BatchError>*
+//!         - signature::{Signer, RandomizedSigner} for *SecretKey* and *KeyPair*
+//!         - signature::Verifier for *PublicKey*, signature::Keypair for *KeyPair*
+//!         - signature::SignatureEncoding for *Signature*
+//!     - xeddsa
+//!         - xeddsa_sign *&SecretKey, &[u8]* -> *Signature*
+//!         - xeddsa_verify *&PublicKey, &[u8], &Signature* -> *Result<(), Error>*
+//!         - vxeddsa_sign *&SecretKey, &[u8]* -> *(VrfSignature, VrfOutput)*
+//!         - vxeddsa_verify *&PublicKey, &[u8], &VrfSignature* -> *Result<VrfOutput, Error>*
+//! - **Hd**
+//!     - path
+//!         - DerivationPath::from_str *&str* -> *Result<DerivationPath, Error>*
+//!         - DerivationPath::new *&[u32]* -> *Result<DerivationPath, Error>*
+//!     - slip10
+//!         - ExtendedKey::from_seed *&[u8]* -> *ExtendedKey*
+//!         - ExtendedKey::derive_child *u32* -> *Result<ExtendedKey, Error>*
+//!         - ExtendedKey::derive *&DerivationPath* -> *ExtendedKey*
+//!         - ExtendedKey::key_pair -> *KeyPair*
+//! - **Lunes**
+//!     - address
+//!         - Address::from_public_key *&PublicKey, ChainId* -> *Address*
+//!         - Address::from_bytes *&[u8]* -> *Result<Address, Error>*
+//!         - Address::from_base58 *&str* -> *Result<Address, Error>*
+//!         - Address::to_base58 -> *String*
+//!         - Address::chain_id -> *ChainId*
+//!         - Alias::new *&str, ChainId* -> *Result<Alias, Error>*
+//!     - seed
+//!         - KeyPair::from_lunes_seed_phrase *&str, u32* -> *KeyPair*
+//!         - account_seed *&str, u32* -> *[u8; 32]*
+//!     - tx
+//!         - Transaction::from *Issue, Transfer, Reissue, Burn, Lease, CancelLease, CreateAlias, MassTransfer or Data*
+//!         - Transaction::body_bytes -> *Result<Vec<u8>, Error>*
+//!         - Transaction::from_body_bytes *&[u8], u8* -> *Result<Transaction, Error>*
+//!         - Transaction::id -> *Result<[u8; 32], Error>*
+//!         - Transaction::sign *&KeyPair* -> *Result<SignedTransaction, Error>*
+//!         - Transaction::sign_with_rng *&KeyPair, &mut (CryptoRng + RngCore)* -> *Result<SignedTransaction, Error>*
+//!         - SignedTransaction::verify -> *Result<(), Error>*
+//! - **Mnemonic**
+//!     - phrase
+//!         - SeedPhrase::generate -> *SeedPhrase*
+//!         - SeedPhrase::generate_with *&mut (CryptoRng + RngCore)* -> *SeedPhrase*
+//!         - SeedPhrase::from_phrase *&str* -> *Result<SeedPhrase, Error>*
+//!         - SeedPhrase::expose_phrase -> *&str*
+//!         - SeedPhrase::to_key_pair -> *KeyPair*
+//!     - bip39
+//!         - Mnemonic::from_entropy *&[u8]* -> *Result<Mnemonic, Error>*
+//!         - Mnemonic::from_phrase *&str* -> *Result<Mnemonic, Error>*
+//!         - Mnemonic::entropy -> *&[u8]*
+//!         - Mnemonic::to_seed *&str* -> *[u8; 64]*
+//!         - Mnemonic::to_key_pair *&str* -> *KeyPair*
+//! - **Protocol**
+//!     - ratchet
+//!         - Session::initiate *&SharedSecret, &PublicKey, Options* -> *Result<Session, Error>*
+//!         - Session::respond *&SharedSecret, KeyPair, Options* -> *Session*
+//!         - Session::encrypt *&[u8], &[u8]* -> *Result<Message, Error>*
+//!         - Session::decrypt *&Message, &[u8]* -> *Result<Vec<u8>, Error>*
+//!         - Session::to_bytes -> *Vec<u8>*
+//!         - Session::from_bytes *&[u8]* -> *Result<Session, Error>*
+//!     - x3dh
+//!         - SignedPreKey::generate *&KeyPair, u32* -> *SignedPreKey*
+//!         - SignedPreKey::new_with_rng *&KeyPair, u32, KeyPair, &mut (CryptoRng + RngCore)* -> *SignedPreKey*
+//!         - OneTimePreKey::generate *u32* -> *OneTimePreKey*
+//!         - PreKeyBundle::new *&KeyPair, &SignedPreKey, Option<&OneTimePreKey>* -> *PreKeyBundle*
+//!         - PreKeyBundle::to_bytes -> *Vec<u8>*
+//!         - PreKeyBundle::from_bytes *&[u8]* -> *Result<PreKeyBundle, Error>*
+//!         - initiate *&KeyPair, &PreKeyBundle* -> *Result<(InitialMessage, Agreement), Error>*
+//!         - respond *&KeyPair, &SignedPreKey, Option<&OneTimePreKey>, &InitialMessage* -> *Result<Agreement, Error>*
+//!
+//! ## ⚡ Benchmarks
+//!
+//! Run with `cargo bench`, median times of the current tree on one core of an x86_64
+//! Xeon, the numbers move with the machine, compare runs of the same one:
 //!
+//! | benchmark | time |
+//! |---|---|
+//! | from_seed | 16.0 µs |
+//! | diffie_hellman | 48.8 µs |
+//! | fast_signature | 35.4 µs |
+//! | sign_deterministic | 34.7 µs |
+//! | verify | 63.9 µs |
+//! | verify_strict | 73.7 µs |
+//! | verify 64 one by one | 4.23 ms |
+//! | verify_batch 64 | 1.78 ms |
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 /// Cryptography functions
 pub mod crypto;
+/// Text encodings of keys and signatures
+#[cfg(feature = "alloc")]
+pub mod encoding;
+/// Errors of keys and signatures functions
+mod error;
+/// Hash functions
+pub mod hash;
+/// Hierarchical deterministic keys
+#[cfg(feature = "alloc")]
+pub mod hd;
+/// Formats of the Lunes blockchain
+#[cfg(feature = "alloc")]
+pub mod lunes;
+/// Seed phrases of wallets
+#[cfg(feature = "alloc")]
+pub mod mnemonic;
+/// Protocols built on the keys
+#[cfg(feature = "alloc")]
+pub mod protocol;
 /// Utils functions
 pub mod utils;
+
+#[cfg(feature = "alloc")]
+pub use error::BatchError;
+pub use error::Error;
+/// The `signature` crate of the implemented traits
+pub use signature;
@@ -0,0 +1,301 @@
+use crate::crypto::array;
+use crate::crypto::keys::PublicKey;
+use crate::encoding::base58;
+use crate::hash::{Blake2b256, Keccak256};
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// Version byte heading every address
+const VERSION: u8 = 1;
+
+/// # Network of an address
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ChainId {
+    /// ## Lunes mainnet, chain byte `'1'`
+    Mainnet,
+    /// ## Lunes testnet, chain byte `'0'`
+    Testnet,
+}
+
+impl ChainId {
+    /// ## Byte written in the address
+    pub fn byte(self) -> u8 {
+        match self {
+            ChainId::Mainnet => b'1',
+            ChainId::Testnet => b'0',
+        }
+    }
+}
+
+impl TryFrom<u8> for ChainId {
+    type Error = Error;
+
+    fn try_from(byte: u8) -> Result<ChainId, Error> {
+        match byte {
+            b'1' => Ok(ChainId::Mainnet),
+            b'0' => Ok(ChainId::Testnet),
+            id => Err(Error::UnknownChainId { id }),
+        }
+    }
+}
+
+/**
+# Address of a Lunes account
+
+- 26 bytes: version `1`, chain byte, the first 20 bytes of
+  Keccak-256(BLAKE2b-256(public key)) and a 4 byte checksum
+- The checksum is the first 4 bytes of Keccak-256(BLAKE2b-256) of the first 22 bytes
+- Written in Base58, mainnet addresses start with `37`
+
+## Example
+
+```rust
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::lunes::address::{Address, ChainId};
+
+let keys = KeyPair::from_seed(&[1; 32]);
+let address = Address::from_public_key(&keys.pubk, ChainId::Mainnet);
+
+assert_eq!("37wyAB6exbMYK37nVypJoSPGdiJeey522mY", address.to_string());
+assert_eq!(Ok(address), "37wyAB6exbMYK37nVypJoSPGdiJeey522mY".parse());
+```
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Address([u8; 26]);
+
+/// Keccak-256(BLAKE2b-256(*`data`*)), the secure hash of Lunes
+fn secure_hash(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(&Blake2b256::digest(data))
+}
+
+impl Address {
+    /// ## Derive the address of *`public_key`* on *`chain`*
+    pub fn from_public_key(public_key: &PublicKey, chain: ChainId) -> Address {
+        let mut address = [0u8; 26];
+        address[0] = VERSION;
+        address[1] = chain.byte();
+        address[2..22].copy_from_slice(&secure_hash(public_key.as_bytes())[..20]);
+
+        let checksum = secure_hash(&address[..22]);
+        address[22..].copy_from_slice(&checksum[..4]);
+        Address(address)
+    }
+
+    /**
+    ## Create an *Address* from raw bytes
+
+    - Fails when there are not 26 bytes, on another version than `1`,
+      on an unknown chain byte or on a wrong checksum
+
+    ```rust
+    use ed25519_axolotl::encoding::base58;
+    use ed25519_axolotl::lunes::address::Address;
+    use ed25519_axolotl::Error;
+
+    let mut bytes = base58::decode("37wyAB6exbMYK37nVypJoSPGdiJeey522mY").unwrap();
+    assert!(Address::from_bytes(&bytes).is_ok());
+
+    bytes[10] ^= 1;
+    assert_eq!(Err(Error::InvalidChecksum), Address::from_bytes(&bytes));
+    ```
+    */
+    pub fn from_bytes(bytes: &[u8]) -> Result<Address, Error> {
+        let address: [u8; 26] = array(bytes)?;
+
+        if address[0] != VERSION {
+            return Err(Error::InvalidEncoding);
+        }
+        ChainId::try_from(address[1])?;
+        if secure_hash(&address[..22])[..4] != address[22..] {
+            return Err(Error::InvalidChecksum);
+        }
+        Ok(Address(address))
+    }
+
+    /// ## Decode an *Address* from Base58, validated like [`Address::from_bytes`]
+    pub fn from_base58(text: &str) -> Result<Address, Error> {
+        Address::from_bytes(&base58::decode(text)?)
+    }
+
+    /// ## Encode the address in Base58
+    pub fn to_base58(&self) -> String {
+        base58::encode(&self.0)
+    }
+
+    /// ## Network of the address
+    pub fn chain_id(&self) -> ChainId {
+        match self.0[1] {
+            b'1' => ChainId::Mainnet,
+            _ => ChainId::Testnet,
+        }
+    }
+
+    /// ## Hash of the public key, the 20 bytes after version and chain
+    pub fn public_key_hash(&self) -> &[u8] {
+        &self.0[2..22]
+    }
+
+    /// ## Copy the address into a byte array
+    pub fn to_bytes(&self) -> [u8; 26] {
+        self.0
+    }
+
+    /// ## Borrow the address as a byte array
+    pub fn as_bytes(&self) -> &[u8; 26] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Address {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Address, Error> {
+        Address::from_bytes(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Address {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_base58())
+    }
+}
+
+impl FromStr for Address {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Address, Error> {
+        Address::from_base58(text)
+    }
+}
+
+/// Type byte heading the bytes of an alias
+const ALIAS_VERSION: u8 = 2;
+
+/// Characters allowed in the name of an alias
+const ALIAS_ALPHABET: &str = "-.0123456789@_abcdefghijklmnopqrstuvwxyz";
+
+/**
+# Alias of a Lunes account
+
+- A name of 4 to 30 characters among `-.0123456789@_a-z`, bound to an
+  address by a create alias transaction
+- Bytes: type `2`, chain byte, name length as 2 bytes big-endian and the name
+- Written `alias:<chain>:<name>`
+
+## Example
+
+```rust
+use ed25519_axolotl::lunes::address::{Alias, ChainId};
+
+let alias = Alias::new("lunes-pay", ChainId::Mainnet).unwrap();
+
+assert_eq!("alias:1:lunes-pay", alias.to_string());
+assert_eq!(Ok(alias), "alias:1:lunes-pay".parse());
+```
+*/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Alias {
+    chain: ChainId,
+    name: String,
+}
+
+impl Alias {
+    /**
+    ## Create an *Alias* named *`name`* on *`chain`*
+
+    - Fails with `InvalidLength` on names shorter than 4 or longer than 30
+      characters, `expected` being the bound, and with `InvalidCharacter`
+      outside of the alphabet
+
+    ```rust
+    use ed25519_axolotl::lunes::address::{Alias, ChainId};
+    use ed25519_axolotl::Error;
+
+    assert_eq!(
+        Err(Error::InvalidCharacter { index: 5, character: 'P' }),
+        Alias::new("lunesPay", ChainId::Mainnet)
+    );
+    ```
+    */
+    pub fn new(name: &str, chain: ChainId) -> Result<Alias, Error> {
+        if let Some((index, character)) = name
+            .char_indices()
+            .find(|(_, character)| !ALIAS_ALPHABET.contains(*character))
+        {
+            return Err(Error::InvalidCharacter { index, character });
+        }
+        if !(4..=30).contains(&name.len()) {
+            return Err(Error::InvalidLength {
+                expected: name.len().clamp(4, 30),
+                actual: name.len(),
+            });
+        }
+        Ok(Alias {
+            chain,
+            name: String::from(name),
+        })
+    }
+
+    /// ## Create an *Alias* from its bytes, validated like [`Alias::new`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Alias, Error> {
+        if bytes.len() < 4 || bytes[0] != ALIAS_VERSION {
+            return Err(Error::InvalidEncoding);
+        }
+        let chain = ChainId::try_from(bytes[1])?;
+        let len = u16::from_be_bytes([bytes[2], bytes[3]]) as usize;
+        if bytes.len() != 4 + len {
+            return Err(Error::InvalidEncoding);
+        }
+        let name = core::str::from_utf8(&bytes[4..]).map_err(|_| Error::InvalidEncoding)?;
+        Alias::new(name, chain)
+    }
+
+    /// ## Bytes of the alias, as written in transactions
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + self.name.len());
+        bytes.push(ALIAS_VERSION);
+        bytes.push(self.chain.byte());
+        bytes.extend_from_slice(&(self.name.len() as u16).to_be_bytes());
+        bytes.extend_from_slice(self.name.as_bytes());
+        bytes
+    }
+
+    /// ## Name of the alias
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// ## Network of the alias
+    pub fn chain_id(&self) -> ChainId {
+        self.chain
+    }
+}
+
+impl fmt::Display for Alias {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "alias:{}:{}", self.chain.byte() as char, self.name)
+    }
+}
+
+impl FromStr for Alias {
+    type Err = Error;
+
+    /// Parse `alias:<chain>:<name>`
+    fn from_str(text: &str) -> Result<Alias, Error> {
+        let rest = text.strip_prefix("alias:").ok_or(Error::InvalidEncoding)?;
+        let (chain, name) = rest.split_once(':').ok_or(Error::InvalidEncoding)?;
+        match chain.as_bytes() {
+            [byte] => Alias::new(name, ChainId::try_from(*byte)?),
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+/// Addresses and aliases of Lunes accounts
+pub mod address;
+/// Accounts of Lunes wallet seed phrases
+pub mod seed;
+/// Transactions of the Lunes blockchain
+pub mod tx;
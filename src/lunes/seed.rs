@@ -0,0 +1,56 @@
+use crate::crypto::keys::KeyPair;
+use crate::hash::{Blake2b256, Keccak256, Sha256};
+use zeroize::Zeroize;
+
+/**
+# Account seed of a Lunes seed phrase
+
+- Keccak-256(BLAKE2b-256(nonce ‖ phrase)), with the nonce in 4 big endian bytes
+- The phrase is hashed as its UTF-8 bytes, exactly as typed
+
+## Example
+
+```rust
+use ed25519_axolotl::lunes::seed::account_seed;
+
+assert_ne!(account_seed("lunes", 0), account_seed("lunes", 1));
+```
+*/
+pub fn account_seed(phrase: &str, nonce: u32) -> [u8; 32] {
+    let mut hasher = Blake2b256::new();
+    hasher.update(&nonce.to_be_bytes());
+    hasher.update(phrase.as_bytes());
+
+    let mut blake = hasher.finalize();
+    let seed = Keccak256::digest(&blake);
+    blake.zeroize();
+    seed
+}
+
+impl KeyPair {
+    /**
+    ## Derive the *KeyPair* of a Lunes wallet seed phrase
+
+    - The secret key is the SHA-256 of the [`account_seed`], clamped by [`KeyPair::from_seed`]
+    - The official wallet derives its first account with nonce 0
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::lunes::address::{Address, ChainId};
+
+    let phrase = "uncle push human bus echo drastic garden joke sand warfare sentence fossil title color combine";
+    let keys = KeyPair::from_lunes_seed_phrase(phrase, 0);
+    let address = Address::from_public_key(&keys.pubk, ChainId::Mainnet);
+
+    assert_eq!("37rUVcv62Z64ATSqTPwiA8nYnQaFaWGYWMD", address.to_string());
+    ```
+    */
+    pub fn from_lunes_seed_phrase(phrase: &str, nonce: u32) -> KeyPair {
+        let mut seed = account_seed(phrase, nonce);
+        let mut sk = Sha256::digest(&seed);
+        let keys = KeyPair::from_seed(&sk);
+        seed.zeroize();
+        sk.zeroize();
+        keys
+    }
+}
@@ -0,0 +1,51 @@
+use super::bytes::{put_short_bytes, Reader};
+use super::{put_header, read_header, Body};
+use crate::crypto::keys::PublicKey;
+use crate::lunes::address::Alias;
+use crate::Error;
+use alloc::vec::Vec;
+
+/**
+# Bind an alias to the address of the sender, type `10`
+
+- Version 1: type, sender, length of the alias bytes as 2 bytes, alias bytes, fee, timestamp
+- Version 2: type, version, then as version 1
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CreateAlias {
+    /// ## Version 1 or 2
+    pub version: u8,
+    /// ## Public key of the account
+    pub sender: PublicKey,
+    /// ## Alias bound to the account
+    pub alias: Alias,
+    /// ## Fee in LUNES units
+    pub fee: u64,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl Body for CreateAlias {
+    const TYPE: u8 = 10;
+    const VERSIONS: u8 = 2;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        out.extend_from_slice(self.sender.as_bytes());
+        put_short_bytes(out, &self.alias.to_bytes())?;
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        Ok(())
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<CreateAlias, Error> {
+        read_header::<Self>(reader, version)?;
+        Ok(CreateAlias {
+            version,
+            sender: reader.public_key()?,
+            alias: Alias::from_bytes(reader.short_bytes()?)?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        })
+    }
+}
@@ -0,0 +1,208 @@
+use super::bytes::{put_short_bytes, Reader};
+use super::{put_header, read_header, Body};
+use crate::crypto::keys::PublicKey;
+use crate::lunes::address::ChainId;
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/**
+# Issue a new asset, type `3`
+
+- Version 1: type, sender, name, description, quantity, decimals, reissuable, fee, timestamp
+- Version 2: type, version, chain byte, then as version 1 and an absent script byte `0`
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Issue {
+    /// ## Version 1 or 2
+    pub version: u8,
+    /// ## Network, only written from version 2 on
+    pub chain: ChainId,
+    /// ## Public key of the issuer
+    pub sender: PublicKey,
+    /// ## Name of the asset, UTF-8
+    pub name: String,
+    /// ## Description of the asset, UTF-8
+    pub description: String,
+    /// ## Number of units issued
+    pub quantity: u64,
+    /// ## Decimal places of a unit
+    pub decimals: u8,
+    /// ## Whether more units can be issued later
+    pub reissuable: bool,
+    /// ## Fee in LUNES units
+    pub fee: u64,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl Body for Issue {
+    const TYPE: u8 = 3;
+    const VERSIONS: u8 = 2;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        if self.version == 2 {
+            out.push(self.chain.byte());
+        }
+        out.extend_from_slice(self.sender.as_bytes());
+        put_short_bytes(out, self.name.as_bytes())?;
+        put_short_bytes(out, self.description.as_bytes())?;
+        out.extend_from_slice(&self.quantity.to_be_bytes());
+        out.push(self.decimals);
+        out.push(self.reissuable as u8);
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        if self.version == 2 {
+            out.push(0);
+        }
+        Ok(())
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<Issue, Error> {
+        read_header::<Self>(reader, version)?;
+        let chain = match version {
+            2 => ChainId::try_from(reader.u8()?)?,
+            _ => ChainId::Mainnet,
+        };
+        let issue = Issue {
+            version,
+            chain,
+            sender: reader.public_key()?,
+            name: reader.string()?,
+            description: reader.string()?,
+            quantity: reader.u64()?,
+            decimals: reader.u8()?,
+            reissuable: reader.bool()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        };
+        if version == 2 && reader.bool()? {
+            return Err(Error::UnsupportedTransaction {
+                type_id: Self::TYPE,
+                version,
+            });
+        }
+        Ok(issue)
+    }
+}
+
+/**
+# Issue more units of a reissuable asset, type `5`
+
+- Version 1: type, sender, asset, quantity, reissuable, fee, timestamp
+- Version 2: type, version, chain byte, then as version 1
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Reissue {
+    /// ## Version 1 or 2
+    pub version: u8,
+    /// ## Network, only written from version 2 on
+    pub chain: ChainId,
+    /// ## Public key of the issuer
+    pub sender: PublicKey,
+    /// ## Id of the asset
+    pub asset: [u8; 32],
+    /// ## Number of units added
+    pub quantity: u64,
+    /// ## Whether more units can be issued later
+    pub reissuable: bool,
+    /// ## Fee in LUNES units
+    pub fee: u64,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl Body for Reissue {
+    const TYPE: u8 = 5;
+    const VERSIONS: u8 = 2;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        if self.version == 2 {
+            out.push(self.chain.byte());
+        }
+        out.extend_from_slice(self.sender.as_bytes());
+        out.extend_from_slice(&self.asset);
+        out.extend_from_slice(&self.quantity.to_be_bytes());
+        out.push(self.reissuable as u8);
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        Ok(())
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<Reissue, Error> {
+        read_header::<Self>(reader, version)?;
+        Ok(Reissue {
+            version,
+            chain: match version {
+                2 => ChainId::try_from(reader.u8()?)?,
+                _ => ChainId::Mainnet,
+            },
+            sender: reader.public_key()?,
+            asset: reader.array()?,
+            quantity: reader.u64()?,
+            reissuable: reader.bool()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        })
+    }
+}
+
+/**
+# Destroy units of an asset, type `6`
+
+- Version 1: type, sender, asset, quantity, fee, timestamp
+- Version 2: type, version, chain byte, then as version 1
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Burn {
+    /// ## Version 1 or 2
+    pub version: u8,
+    /// ## Network, only written from version 2 on
+    pub chain: ChainId,
+    /// ## Public key of the holder
+    pub sender: PublicKey,
+    /// ## Id of the asset
+    pub asset: [u8; 32],
+    /// ## Number of units destroyed
+    pub quantity: u64,
+    /// ## Fee in LUNES units
+    pub fee: u64,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl Body for Burn {
+    const TYPE: u8 = 6;
+    const VERSIONS: u8 = 2;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        if self.version == 2 {
+            out.push(self.chain.byte());
+        }
+        out.extend_from_slice(self.sender.as_bytes());
+        out.extend_from_slice(&self.asset);
+        out.extend_from_slice(&self.quantity.to_be_bytes());
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        Ok(())
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<Burn, Error> {
+        read_header::<Self>(reader, version)?;
+        Ok(Burn {
+            version,
+            chain: match version {
+                2 => ChainId::try_from(reader.u8()?)?,
+                _ => ChainId::Mainnet,
+            },
+            sender: reader.public_key()?,
+            asset: reader.array()?,
+            quantity: reader.u64()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use super::Recipient;
+use crate::crypto::array;
+use crate::crypto::keys::PublicKey;
+use crate::lunes::address::{Address, Alias};
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Cursor over the body of a transaction, failing with `InvalidEncoding` when short
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Reader<'a> {
+        Reader { bytes }
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.bytes.len() < len {
+            return Err(Error::InvalidEncoding);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        array(self.take(N)?)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_be_bytes(self.array()?))
+    }
+
+    pub(crate) fn bool(&mut self) -> Result<bool, Error> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+
+    /// Bytes behind a 2 bytes length
+    pub(crate) fn short_bytes(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.u16()? as usize;
+        self.take(len)
+    }
+
+    /// UTF-8 text behind a 2 bytes length
+    pub(crate) fn string(&mut self) -> Result<String, Error> {
+        let bytes = self.short_bytes()?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| Error::InvalidEncoding)
+    }
+
+    pub(crate) fn public_key(&mut self) -> Result<PublicKey, Error> {
+        Ok(PublicKey::from_bytes(self.array()?))
+    }
+
+    /// Asset behind a flag byte, `None` for LUNES
+    pub(crate) fn asset(&mut self) -> Result<Option<[u8; 32]>, Error> {
+        match self.bool()? {
+            false => Ok(None),
+            true => Ok(Some(self.array()?)),
+        }
+    }
+
+    pub(crate) fn recipient(&mut self) -> Result<Recipient, Error> {
+        match self.bytes.first() {
+            Some(1) => Ok(Recipient::Address(Address::from_bytes(self.take(26)?)?)),
+            Some(2) => {
+                let len = u16::from_be_bytes(array(self.bytes.get(2..4).unwrap_or(&[]))?);
+                let bytes = self.take(4 + len as usize)?;
+                Ok(Recipient::Alias(Alias::from_bytes(bytes)?))
+            }
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+
+    /// Fail on trailing bytes
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        match self.bytes.is_empty() {
+            true => Ok(()),
+            false => Err(Error::InvalidEncoding),
+        }
+    }
+}
+
+/// Append *`bytes`* behind a 2 bytes length
+pub(crate) fn put_short_bytes(out: &mut Vec<u8>, bytes: &[u8]) -> Result<(), Error> {
+    let len = u16::try_from(bytes.len()).map_err(|_| Error::InvalidLength {
+        expected: u16::MAX as usize,
+        actual: bytes.len(),
+    })?;
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(bytes);
+    Ok(())
+}
+
+/// Append an asset behind a flag byte, `None` for LUNES
+pub(crate) fn put_asset(out: &mut Vec<u8>, asset: &Option<[u8; 32]>) {
+    match asset {
+        None => out.push(0),
+        Some(id) => {
+            out.push(1);
+            out.extend_from_slice(id);
+        }
+    }
+}
+
+/// Count of a list as 2 bytes
+pub(crate) fn put_count(out: &mut Vec<u8>, count: usize) -> Result<(), Error> {
+    let count = u16::try_from(count).map_err(|_| Error::InvalidLength {
+        expected: u16::MAX as usize,
+        actual: count,
+    })?;
+    out.extend_from_slice(&count.to_be_bytes());
+    Ok(())
+}
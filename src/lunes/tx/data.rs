@@ -0,0 +1,108 @@
+use super::bytes::{put_count, put_short_bytes, Reader};
+use super::{put_header, read_header, Body};
+use crate::crypto::keys::PublicKey;
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// # Value of a data entry
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DataValue {
+    /// ## Type byte `0`, 8 bytes big-endian
+    Integer(i64),
+    /// ## Type byte `1`, one byte
+    Boolean(bool),
+    /// ## Type byte `2`, length as 2 bytes and the bytes
+    Binary(Vec<u8>),
+    /// ## Type byte `3`, length as 2 bytes and the UTF-8 bytes
+    String(String),
+}
+
+/// # Key and value stored in the account of the sender
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DataEntry {
+    /// ## Key, UTF-8 behind its length as 2 bytes
+    pub key: String,
+    /// ## Typed value
+    pub value: DataValue,
+}
+
+/**
+# Store entries in the account of the sender, type `12`
+
+- Version 1: type, version, sender, count of entries as 2 bytes, entries, timestamp, fee
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Data {
+    /// ## Version 1
+    pub version: u8,
+    /// ## Public key of the account
+    pub sender: PublicKey,
+    /// ## Entries written
+    pub entries: Vec<DataEntry>,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// ## Fee in LUNES units
+    pub fee: u64,
+}
+
+impl Body for Data {
+    const TYPE: u8 = 12;
+    const VERSIONS: u8 = 1;
+    const VERSIONED: bool = true;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        out.extend_from_slice(self.sender.as_bytes());
+        put_count(out, self.entries.len())?;
+        for entry in &self.entries {
+            put_short_bytes(out, entry.key.as_bytes())?;
+            match &entry.value {
+                DataValue::Integer(value) => {
+                    out.push(0);
+                    out.extend_from_slice(&value.to_be_bytes());
+                }
+                DataValue::Boolean(value) => {
+                    out.push(1);
+                    out.push(*value as u8);
+                }
+                DataValue::Binary(value) => {
+                    out.push(2);
+                    put_short_bytes(out, value)?;
+                }
+                DataValue::String(value) => {
+                    out.push(3);
+                    put_short_bytes(out, value.as_bytes())?;
+                }
+            }
+        }
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        Ok(())
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<Data, Error> {
+        read_header::<Self>(reader, version)?;
+        let sender = reader.public_key()?;
+        let count = reader.u16()?;
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            let key = reader.string()?;
+            let value = match reader.u8()? {
+                0 => DataValue::Integer(reader.u64()? as i64),
+                1 => DataValue::Boolean(reader.bool()?),
+                2 => DataValue::Binary(reader.short_bytes()?.to_vec()),
+                3 => DataValue::String(reader.string()?),
+                _ => return Err(Error::InvalidEncoding),
+            };
+            entries.push(DataEntry { key, value });
+        }
+        Ok(Data {
+            version,
+            sender,
+            entries,
+            timestamp: reader.u64()?,
+            fee: reader.u64()?,
+        })
+    }
+}
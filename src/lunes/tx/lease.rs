@@ -0,0 +1,115 @@
+use super::bytes::Reader;
+use super::{put_header, read_header, Body, Recipient};
+use crate::crypto::keys::PublicKey;
+use crate::lunes::address::ChainId;
+use crate::Error;
+use alloc::vec::Vec;
+
+/**
+# Lease LUNES to a node for mining, type `8`
+
+- Version 1: type, sender, recipient, amount, fee, timestamp
+- Version 2: type, version, asset byte `0` for LUNES, then as version 1
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Lease {
+    /// ## Version 1 or 2
+    pub version: u8,
+    /// ## Public key of the lessor
+    pub sender: PublicKey,
+    /// ## Address or alias of the node
+    pub recipient: Recipient,
+    /// ## LUNES units leased
+    pub amount: u64,
+    /// ## Fee in LUNES units
+    pub fee: u64,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl Body for Lease {
+    const TYPE: u8 = 8;
+    const VERSIONS: u8 = 2;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        if self.version == 2 {
+            out.push(0);
+        }
+        out.extend_from_slice(self.sender.as_bytes());
+        self.recipient.write(out);
+        out.extend_from_slice(&self.amount.to_be_bytes());
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        Ok(())
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<Lease, Error> {
+        read_header::<Self>(reader, version)?;
+        if version == 2 && reader.u8()? != 0 {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok(Lease {
+            version,
+            sender: reader.public_key()?,
+            recipient: reader.recipient()?,
+            amount: reader.u64()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+        })
+    }
+}
+
+/**
+# Cancel a lease, type `9`
+
+- Version 1: type, sender, fee, timestamp, lease id
+- Version 2: type, version, chain byte, then as version 1
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CancelLease {
+    /// ## Version 1 or 2
+    pub version: u8,
+    /// ## Network, only written from version 2 on
+    pub chain: ChainId,
+    /// ## Public key of the lessor
+    pub sender: PublicKey,
+    /// ## Fee in LUNES units
+    pub fee: u64,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// ## Id of the lease transaction
+    pub lease: [u8; 32],
+}
+
+impl Body for CancelLease {
+    const TYPE: u8 = 9;
+    const VERSIONS: u8 = 2;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        if self.version == 2 {
+            out.push(self.chain.byte());
+        }
+        out.extend_from_slice(self.sender.as_bytes());
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        out.extend_from_slice(&self.lease);
+        Ok(())
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<CancelLease, Error> {
+        read_header::<Self>(reader, version)?;
+        Ok(CancelLease {
+            version,
+            chain: match version {
+                2 => ChainId::try_from(reader.u8()?)?,
+                _ => ChainId::Mainnet,
+            },
+            sender: reader.public_key()?,
+            fee: reader.u64()?,
+            timestamp: reader.u64()?,
+            lease: reader.array()?,
+        })
+    }
+}
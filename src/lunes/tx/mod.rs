@@ -0,0 +1,394 @@
+/// Bind aliases to addresses
+mod alias;
+/// Issue, reissue and burn assets
+mod asset;
+/// Reading and writing of transaction fields
+mod bytes;
+/// Entries stored in accounts
+mod data;
+/// Lease LUNES to nodes
+mod lease;
+/// Transfers of LUNES and assets
+mod transfer;
+
+pub use alias::CreateAlias;
+pub use asset::{Burn, Issue, Reissue};
+pub use data::{Data, DataEntry, DataValue};
+pub use lease::{CancelLease, Lease};
+pub use transfer::{MassTransfer, Transfer};
+
+use crate::crypto::keys::{KeyPair, PublicKey};
+use crate::crypto::signatures::Signature;
+use crate::hash::Blake2b256;
+use crate::lunes::address::{Address, Alias};
+use crate::utils::random::{CryptoRng, RngCore};
+use crate::Error;
+use alloc::vec::Vec;
+use bytes::Reader;
+
+/// # Receiver of a transfer or a lease
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Recipient {
+    /// ## Written as the 26 bytes of the address
+    Address(Address),
+    /// ## Written as the bytes of the alias
+    Alias(Alias),
+}
+
+impl Recipient {
+    fn write(&self, out: &mut Vec<u8>) {
+        match self {
+            Recipient::Address(address) => out.extend_from_slice(address.as_bytes()),
+            Recipient::Alias(alias) => out.extend_from_slice(&alias.to_bytes()),
+        }
+    }
+}
+
+impl From<Address> for Recipient {
+    fn from(address: Address) -> Recipient {
+        Recipient::Address(address)
+    }
+}
+
+impl From<Alias> for Recipient {
+    fn from(alias: Alias) -> Recipient {
+        Recipient::Alias(alias)
+    }
+}
+
+/// Binary body of one transaction type
+pub(crate) trait Body: Sized {
+    /// Type byte
+    const TYPE: u8;
+    /// Highest supported version, versions start at 1
+    const VERSIONS: u8;
+    /// Whether version 1 also writes its version byte
+    const VERSIONED: bool = false;
+
+    /// Append the body, from the type byte on
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error>;
+
+    /// Read the body of *`version`*, from the type byte on
+    fn read(reader: &mut Reader, version: u8) -> Result<Self, Error>;
+}
+
+/// Check *`version`* and append the type byte, then the version byte when written
+fn put_header<T: Body>(out: &mut Vec<u8>, version: u8) -> Result<(), Error> {
+    if !(1..=T::VERSIONS).contains(&version) {
+        return Err(Error::UnsupportedTransaction {
+            type_id: T::TYPE,
+            version,
+        });
+    }
+    out.push(T::TYPE);
+    if T::VERSIONED || version > 1 {
+        out.push(version);
+    }
+    Ok(())
+}
+
+/// Check *`version`* and read the type byte, then the version byte when written
+fn read_header<T: Body>(reader: &mut Reader, version: u8) -> Result<(), Error> {
+    if reader.u8()? != T::TYPE {
+        return Err(Error::InvalidEncoding);
+    }
+    if (T::VERSIONED || version > 1) && reader.u8()? != version {
+        return Err(Error::InvalidEncoding);
+    }
+    Ok(())
+}
+
+/**
+# Transaction of the Lunes blockchain
+
+- The body bytes are signed, their BLAKE2b-256 hash is the transaction id
+- Version 1 of types 3 to 10 has no version byte, so [`Transaction::from_body_bytes`]
+  takes the version apart
+
+## Example
+
+```rust
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::lunes::tx::{Recipient, Transaction, Transfer};
+use ed25519_axolotl::utils::random::OsRng;
+
+let keys = KeyPair::from_seed(&[1; 32]);
+let recipient = "37YdZBzMftB5SDgeQxPyUxFzr5oPiHiTYcY".parse().unwrap();
+
+let transfer = Transaction::from(Transfer {
+    version: 1,
+    sender: keys.pubk,
+    asset: None,
+    fee_asset: None,
+    timestamp: 1_650_000_000_000,
+    amount: 100_000_000,
+    fee: 1_000_000,
+    recipient: Recipient::Address(recipient),
+    attachment: b"Lunes".to_vec(),
+});
+
+let signed = transfer.sign_with_rng(&keys, &mut OsRng).unwrap();
+assert_eq!(Ok(()), signed.verify());
+
+let body = signed.transaction.body_bytes().unwrap();
+assert_eq!(Ok(signed.transaction), Transaction::from_body_bytes(&body, 1));
+```
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Transaction {
+    /// ## Type `3`
+    Issue(Issue),
+    /// ## Type `4`
+    Transfer(Transfer),
+    /// ## Type `5`
+    Reissue(Reissue),
+    /// ## Type `6`
+    Burn(Burn),
+    /// ## Type `8`
+    Lease(Lease),
+    /// ## Type `9`
+    CancelLease(CancelLease),
+    /// ## Type `10`
+    CreateAlias(CreateAlias),
+    /// ## Type `11`
+    MassTransfer(MassTransfer),
+    /// ## Type `12`
+    Data(Data),
+}
+
+impl Transaction {
+    /// ## Type byte of the transaction
+    pub fn type_id(&self) -> u8 {
+        match self {
+            Transaction::Issue(_) => Issue::TYPE,
+            Transaction::Transfer(_) => Transfer::TYPE,
+            Transaction::Reissue(_) => Reissue::TYPE,
+            Transaction::Burn(_) => Burn::TYPE,
+            Transaction::Lease(_) => Lease::TYPE,
+            Transaction::CancelLease(_) => CancelLease::TYPE,
+            Transaction::CreateAlias(_) => CreateAlias::TYPE,
+            Transaction::MassTransfer(_) => MassTransfer::TYPE,
+            Transaction::Data(_) => Data::TYPE,
+        }
+    }
+
+    /// ## Version of the transaction
+    pub fn version(&self) -> u8 {
+        match self {
+            Transaction::Issue(tx) => tx.version,
+            Transaction::Transfer(tx) => tx.version,
+            Transaction::Reissue(tx) => tx.version,
+            Transaction::Burn(tx) => tx.version,
+            Transaction::Lease(tx) => tx.version,
+            Transaction::CancelLease(tx) => tx.version,
+            Transaction::CreateAlias(tx) => tx.version,
+            Transaction::MassTransfer(tx) => tx.version,
+            Transaction::Data(tx) => tx.version,
+        }
+    }
+
+    /// ## Public key of the sender, who signs the transaction
+    pub fn sender(&self) -> &PublicKey {
+        match self {
+            Transaction::Issue(tx) => &tx.sender,
+            Transaction::Transfer(tx) => &tx.sender,
+            Transaction::Reissue(tx) => &tx.sender,
+            Transaction::Burn(tx) => &tx.sender,
+            Transaction::Lease(tx) => &tx.sender,
+            Transaction::CancelLease(tx) => &tx.sender,
+            Transaction::CreateAlias(tx) => &tx.sender,
+            Transaction::MassTransfer(tx) => &tx.sender,
+            Transaction::Data(tx) => &tx.sender,
+        }
+    }
+
+    /**
+    ## Serialize the body of the transaction, the bytes that are signed
+
+    - Fails with `UnsupportedTransaction` on an unknown version and with
+      `InvalidLength` on a field or list longer than its 2 bytes length allows
+    */
+    pub fn body_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut out = Vec::with_capacity(256);
+        match self {
+            Transaction::Issue(tx) => tx.write(&mut out),
+            Transaction::Transfer(tx) => tx.write(&mut out),
+            Transaction::Reissue(tx) => tx.write(&mut out),
+            Transaction::Burn(tx) => tx.write(&mut out),
+            Transaction::Lease(tx) => tx.write(&mut out),
+            Transaction::CancelLease(tx) => tx.write(&mut out),
+            Transaction::CreateAlias(tx) => tx.write(&mut out),
+            Transaction::MassTransfer(tx) => tx.write(&mut out),
+            Transaction::Data(tx) => tx.write(&mut out),
+        }?;
+        Ok(out)
+    }
+
+    /**
+    ## Parse the body of a transaction of *`version`*
+
+    - Fails with `UnsupportedTransaction` on an unknown type or version and
+      with `InvalidEncoding` on malformed or trailing bytes
+    */
+    pub fn from_body_bytes(bytes: &[u8], version: u8) -> Result<Transaction, Error> {
+        fn read<T: Body>(bytes: &[u8], version: u8) -> Result<T, Error> {
+            if !(1..=T::VERSIONS).contains(&version) {
+                return Err(Error::UnsupportedTransaction {
+                    type_id: T::TYPE,
+                    version,
+                });
+            }
+            let mut reader = Reader::new(bytes);
+            let body = T::read(&mut reader, version)?;
+            reader.finish()?;
+            Ok(body)
+        }
+
+        match bytes.first() {
+            Some(&Issue::TYPE) => read(bytes, version).map(Transaction::Issue),
+            Some(&Transfer::TYPE) => read(bytes, version).map(Transaction::Transfer),
+            Some(&Reissue::TYPE) => read(bytes, version).map(Transaction::Reissue),
+            Some(&Burn::TYPE) => read(bytes, version).map(Transaction::Burn),
+            Some(&Lease::TYPE) => read(bytes, version).map(Transaction::Lease),
+            Some(&CancelLease::TYPE) => read(bytes, version).map(Transaction::CancelLease),
+            Some(&CreateAlias::TYPE) => read(bytes, version).map(Transaction::CreateAlias),
+            Some(&MassTransfer::TYPE) => read(bytes, version).map(Transaction::MassTransfer),
+            Some(&Data::TYPE) => read(bytes, version).map(Transaction::Data),
+            Some(&type_id) => Err(Error::UnsupportedTransaction { type_id, version }),
+            None => Err(Error::InvalidEncoding),
+        }
+    }
+
+    /**
+    ## Id of the transaction, BLAKE2b-256 of the body bytes
+
+    - As in Waves, the id of a *CreateAlias* of either version is the BLAKE2b-256
+      of its type followed by the alias bytes, so it does not depend on the sender
+    */
+    pub fn id(&self) -> Result<[u8; 32], Error> {
+        match self {
+            Transaction::CreateAlias(tx) => {
+                let mut hasher = Blake2b256::new();
+                hasher.update(&[CreateAlias::TYPE]);
+                hasher.update(&tx.alias.to_bytes());
+                Ok(hasher.finalize())
+            }
+            _ => Ok(Blake2b256::digest(&self.body_bytes()?)),
+        }
+    }
+
+    /**
+    ## Sign the transaction with the key pair of its sender
+
+    - The proof is a `fast_signature` of the body bytes
+    - Fails with `SignerMismatch` when *`keys`* is not the key pair of the sender
+    */
+    #[cfg(feature = "getrandom")]
+    pub fn sign(self, keys: &KeyPair) -> Result<SignedTransaction, Error> {
+        use crate::utils::random::OsRng;
+
+        self.sign_with_rng(keys, &mut OsRng)
+    }
+
+    /// ## Sign the transaction with your own random generator
+    pub fn sign_with_rng<R: CryptoRng + RngCore>(
+        self,
+        keys: &KeyPair,
+        rng: &mut R,
+    ) -> Result<SignedTransaction, Error> {
+        if keys.pubk != *self.sender() {
+            return Err(Error::SignerMismatch);
+        }
+        let proof = keys.fast_signature_with_rng(&self.body_bytes()?, rng);
+        Ok(SignedTransaction {
+            transaction: self,
+            proofs: alloc::vec![proof],
+        })
+    }
+}
+
+impl From<Issue> for Transaction {
+    fn from(tx: Issue) -> Transaction {
+        Transaction::Issue(tx)
+    }
+}
+
+impl From<Transfer> for Transaction {
+    fn from(tx: Transfer) -> Transaction {
+        Transaction::Transfer(tx)
+    }
+}
+
+impl From<Reissue> for Transaction {
+    fn from(tx: Reissue) -> Transaction {
+        Transaction::Reissue(tx)
+    }
+}
+
+impl From<Burn> for Transaction {
+    fn from(tx: Burn) -> Transaction {
+        Transaction::Burn(tx)
+    }
+}
+
+impl From<Lease> for Transaction {
+    fn from(tx: Lease) -> Transaction {
+        Transaction::Lease(tx)
+    }
+}
+
+impl From<CancelLease> for Transaction {
+    fn from(tx: CancelLease) -> Transaction {
+        Transaction::CancelLease(tx)
+    }
+}
+
+impl From<CreateAlias> for Transaction {
+    fn from(tx: CreateAlias) -> Transaction {
+        Transaction::CreateAlias(tx)
+    }
+}
+
+impl From<MassTransfer> for Transaction {
+    fn from(tx: MassTransfer) -> Transaction {
+        Transaction::MassTransfer(tx)
+    }
+}
+
+impl From<Data> for Transaction {
+    fn from(tx: Data) -> Transaction {
+        Transaction::Data(tx)
+    }
+}
+
+/**
+# Transaction with its proofs
+
+- Version 1 of types 3 to 10 carries its only proof as the signature field
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SignedTransaction {
+    /// ## Signed transaction
+    pub transaction: Transaction,
+    /// ## Signatures of the body bytes, the first one by the sender
+    pub proofs: Vec<Signature>,
+}
+
+impl SignedTransaction {
+    /// ## Id of the transaction, proofs are not part of it
+    pub fn id(&self) -> Result<[u8; 32], Error> {
+        self.transaction.id()
+    }
+
+    /**
+    ## Verify the first proof against the sender
+
+    - Fails with `InvalidSignature` when there is no proof or it does not match
+    */
+    pub fn verify(&self) -> Result<(), Error> {
+        let proof = self.proofs.first().ok_or(Error::InvalidSignature)?;
+        self.transaction
+            .sender()
+            .verify(&self.transaction.body_bytes()?, proof)
+    }
+}
@@ -0,0 +1,129 @@
+use super::bytes::{put_asset, put_count, put_short_bytes, Reader};
+use super::{put_header, read_header, Body, Recipient};
+use crate::crypto::keys::PublicKey;
+use crate::Error;
+use alloc::vec::Vec;
+
+/**
+# Transfer LUNES or an asset, type `4`
+
+- Version 1: type, sender, asset, fee asset, timestamp, amount, fee, recipient, attachment
+- Version 2: type, version, then as version 1
+- Assets are a byte `0` for LUNES or `1` followed by the 32 bytes of the asset id
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Transfer {
+    /// ## Version 1 or 2
+    pub version: u8,
+    /// ## Public key of the payer
+    pub sender: PublicKey,
+    /// ## Id of the asset sent, `None` for LUNES
+    pub asset: Option<[u8; 32]>,
+    /// ## Id of the asset paying the fee, `None` for LUNES
+    pub fee_asset: Option<[u8; 32]>,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// ## Units of the asset sent
+    pub amount: u64,
+    /// ## Fee in units of the fee asset
+    pub fee: u64,
+    /// ## Address or alias receiving the amount
+    pub recipient: Recipient,
+    /// ## Free bytes attached to the transfer
+    pub attachment: Vec<u8>,
+}
+
+impl Body for Transfer {
+    const TYPE: u8 = 4;
+    const VERSIONS: u8 = 2;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        out.extend_from_slice(self.sender.as_bytes());
+        put_asset(out, &self.asset);
+        put_asset(out, &self.fee_asset);
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        out.extend_from_slice(&self.amount.to_be_bytes());
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        self.recipient.write(out);
+        put_short_bytes(out, &self.attachment)
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<Transfer, Error> {
+        read_header::<Self>(reader, version)?;
+        Ok(Transfer {
+            version,
+            sender: reader.public_key()?,
+            asset: reader.asset()?,
+            fee_asset: reader.asset()?,
+            timestamp: reader.u64()?,
+            amount: reader.u64()?,
+            fee: reader.u64()?,
+            recipient: reader.recipient()?,
+            attachment: reader.short_bytes()?.to_vec(),
+        })
+    }
+}
+
+/**
+# Transfer LUNES or an asset to many recipients, type `11`
+
+- Version 1: type, version, sender, asset, count of transfers as 2 bytes,
+  recipient and amount of each transfer, timestamp, fee, attachment
+*/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MassTransfer {
+    /// ## Version 1
+    pub version: u8,
+    /// ## Public key of the payer
+    pub sender: PublicKey,
+    /// ## Id of the asset sent, `None` for LUNES
+    pub asset: Option<[u8; 32]>,
+    /// ## Recipient and amount of each transfer
+    pub transfers: Vec<(Recipient, u64)>,
+    /// ## Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// ## Fee in LUNES units
+    pub fee: u64,
+    /// ## Free bytes attached to the transfers
+    pub attachment: Vec<u8>,
+}
+
+impl Body for MassTransfer {
+    const TYPE: u8 = 11;
+    const VERSIONS: u8 = 1;
+    const VERSIONED: bool = true;
+
+    fn write(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        put_header::<Self>(out, self.version)?;
+        out.extend_from_slice(self.sender.as_bytes());
+        put_asset(out, &self.asset);
+        put_count(out, self.transfers.len())?;
+        for (recipient, amount) in &self.transfers {
+            recipient.write(out);
+            out.extend_from_slice(&amount.to_be_bytes());
+        }
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        put_short_bytes(out, &self.attachment)
+    }
+
+    fn read(reader: &mut Reader, version: u8) -> Result<MassTransfer, Error> {
+        read_header::<Self>(reader, version)?;
+        let sender = reader.public_key()?;
+        let asset = reader.asset()?;
+        let count = reader.u16()?;
+        let transfers = (0..count)
+            .map(|_| Ok((reader.recipient()?, reader.u64()?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(MassTransfer {
+            version,
+            sender,
+            asset,
+            transfers,
+            timestamp: reader.u64()?,
+            fee: reader.u64()?,
+            attachment: reader.short_bytes()?.to_vec(),
+        })
+    }
+}
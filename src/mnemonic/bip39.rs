@@ -0,0 +1,262 @@
+use crate::crypto::keys::KeyPair;
+use crate::hash::pbkdf2::pbkdf2_sha512;
+use crate::hash::Sha256;
+use crate::mnemonic::english::{parse, ENGLISH};
+use crate::utils::ct::ConstantTimeEq;
+use crate::Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+use zeroize::Zeroize;
+
+/**
+# BIP-39 mnemonic
+
+- 12, 15, 18, 21 or 24 words of the English list, encoding 16 to 32 bytes of
+  entropy followed by the first bit of its SHA-256 for every 4 bytes
+- Turned into a 64 byte seed by PBKDF2-HMAC-SHA-512, salted with an optional passphrase
+- Wiped from memory on drop and never printed, read it with [`Mnemonic::expose_phrase`]
+
+## Example
+
+```rust
+use ed25519_axolotl::mnemonic::bip39::Mnemonic;
+
+let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+assert_eq!(
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    mnemonic.expose_phrase()
+);
+
+let typed: Mnemonic = mnemonic.expose_phrase().parse().unwrap();
+assert_eq!([0; 16], typed.entropy());
+assert_eq!(0xc5, typed.to_seed("TREZOR")[0]);
+```
+*/
+#[derive(Clone)]
+pub struct Mnemonic {
+    phrase: String,
+    entropy: [u8; 32],
+    len: usize,
+}
+
+/// 11 bits of *`bytes`* from bit *`offset`*, most significant first
+fn index_at(bytes: &[u8], offset: usize) -> u16 {
+    let mut index = 0u16;
+    for bit in offset..offset + 11 {
+        index = index << 1 | (bytes[bit / 8] >> (7 - bit % 8) & 1) as u16;
+    }
+    index
+}
+
+/// Checksum bits of *`entropy`*, the first bit of its SHA-256 for every 4 bytes
+fn checksum(entropy: &[u8]) -> u8 {
+    Sha256::digest(entropy)[0] >> (8 - entropy.len() / 4)
+}
+
+impl Mnemonic {
+    /**
+    ## Encode *`entropy`* as a *Mnemonic*
+
+    - Fails when the entropy does not have 16, 20, 24, 28 or 32 bytes, then
+      `expected` is the closest allowed length above
+
+    ```rust
+    use ed25519_axolotl::mnemonic::bip39::Mnemonic;
+    use ed25519_axolotl::Error;
+
+    assert_eq!(24, Mnemonic::from_entropy(&[7; 32]).unwrap().words().count());
+    assert_eq!(
+        Some(Error::InvalidLength { expected: 20, actual: 17 }),
+        Mnemonic::from_entropy(&[7; 17]).err()
+    );
+    ```
+    */
+    pub fn from_entropy(entropy: &[u8]) -> Result<Mnemonic, Error> {
+        let len = entropy.len();
+        if !(16..=32).contains(&len) || !len.is_multiple_of(4) {
+            return Err(Error::InvalidLength {
+                expected: (len.div_ceil(4) * 4).clamp(16, 32),
+                actual: len,
+            });
+        }
+
+        // Entropy followed by its checksum, in the high bits of the next byte.
+        let mut data = [0u8; 33];
+        data[..len].copy_from_slice(entropy);
+        data[len] = checksum(entropy) << (8 - len / 4);
+
+        let words = len * 3 / 4;
+        // Room for words of up to 8 letters, so the phrase is never moved.
+        let mut phrase = String::with_capacity(9 * words);
+        for i in 0..words {
+            if i > 0 {
+                phrase.push(' ');
+            }
+            phrase.push_str(ENGLISH[index_at(&data, 11 * i) as usize]);
+        }
+
+        let mut mnemonic = Mnemonic {
+            phrase,
+            entropy: [0; 32],
+            len,
+        };
+        mnemonic.entropy.copy_from_slice(&data[..32]);
+        data.zeroize();
+        Ok(mnemonic)
+    }
+
+    /**
+    ## Validate a typed *Mnemonic*
+
+    - Words may be separated by any whitespace, they are kept separated by one space
+    - Fails on a word outside of the list, given by its position, on a count
+      other than 12, 15, 18, 21 or 24 words, then `expected` is the closest
+      allowed count above, or on a wrong checksum
+
+    ```rust
+    use ed25519_axolotl::mnemonic::bip39::Mnemonic;
+    use ed25519_axolotl::Error;
+
+    let words = "legal winner thank year wave sausage worth useful legal winner thank";
+    assert!(Mnemonic::from_phrase(&format!("{words} yellow")).is_ok());
+    assert_eq!(
+        Some(Error::InvalidChecksum),
+        Mnemonic::from_phrase(&format!("{words} zoo")).err()
+    );
+    assert_eq!(
+        Some(Error::InvalidWordCount { expected: 12, actual: 11 }),
+        Mnemonic::from_phrase(words).err()
+    );
+    ```
+    */
+    pub fn from_phrase(phrase: &str) -> Result<Mnemonic, Error> {
+        let (mut words, mut indices) = parse(phrase)?;
+        let count = indices.len();
+        if !(12..=24).contains(&count) || !count.is_multiple_of(3) {
+            words.zeroize();
+            indices.zeroize();
+            return Err(Error::InvalidWordCount {
+                expected: (count.div_ceil(3) * 3).clamp(12, 24),
+                actual: count,
+            });
+        }
+
+        let mut data = [0u8; 33];
+        for (i, index) in indices.iter().enumerate() {
+            for b in 0..11 {
+                let bit = 11 * i + b;
+                data[bit / 8] |= ((index >> (10 - b) & 1) as u8) << (7 - bit % 8);
+            }
+        }
+        indices.zeroize();
+
+        let len = count * 4 / 3;
+        let valid = checksum(&data[..len]) == data[len] >> (8 - len / 4);
+        data[len] = 0;
+
+        let mut mnemonic = Mnemonic {
+            phrase: words,
+            entropy: [0; 32],
+            len,
+        };
+        mnemonic.entropy.copy_from_slice(&data[..32]);
+        data.zeroize();
+
+        match valid {
+            true => Ok(mnemonic),
+            false => Err(Error::InvalidChecksum),
+        }
+    }
+
+    /// ## Borrow the words, separated by one space
+    pub fn expose_phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// ## Iterate over the words, to show them one by one
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.phrase.split(' ')
+    }
+
+    /// ## Borrow the entropy encoded by the words
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy[..self.len]
+    }
+
+    /**
+    ## Derive the 64 byte seed, protected by *`passphrase`*
+
+    - PBKDF2-HMAC-SHA-512 of the words, salted with `"mnemonic"` and the
+      passphrase, in 2048 rounds
+    - Every passphrase gives a valid but different seed, use `""` for none
+    - The passphrase is used as given, normalize non ASCII passphrases to
+      Unicode NFKD before, as BIP-39 specifies
+    */
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        let mut salt = Vec::with_capacity(8 + passphrase.len());
+        salt.extend_from_slice(b"mnemonic");
+        salt.extend_from_slice(passphrase.as_bytes());
+
+        let mut seed = [0u8; 64];
+        pbkdf2_sha512(self.phrase.as_bytes(), &salt, 2048, &mut seed);
+        salt.zeroize();
+        seed
+    }
+
+    /**
+    ## Derive a *KeyPair* from the seed of *`passphrase`*
+
+    - The first 32 bytes of [`Mnemonic::to_seed`] are the seed of [`KeyPair::from_seed`]
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::mnemonic::bip39::Mnemonic;
+
+    let mnemonic = Mnemonic::from_entropy(&[0; 16]).unwrap();
+    let seed = mnemonic.to_seed("");
+    let keys = KeyPair::from_seed(seed[..32].try_into().unwrap());
+    assert_eq!(keys.pubk, mnemonic.to_key_pair("").pubk);
+    assert_ne!(keys.pubk, mnemonic.to_key_pair("TREZOR").pubk);
+    ```
+    */
+    pub fn to_key_pair(&self, passphrase: &str) -> KeyPair {
+        let mut seed = self.to_seed(passphrase);
+        let mut sk = [0u8; 32];
+        sk.copy_from_slice(&seed[..32]);
+        let keys = KeyPair::from_seed(&sk);
+        seed.zeroize();
+        sk.zeroize();
+        keys
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    fn from_str(phrase: &str) -> Result<Mnemonic, Error> {
+        Mnemonic::from_phrase(phrase)
+    }
+}
+
+impl PartialEq for Mnemonic {
+    fn eq(&self, other: &Mnemonic) -> bool {
+        self.phrase.as_bytes().ct_eq(other.phrase.as_bytes()).into()
+    }
+}
+
+impl Eq for Mnemonic {}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Mnemonic(<redacted>)")
+    }
+}
+
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.entropy.zeroize();
+    }
+}
@@ -0,0 +1,9 @@
+/// BIP-39 mnemonics of multi-chain wallets
+pub mod bip39;
+/// English word list and its lookup
+pub(crate) mod english;
+/// 15 word seed phrases of Lunes wallets
+pub mod phrase;
+
+pub use bip39::Mnemonic;
+pub use phrase::SeedPhrase;
@@ -0,0 +1,170 @@
+use crate::crypto::keys::KeyPair;
+use crate::mnemonic::english::{parse, ENGLISH};
+use crate::utils::ct::ConstantTimeEq;
+use crate::utils::random::{CryptoRng, RngCore};
+use crate::Error;
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+use zeroize::Zeroize;
+
+/**
+# Seed phrase of a Lunes wallet
+
+- 15 words of the BIP-39 English list, 165 bits of entropy, without checksum
+- Wiped from memory on drop and never printed, read it with [`SeedPhrase::expose_phrase`]
+
+## Example
+
+```rust
+use ed25519_axolotl::mnemonic::SeedPhrase;
+use ed25519_axolotl::utils::random::OsRng;
+
+let phrase = SeedPhrase::generate_with(&mut OsRng);
+let keys = phrase.to_key_pair();
+
+let typed: SeedPhrase = phrase.expose_phrase().parse().unwrap();
+assert_eq!(keys.pubk, typed.to_key_pair().pubk);
+```
+*/
+#[derive(Clone)]
+pub struct SeedPhrase(String);
+
+impl SeedPhrase {
+    /// ## Number of words of a phrase
+    pub const WORDS: usize = 15;
+
+    /**
+    ## Generate a new *SeedPhrase*
+    ```rust
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+
+    let phrase = SeedPhrase::generate();
+    assert_eq!(SeedPhrase::WORDS, phrase.words().count());
+    ```
+    */
+    #[cfg(feature = "getrandom")]
+    pub fn generate() -> SeedPhrase {
+        use crate::utils::random::OsRng;
+
+        SeedPhrase::generate_with(&mut OsRng)
+    }
+
+    /// ## Generate a new *SeedPhrase* with your own random generator
+    pub fn generate_with<R: CryptoRng + RngCore>(rng: &mut R) -> SeedPhrase {
+        use crate::utils::random::random_array_with;
+
+        // 11 bits of every 2 bytes pick a word, 2048 is a power of two so none is favoured.
+        let mut bytes: [u8; 2 * SeedPhrase::WORDS] = random_array_with(rng);
+
+        // Room for 15 words of up to 8 letters, so the phrase is never moved.
+        let mut phrase = String::with_capacity(9 * SeedPhrase::WORDS);
+        for pair in bytes.chunks_exact(2) {
+            if !phrase.is_empty() {
+                phrase.push(' ');
+            }
+            let index = u16::from_le_bytes([pair[0], pair[1]]) & 2047;
+            phrase.push_str(ENGLISH[index as usize]);
+        }
+        bytes.zeroize();
+
+        SeedPhrase(phrase)
+    }
+
+    /**
+    ## Validate a typed *SeedPhrase*
+
+    - Words may be separated by any whitespace, they are kept separated by one space
+    - Fails on a word outside of the list, given by its position, or when
+      there are not 15 words
+
+    ```rust
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+    use ed25519_axolotl::Error;
+
+    let words = "uncle push human bus echo drastic garden joke sand warfare sentence fossil title color";
+    assert_eq!(
+        Some(Error::InvalidWordCount { expected: 15, actual: 14 }),
+        SeedPhrase::from_phrase(words).err()
+    );
+    assert_eq!(
+        Some(Error::UnknownWord { index: 14 }),
+        SeedPhrase::from_phrase(&format!("{words} lunes")).err()
+    );
+    assert!(SeedPhrase::from_phrase(&format!("  {words}\ncombine ")).is_ok());
+    ```
+    */
+    pub fn from_phrase(phrase: &str) -> Result<SeedPhrase, Error> {
+        let (mut words, mut indices) = parse(phrase)?;
+        let count = indices.len();
+        indices.zeroize();
+
+        if count != SeedPhrase::WORDS {
+            words.zeroize();
+            return Err(Error::InvalidWordCount {
+                expected: SeedPhrase::WORDS,
+                actual: count,
+            });
+        }
+        Ok(SeedPhrase(words))
+    }
+
+    /// ## Borrow the words, separated by one space
+    pub fn expose_phrase(&self) -> &str {
+        &self.0
+    }
+
+    /// ## Iterate over the words, to show them one by one
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.0.split(' ')
+    }
+
+    /**
+    ## Derive the *KeyPair* of the phrase, as the Lunes wallet does
+
+    - The first account of the wallet, [`KeyPair::from_lunes_seed_phrase`] with nonce 0
+
+    ```rust
+    use ed25519_axolotl::lunes::address::{Address, ChainId};
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+
+    let phrase: SeedPhrase =
+        "uncle push human bus echo drastic garden joke sand warfare sentence fossil title color combine"
+            .parse()
+            .unwrap();
+    let address = Address::from_public_key(&phrase.to_key_pair().pubk, ChainId::Mainnet);
+    assert_eq!("37rUVcv62Z64ATSqTPwiA8nYnQaFaWGYWMD", address.to_string());
+    ```
+    */
+    pub fn to_key_pair(&self) -> KeyPair {
+        KeyPair::from_lunes_seed_phrase(&self.0, 0)
+    }
+}
+
+impl FromStr for SeedPhrase {
+    type Err = Error;
+
+    fn from_str(phrase: &str) -> Result<SeedPhrase, Error> {
+        SeedPhrase::from_phrase(phrase)
+    }
+}
+
+impl PartialEq for SeedPhrase {
+    fn eq(&self, other: &SeedPhrase) -> bool {
+        self.0.as_bytes().ct_eq(other.0.as_bytes()).into()
+    }
+}
+
+impl Eq for SeedPhrase {}
+
+impl fmt::Debug for SeedPhrase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SeedPhrase(<redacted>)")
+    }
+}
+
+impl Drop for SeedPhrase {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
@@ -0,0 +1,24 @@
+use crate::hash::HmacSha512;
+use alloc::vec::Vec;
+use zeroize::Zeroize;
+
+/// HKDF-SHA-512 as specified in RFC 5869, filling `okm` with at most 255 blocks
+pub(crate) fn hkdf_sha512(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) {
+    let mut prk = HmacSha512::mac(salt, ikm);
+    let mut t: Vec<u8> = Vec::with_capacity(64 + info.len() + 1);
+
+    for (i, chunk) in okm.chunks_mut(64).enumerate() {
+        t.extend_from_slice(info);
+        t.push(i as u8 + 1);
+        let mut block = HmacSha512::mac(&prk, &t);
+        chunk.copy_from_slice(&block[..chunk.len()]);
+
+        t.zeroize();
+        t.clear();
+        t.extend_from_slice(&block);
+        block.zeroize();
+    }
+
+    prk.zeroize();
+    t.zeroize();
+}
@@ -0,0 +1,8 @@
+/// ChaCha20 stream cipher
+mod cipher;
+/// HKDF over SHA-512
+mod kdf;
+/// Double Ratchet sessions
+pub mod ratchet;
+/// Extended Triple Diffie-Hellman key agreement
+pub mod x3dh;
@@ -0,0 +1,721 @@
+use crate::crypto::agreement::SharedSecret;
+use crate::crypto::array;
+use crate::crypto::keys::{KeyPair, PublicKey, SecretKey};
+use crate::hash::HmacSha512;
+use crate::protocol::cipher::chacha20_xor;
+use crate::protocol::kdf::hkdf_sha512;
+use crate::utils::random::{CryptoRng, RngCore};
+use crate::Error;
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use zeroize::Zeroize;
+
+/// Application names given to HKDF as `info`
+const ROOT_INFO: &[u8] = b"Lunes Ratchet";
+const MESSAGE_INFO: &[u8] = b"Lunes Ratchet Message";
+const HEADER_INFO: &[u8] = b"Lunes Ratchet Header";
+const HEADER_KEYS_INFO: &[u8] = b"Lunes Ratchet Header Keys";
+
+/// Version of the serialized session state
+const VERSION: u8 = 1;
+
+/// Bytes of the authentication tag closing ciphertexts and encrypted headers
+const TAG_LEN: usize = 32;
+
+/// # Options of a *Session*
+///
+/// - Both sides of a session must agree on `header_encryption`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Options {
+    /// ## Encrypt headers, hiding ratchet keys and message numbers
+    pub header_encryption: bool,
+    /// ## Most message keys skipped at once in a single chain
+    pub max_skip: u32,
+    /// ## Most skipped message keys stored, the oldest are dropped first
+    pub max_stored: u32,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            header_encryption: false,
+            max_skip: 1000,
+            max_stored: 2000,
+        }
+    }
+}
+
+/// # Encrypted message of a *Session*
+///
+/// - Serialized as the header length, header and ciphertext
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Message {
+    /// ## Ratchet key and message numbers, encrypted when the session encrypts headers
+    pub header: Vec<u8>,
+    /// ## Encrypted message followed by a 32 byte authentication tag
+    pub ciphertext: Vec<u8>,
+}
+
+impl Message {
+    /// ## Serialize the message
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.header.len() + self.ciphertext.len());
+        bytes.push(self.header.len() as u8);
+        bytes.extend_from_slice(&self.header);
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes
+    }
+
+    /// ## Deserialize a message
+    ///
+    /// - Fails when the bytes are too short to hold the header and tag
+    pub fn from_bytes(bytes: &[u8]) -> Result<Message, Error> {
+        let header_len = *bytes.first().ok_or(Error::InvalidLength {
+            expected: 1,
+            actual: 0,
+        })? as usize;
+
+        if bytes.len() < 1 + header_len + TAG_LEN {
+            return Err(Error::InvalidLength {
+                expected: 1 + header_len + TAG_LEN,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(Message {
+            header: bytes[1..1 + header_len].to_vec(),
+            ciphertext: bytes[1 + header_len..].to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for Message {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Message, Error> {
+        Message::from_bytes(bytes)
+    }
+}
+
+/// Ratchet public key, previous chain length and message number
+struct Header {
+    dh: PublicKey,
+    pn: u32,
+    n: u32,
+}
+
+impl Header {
+    fn to_bytes(&self) -> [u8; 40] {
+        let mut bytes = [0u8; 40];
+        bytes[..32].copy_from_slice(self.dh.as_bytes());
+        bytes[32..36].copy_from_slice(&self.pn.to_be_bytes());
+        bytes[36..].copy_from_slice(&self.n.to_be_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Header, Error> {
+        let bytes: [u8; 40] = array(bytes)?;
+        Ok(Header {
+            dh: PublicKey::from_bytes(array(&bytes[..32])?),
+            pn: u32::from_be_bytes(array(&bytes[32..36])?),
+            n: u32::from_be_bytes(array(&bytes[36..])?),
+        })
+    }
+}
+
+/// Message key of a skipped message, found by chain and message number
+#[derive(Clone)]
+struct Skipped {
+    /// Ratchet public key, or header key when headers are encrypted
+    chain: [u8; 32],
+    n: u32,
+    key: [u8; 32],
+}
+
+impl Drop for Skipped {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// Copy the first 32 bytes of `bytes`
+fn key(bytes: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&bytes[..32]);
+    key
+}
+
+/// Compare bytes in constant time
+fn equal(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Root key step: next root key, chain key and next header key
+fn kdf_rk(rk: &[u8; 32], dh: &SharedSecret) -> ([u8; 32], [u8; 32], [u8; 32]) {
+    let mut okm = [0u8; 96];
+    hkdf_sha512(rk, dh.as_bytes(), ROOT_INFO, &mut okm);
+    let keys = (key(&okm), key(&okm[32..]), key(&okm[64..]));
+    okm.zeroize();
+    keys
+}
+
+/// Chain key step: next chain key and message key
+fn kdf_ck(ck: &[u8; 32]) -> ([u8; 32], [u8; 32]) {
+    (
+        key(&HmacSha512::mac(ck, &[2])),
+        key(&HmacSha512::mac(ck, &[1])),
+    )
+}
+
+/// First header keys of initiator and responder
+fn header_keys(secret: &SharedSecret) -> ([u8; 32], [u8; 32]) {
+    let mut okm = [0u8; 64];
+    hkdf_sha512(&[0; 64], secret.as_bytes(), HEADER_KEYS_INFO, &mut okm);
+    let keys = (key(&okm), key(&okm[32..]));
+    okm.zeroize();
+    keys
+}
+
+/// Encrypt with ChaCha20 then authenticate with HMAC-SHA-512, keys derived from `mk`
+fn seal(mk: &[u8; 32], plaintext: &[u8], associated_data: &[u8]) -> Vec<u8> {
+    let mut okm = [0u8; 76];
+    hkdf_sha512(&[0; 64], mk, MESSAGE_INFO, &mut okm);
+
+    let mut ciphertext = plaintext.to_vec();
+    chacha20_xor(&okm[..32], &okm[64..], &mut ciphertext);
+    let tag = HmacSha512::mac(&okm[32..64], &[associated_data, &ciphertext].concat());
+    ciphertext.extend_from_slice(&tag[..TAG_LEN]);
+
+    okm.zeroize();
+    ciphertext
+}
+
+/// Check the tag then decrypt a ciphertext of [`seal`]
+fn open(mk: &[u8; 32], ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, Error> {
+    if ciphertext.len() < TAG_LEN {
+        return Err(Error::DecryptionFailed);
+    }
+    let (ciphertext, tag) = ciphertext.split_at(ciphertext.len() - TAG_LEN);
+
+    let mut okm = [0u8; 76];
+    hkdf_sha512(&[0; 64], mk, MESSAGE_INFO, &mut okm);
+
+    let expected = HmacSha512::mac(&okm[32..64], &[associated_data, ciphertext].concat());
+    if !equal(&expected[..TAG_LEN], tag) {
+        okm.zeroize();
+        return Err(Error::DecryptionFailed);
+    }
+
+    let mut plaintext = ciphertext.to_vec();
+    chacha20_xor(&okm[..32], &okm[64..], &mut plaintext);
+
+    okm.zeroize();
+    Ok(plaintext)
+}
+
+/// Encrypt a header with a synthetic nonce, since a header key encrypts a whole chain
+fn seal_header(hk: &[u8; 32], header: &[u8; 40]) -> Vec<u8> {
+    let mut okm = [0u8; 64];
+    hkdf_sha512(&[0; 64], hk, HEADER_INFO, &mut okm);
+
+    let tag = HmacSha512::mac(&okm[32..], header);
+    let mut sealed = tag[..TAG_LEN].to_vec();
+    sealed.extend_from_slice(header);
+    chacha20_xor(&okm[..32], &tag[..12], &mut sealed[TAG_LEN..]);
+
+    okm.zeroize();
+    sealed
+}
+
+/// Decrypt then check a header of [`seal_header`]
+fn open_header(hk: &[u8; 32], sealed: &[u8]) -> Result<Header, Error> {
+    if sealed.len() != TAG_LEN + 40 {
+        return Err(Error::DecryptionFailed);
+    }
+
+    let mut okm = [0u8; 64];
+    hkdf_sha512(&[0; 64], hk, HEADER_INFO, &mut okm);
+
+    let mut header = sealed[TAG_LEN..].to_vec();
+    chacha20_xor(&okm[..32], &sealed[..12], &mut header);
+    let tag = HmacSha512::mac(&okm[32..], &header);
+
+    okm.zeroize();
+    if !equal(&tag[..TAG_LEN], &sealed[..TAG_LEN]) {
+        return Err(Error::DecryptionFailed);
+    }
+    Header::from_bytes(&header)
+}
+
+/**
+# Double Ratchet session
+## Encrypt and decrypt the messages of a conversation
+
+- The Double Ratchet of Signal, formerly Axolotl: every message has its own
+  key and every reply rotates the Diffie-Hellman ratchet keys
+- Start from the secret of a key agreement such as X3DH, the initiator also
+  needs the ratchet public key of the responder, usually its signed prekey
+- Decryption is all or nothing, a message that fails leaves the session untouched
+- The serialized state holds secret keys, store it encrypted
+
+## Example
+
+```rust
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::protocol::ratchet::{Options, Session};
+use ed25519_axolotl::protocol::x3dh::{initiate, respond, PreKeyBundle, SignedPreKey};
+
+let alice = KeyPair::generate();
+let bob = KeyPair::generate();
+let signed_prekey = SignedPreKey::generate(&bob, 1);
+let bundle = PreKeyBundle::new(&bob, &signed_prekey, None);
+
+let (initial, sent) = initiate(&alice, &bundle).unwrap();
+let received = respond(&bob, &signed_prekey, None, &initial).unwrap();
+
+let mut alice_session =
+    Session::initiate(&sent.secret, &bundle.signed_prekey, Options::default()).unwrap();
+let mut bob_session = Session::respond(&received.secret, signed_prekey.keys, Options::default());
+
+let message = alice_session.encrypt(b"Lunes", &sent.associated_data).unwrap();
+let plaintext = bob_session.decrypt(&message, &received.associated_data).unwrap();
+
+assert_eq!(b"Lunes".to_vec(), plaintext);
+```
+*/
+#[derive(Clone)]
+pub struct Session {
+    options: Options,
+    dhs: SecretKey,
+    dhs_public: PublicKey,
+    dhr: Option<PublicKey>,
+    rk: [u8; 32],
+    cks: Option<[u8; 32]>,
+    ckr: Option<[u8; 32]>,
+    ns: u32,
+    nr: u32,
+    pn: u32,
+    hks: Option<[u8; 32]>,
+    hkr: Option<[u8; 32]>,
+    nhks: [u8; 32],
+    nhkr: [u8; 32],
+    skipped: VecDeque<Skipped>,
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        self.rk.zeroize();
+        self.cks.zeroize();
+        self.ckr.zeroize();
+        self.hks.zeroize();
+        self.hkr.zeroize();
+        self.nhks.zeroize();
+        self.nhkr.zeroize();
+    }
+}
+
+impl Session {
+    /// ## Start the session of the initiator, which sends the first message
+    ///
+    /// - *`remote`* is the ratchet public key of the responder
+    #[cfg(feature = "getrandom")]
+    pub fn initiate(
+        secret: &SharedSecret,
+        remote: &PublicKey,
+        options: Options,
+    ) -> Result<Session, Error> {
+        use crate::utils::random::OsRng;
+
+        Session::initiate_with(secret, remote, options, &mut OsRng)
+    }
+
+    /// ## Same as [`Session::initiate`], with the ratchet key generated from *`rng`*
+    pub fn initiate_with<R: CryptoRng + RngCore>(
+        secret: &SharedSecret,
+        remote: &PublicKey,
+        options: Options,
+        rng: &mut R,
+    ) -> Result<Session, Error> {
+        let (hka, nhkb) = header_keys(secret);
+        let keys = KeyPair::generate_with(rng);
+        let (rk, cks, nhks) = kdf_rk(secret.as_bytes(), &keys.diffie_hellman(remote)?);
+
+        Ok(Session {
+            options,
+            dhs: keys.prvk,
+            dhs_public: keys.pubk,
+            dhr: Some(*remote),
+            rk,
+            cks: Some(cks),
+            ckr: None,
+            ns: 0,
+            nr: 0,
+            pn: 0,
+            hks: Some(hka),
+            hkr: None,
+            nhks,
+            nhkr: nhkb,
+            skipped: VecDeque::new(),
+        })
+    }
+
+    /// ## Start the session of the responder with its ratchet *`keys`*
+    ///
+    /// - The responder can send once it has decrypted a first message
+    pub fn respond(secret: &SharedSecret, keys: KeyPair, options: Options) -> Session {
+        let (hka, nhkb) = header_keys(secret);
+
+        Session {
+            options,
+            dhs: keys.prvk,
+            dhs_public: keys.pubk,
+            dhr: None,
+            rk: secret.to_bytes(),
+            cks: None,
+            ckr: None,
+            ns: 0,
+            nr: 0,
+            pn: 0,
+            hks: None,
+            hkr: None,
+            nhks: nhkb,
+            nhkr: hka,
+            skipped: VecDeque::new(),
+        }
+    }
+
+    /// ## Options the session was started with
+    pub fn options(&self) -> Options {
+        self.options
+    }
+
+    /**
+    ## Encrypt a message
+
+    - *`associated_data`* is authenticated but not encrypted, for instance the
+      associated data of X3DH
+    - Fails with [`Error::NoSendingChain`] on a responder that did not receive yet
+    */
+    pub fn encrypt(&mut self, plaintext: &[u8], associated_data: &[u8]) -> Result<Message, Error> {
+        let ck = self.cks.ok_or(Error::NoSendingChain)?;
+        let header = Header {
+            dh: self.dhs_public,
+            pn: self.pn,
+            n: self.ns,
+        }
+        .to_bytes();
+
+        let header = match self.options.header_encryption {
+            true => seal_header(&self.hks.ok_or(Error::NoSendingChain)?, &header),
+            false => header.to_vec(),
+        };
+
+        let (next, mut mk) = kdf_ck(&ck);
+        let ciphertext = seal(&mk, plaintext, &[associated_data, &header].concat());
+        mk.zeroize();
+
+        self.cks = Some(next);
+        self.ns += 1;
+
+        Ok(Message { header, ciphertext })
+    }
+
+    /**
+    ## Decrypt a message
+
+    - Messages may arrive out of order, the keys of skipped messages are kept
+      up to the limits of the *Options*
+    - Fails with [`Error::DecryptionFailed`] on forged, replayed or corrupted
+      messages and [`Error::TooManySkipped`] past the skip limit
+    */
+    #[cfg(feature = "getrandom")]
+    pub fn decrypt(&mut self, message: &Message, associated_data: &[u8]) -> Result<Vec<u8>, Error> {
+        use crate::utils::random::OsRng;
+
+        self.decrypt_with(message, associated_data, &mut OsRng)
+    }
+
+    /// ## Same as [`Session::decrypt`], with new ratchet keys generated from *`rng`*
+    pub fn decrypt_with<R: CryptoRng + RngCore>(
+        &mut self,
+        message: &Message,
+        associated_data: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<u8>, Error> {
+        // Work on a copy, so a failed message leaves the session untouched.
+        let mut next = self.clone();
+        let associated_data = [associated_data, &message.header].concat();
+        let plaintext = next.receive(message, &associated_data, rng)?;
+        *self = next;
+        Ok(plaintext)
+    }
+
+    fn receive<R: CryptoRng + RngCore>(
+        &mut self,
+        message: &Message,
+        associated_data: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<u8>, Error> {
+        if let Some(mut mk) = self.take_skipped(&message.header)? {
+            let plaintext = open(&mk, &message.ciphertext, associated_data);
+            mk.zeroize();
+            return plaintext;
+        }
+
+        let (header, step) = self.read_header(&message.header)?;
+        if step {
+            self.skip(header.pn)?;
+            self.step(&header, rng)?;
+        }
+        self.skip(header.n)?;
+
+        let (next, mut mk) = kdf_ck(&self.ckr.ok_or(Error::DecryptionFailed)?);
+        self.ckr = Some(next);
+        self.nr += 1;
+
+        let plaintext = open(&mk, &message.ciphertext, associated_data);
+        mk.zeroize();
+        plaintext
+    }
+
+    /// Remove the stored key of a skipped message
+    fn take_skipped(&mut self, header: &[u8]) -> Result<Option<[u8; 32]>, Error> {
+        let index = match self.options.header_encryption {
+            true => self.skipped.iter().position(|skipped| {
+                open_header(&skipped.chain, header).is_ok_and(|h| h.n == skipped.n)
+            }),
+            false => {
+                let header = Header::from_bytes(header)?;
+                self.skipped.iter().position(|skipped| {
+                    skipped.chain == *header.dh.as_bytes() && skipped.n == header.n
+                })
+            }
+        };
+
+        Ok(index
+            .and_then(|index| self.skipped.remove(index))
+            .map(|skipped| skipped.key))
+    }
+
+    /// Read a header, telling whether it starts a new receiving chain
+    fn read_header(&self, header: &[u8]) -> Result<(Header, bool), Error> {
+        if !self.options.header_encryption {
+            let header = Header::from_bytes(header)?;
+            let step = self.dhr != Some(header.dh);
+            return Ok((header, step));
+        }
+
+        if let Some(hk) = &self.hkr {
+            if let Ok(header) = open_header(hk, header) {
+                return Ok((header, false));
+            }
+        }
+        open_header(&self.nhkr, header).map(|header| (header, true))
+    }
+
+    /// Store the message keys of the receiving chain up to message `until`
+    fn skip(&mut self, until: u32) -> Result<(), Error> {
+        if until > self.nr.saturating_add(self.options.max_skip) {
+            return Err(Error::TooManySkipped {
+                limit: self.options.max_skip,
+            });
+        }
+
+        let chain = match self.options.header_encryption {
+            true => self.hkr,
+            false => self.dhr.map(|dh| dh.to_bytes()),
+        };
+
+        if let (Some(mut ck), Some(chain)) = (self.ckr, chain) {
+            while self.nr < until {
+                let (next, key) = kdf_ck(&ck);
+                self.skipped.push_back(Skipped {
+                    chain,
+                    n: self.nr,
+                    key,
+                });
+                if self.skipped.len() > self.options.max_stored as usize {
+                    self.skipped.pop_front();
+                }
+                ck = next;
+                self.nr += 1;
+            }
+            self.ckr = Some(ck);
+        }
+        Ok(())
+    }
+
+    /// Diffie-Hellman ratchet step on the new ratchet key of the header
+    fn step<R: CryptoRng + RngCore>(&mut self, header: &Header, rng: &mut R) -> Result<(), Error> {
+        self.pn = self.ns;
+        self.ns = 0;
+        self.nr = 0;
+        self.hks = Some(self.nhks);
+        self.hkr = Some(self.nhkr);
+        self.dhr = Some(header.dh);
+
+        let (rk, ckr, nhkr) = kdf_rk(&self.rk, &self.dhs.diffie_hellman(&header.dh)?);
+        let keys = KeyPair::generate_with(rng);
+        let (rk, cks, nhks) = kdf_rk(&rk, &keys.diffie_hellman(&header.dh)?);
+
+        self.dhs = keys.prvk;
+        self.dhs_public = keys.pubk;
+        self.rk = rk;
+        self.ckr = Some(ckr);
+        self.cks = Some(cks);
+        self.nhkr = nhkr;
+        self.nhks = nhks;
+        Ok(())
+    }
+
+    /// ## Serialize the session state, secret keys included
+    pub fn to_bytes(&self) -> Vec<u8> {
+        fn optional(bytes: &mut Vec<u8>, key: &Option<[u8; 32]>) {
+            match key {
+                Some(key) => {
+                    bytes.push(1);
+                    bytes.extend_from_slice(key);
+                }
+                None => bytes.push(0),
+            }
+        }
+
+        let mut bytes = vec![VERSION, self.options.header_encryption as u8];
+        bytes.extend_from_slice(&self.options.max_skip.to_be_bytes());
+        bytes.extend_from_slice(&self.options.max_stored.to_be_bytes());
+        bytes.extend_from_slice(self.dhs.expose_secret());
+        bytes.extend_from_slice(self.dhs_public.as_bytes());
+        optional(&mut bytes, &self.dhr.map(|dh| dh.to_bytes()));
+        bytes.extend_from_slice(&self.rk);
+        optional(&mut bytes, &self.cks);
+        optional(&mut bytes, &self.ckr);
+        for n in [self.ns, self.nr, self.pn] {
+            bytes.extend_from_slice(&n.to_be_bytes());
+        }
+        optional(&mut bytes, &self.hks);
+        optional(&mut bytes, &self.hkr);
+        bytes.extend_from_slice(&self.nhks);
+        bytes.extend_from_slice(&self.nhkr);
+
+        bytes.extend_from_slice(&(self.skipped.len() as u32).to_be_bytes());
+        for skipped in &self.skipped {
+            bytes.extend_from_slice(&skipped.chain);
+            bytes.extend_from_slice(&skipped.n.to_be_bytes());
+            bytes.extend_from_slice(&skipped.key);
+        }
+        bytes
+    }
+
+    /// ## Deserialize a session state of [`Session::to_bytes`]
+    ///
+    /// - Fails with [`Error::InvalidEncoding`] on an unknown version or flag
+    pub fn from_bytes(bytes: &[u8]) -> Result<Session, Error> {
+        let mut reader = Reader { bytes, position: 0 };
+
+        if reader.byte()? != VERSION {
+            return Err(Error::InvalidEncoding);
+        }
+        let options = Options {
+            header_encryption: reader.flag()?,
+            max_skip: reader.number()?,
+            max_stored: reader.number()?,
+        };
+
+        let dhs = SecretKey::from_bytes(reader.key()?);
+        let dhs_public = PublicKey::from_bytes(reader.key()?);
+        let dhr = reader.optional()?.map(PublicKey::from_bytes);
+        let rk = reader.key()?;
+        let cks = reader.optional()?;
+        let ckr = reader.optional()?;
+        let ns = reader.number()?;
+        let nr = reader.number()?;
+        let pn = reader.number()?;
+        let hks = reader.optional()?;
+        let hkr = reader.optional()?;
+        let nhks = reader.key()?;
+        let nhkr = reader.key()?;
+
+        let mut skipped = VecDeque::new();
+        for _ in 0..reader.number()? {
+            skipped.push_back(Skipped {
+                chain: reader.key()?,
+                n: reader.number()?,
+                key: reader.key()?,
+            });
+        }
+
+        if reader.position != bytes.len() {
+            return Err(Error::InvalidLength {
+                expected: reader.position,
+                actual: bytes.len(),
+            });
+        }
+
+        Ok(Session {
+            options,
+            dhs,
+            dhs_public,
+            dhr,
+            rk,
+            cks,
+            ckr,
+            ns,
+            nr,
+            pn,
+            hks,
+            hkr,
+            nhks,
+            nhkr,
+            skipped,
+        })
+    }
+}
+
+/// Cursor over a serialized session state
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8], Error> {
+        let end = self.position + n;
+        if end > self.bytes.len() {
+            return Err(Error::InvalidLength {
+                expected: end,
+                actual: self.bytes.len(),
+            });
+        }
+        let taken = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn flag(&mut self) -> Result<bool, Error> {
+        match self.byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+
+    fn number(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_be_bytes(array(self.take(4)?)?))
+    }
+
+    fn key(&mut self) -> Result<[u8; 32], Error> {
+        array(self.take(32)?)
+    }
+
+    fn optional(&mut self) -> Result<Option<[u8; 32]>, Error> {
+        match self.flag()? {
+            true => Ok(Some(self.key()?)),
+            false => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,350 @@
+use crate::crypto::agreement::SharedSecret;
+use crate::crypto::array;
+use crate::crypto::keys::{KeyPair, PublicKey};
+use crate::crypto::signatures::Signature;
+use crate::utils::random::{CryptoRng, RngCore};
+use crate::Error;
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+/// Application name given to HKDF as `info`
+const INFO: &[u8] = b"Lunes X3DH";
+
+/// Type byte of Curve25519 keys in the encoding of Signal
+const KEY_TYPE: u8 = 0x05;
+
+/// Encode a public key as its type byte followed by its 32 bytes
+fn encode(key: &PublicKey) -> [u8; 33] {
+    let mut encoded = [KEY_TYPE; 33];
+    encoded[1..].copy_from_slice(key.as_bytes());
+    encoded
+}
+
+/// # Signed prekey of a responder
+///
+/// - Medium term key pair, signed by the identity key and published in the *PreKeyBundle*
+pub struct SignedPreKey {
+    /// ## Identifier sent back by initiators
+    pub id: u32,
+    /// ## Key pair of the prekey
+    pub keys: KeyPair,
+    /// ## Signature of the encoded public key by the identity key
+    pub signature: Signature,
+}
+
+/// # One-time prekey of a responder
+///
+/// - Used by a single initiator, then deleted by the responder
+pub struct OneTimePreKey {
+    /// ## Identifier sent back by initiators
+    pub id: u32,
+    /// ## Key pair of the prekey
+    pub keys: KeyPair,
+}
+
+/// # Public keys published by a responder
+///
+/// - Serialized as identity key, signed prekey id (big endian), signed prekey,
+///   signature and optionally one-time prekey id and key: 132 or 168 bytes
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PreKeyBundle {
+    /// ## Identity key of the responder
+    pub identity_key: PublicKey,
+    /// ## Identifier of the signed prekey
+    pub signed_prekey_id: u32,
+    /// ## Public signed prekey
+    pub signed_prekey: PublicKey,
+    /// ## Signature of the signed prekey by the identity key
+    pub signed_prekey_signature: Signature,
+    /// ## Identifier and public key of a one-time prekey, if any is left
+    pub one_time_prekey: Option<(u32, PublicKey)>,
+}
+
+/// # First message of an initiator
+///
+/// - Tells the responder which keys to agree the same secret with
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InitialMessage {
+    /// ## Identity key of the initiator
+    pub identity_key: PublicKey,
+    /// ## Ephemeral key of the initiator
+    pub ephemeral_key: PublicKey,
+    /// ## Identifier of the signed prekey used
+    pub signed_prekey_id: u32,
+    /// ## Identifier of the one-time prekey used, if any
+    pub one_time_prekey_id: Option<u32>,
+}
+
+/// # Result of the key agreement
+pub struct Agreement {
+    /// ## 32 byte secret shared by initiator and responder
+    pub secret: SharedSecret,
+    /// ## Encoded identity keys of initiator and responder, to authenticate the first messages
+    pub associated_data: Vec<u8>,
+}
+
+impl SignedPreKey {
+    /// ## Sign the prekey *`keys`* with the *`identity`* of the responder
+    #[cfg(feature = "getrandom")]
+    pub fn new(identity: &KeyPair, id: u32, keys: KeyPair) -> SignedPreKey {
+        use crate::utils::random::OsRng;
+
+        SignedPreKey::new_with_rng(identity, id, keys, &mut OsRng)
+    }
+
+    /// ## Same as [`SignedPreKey::new`] with your own random generator
+    pub fn new_with_rng<R: CryptoRng + RngCore>(
+        identity: &KeyPair,
+        id: u32,
+        keys: KeyPair,
+        rng: &mut R,
+    ) -> SignedPreKey {
+        let signature = identity.fast_signature_with_rng(&encode(&keys.pubk), rng);
+        SignedPreKey {
+            id,
+            keys,
+            signature,
+        }
+    }
+
+    /// ## Generate and sign a new prekey
+    #[cfg(feature = "getrandom")]
+    pub fn generate(identity: &KeyPair, id: u32) -> SignedPreKey {
+        SignedPreKey::new(identity, id, KeyPair::generate())
+    }
+}
+
+impl OneTimePreKey {
+    /// ## Generate a new one-time prekey
+    #[cfg(feature = "getrandom")]
+    pub fn generate(id: u32) -> OneTimePreKey {
+        OneTimePreKey {
+            id,
+            keys: KeyPair::generate(),
+        }
+    }
+}
+
+impl PreKeyBundle {
+    /**
+    ## Bundle the public keys of a responder
+
+    ```rust
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::protocol::x3dh::{OneTimePreKey, PreKeyBundle, SignedPreKey};
+
+    let identity = KeyPair::generate();
+    let signed_prekey = SignedPreKey::generate(&identity, 1);
+    let one_time_prekey = OneTimePreKey::generate(1);
+
+    let bundle = PreKeyBundle::new(&identity, &signed_prekey, Some(&one_time_prekey));
+    assert_eq!(Ok(bundle.clone()), PreKeyBundle::from_bytes(&bundle.to_bytes()));
+    ```
+    */
+    pub fn new(
+        identity: &KeyPair,
+        signed_prekey: &SignedPreKey,
+        one_time_prekey: Option<&OneTimePreKey>,
+    ) -> PreKeyBundle {
+        PreKeyBundle {
+            identity_key: identity.pubk,
+            signed_prekey_id: signed_prekey.id,
+            signed_prekey: signed_prekey.keys.pubk,
+            signed_prekey_signature: signed_prekey.signature,
+            one_time_prekey: one_time_prekey.map(|prekey| (prekey.id, prekey.keys.pubk)),
+        }
+    }
+
+    /// ## Check the signature of the signed prekey by the identity key
+    pub fn verify(&self) -> Result<(), Error> {
+        self.identity_key
+            .verify(&encode(&self.signed_prekey), &self.signed_prekey_signature)
+    }
+
+    /// ## Serialize the bundle into 132 or 168 bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(168);
+        bytes.extend_from_slice(self.identity_key.as_bytes());
+        bytes.extend_from_slice(&self.signed_prekey_id.to_be_bytes());
+        bytes.extend_from_slice(self.signed_prekey.as_bytes());
+        bytes.extend_from_slice(self.signed_prekey_signature.as_bytes());
+        if let Some((id, key)) = &self.one_time_prekey {
+            bytes.extend_from_slice(&id.to_be_bytes());
+            bytes.extend_from_slice(key.as_bytes());
+        }
+        bytes
+    }
+
+    /// ## Deserialize a bundle
+    ///
+    /// - Fails when there are not 132 or 168 bytes, the signature is not checked
+    pub fn from_bytes(bytes: &[u8]) -> Result<PreKeyBundle, Error> {
+        if bytes.len() != 132 && bytes.len() != 168 {
+            return Err(Error::InvalidLength {
+                expected: if bytes.len() < 132 { 132 } else { 168 },
+                actual: bytes.len(),
+            });
+        }
+
+        let one_time_prekey = match bytes.len() {
+            168 => Some((
+                u32::from_be_bytes(array(&bytes[132..136])?),
+                PublicKey::from_bytes(array(&bytes[136..168])?),
+            )),
+            _ => None,
+        };
+
+        Ok(PreKeyBundle {
+            identity_key: PublicKey::from_bytes(array(&bytes[..32])?),
+            signed_prekey_id: u32::from_be_bytes(array(&bytes[32..36])?),
+            signed_prekey: PublicKey::from_bytes(array(&bytes[36..68])?),
+            signed_prekey_signature: Signature::from_bytes(array(&bytes[68..132])?),
+            one_time_prekey,
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for PreKeyBundle {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<PreKeyBundle, Error> {
+        PreKeyBundle::from_bytes(bytes)
+    }
+}
+
+/// Derive the agreement from the concatenated Diffie-Hellman outputs
+fn agreement(dh: &[u8], initiator: &PublicKey, responder: &PublicKey) -> Agreement {
+    use crate::protocol::kdf::hkdf_sha512;
+
+    let mut secret = [0u8; 32];
+    hkdf_sha512(&[0; 64], dh, INFO, &mut secret);
+
+    let mut associated_data = encode(initiator).to_vec();
+    associated_data.extend_from_slice(&encode(responder));
+
+    Agreement {
+        secret: SharedSecret(secret),
+        associated_data,
+    }
+}
+
+/**
+# X3DH
+## Agree a secret with a responder from its published *PreKeyBundle*
+
+- Verifies the signed prekey, then agrees DH(IK_A, SPK_B), DH(EK_A, IK_B),
+  DH(EK_A, SPK_B) and DH(EK_A, OPK_B) when a one-time prekey is bundled
+- Returns the *InitialMessage* to send along the first encrypted message
+
+## Example
+
+```rust
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::protocol::x3dh::{initiate, respond, OneTimePreKey, PreKeyBundle, SignedPreKey};
+
+let alice = KeyPair::generate();
+let bob = KeyPair::generate();
+let signed_prekey = SignedPreKey::generate(&bob, 1);
+let one_time_prekey = OneTimePreKey::generate(7);
+let bundle = PreKeyBundle::new(&bob, &signed_prekey, Some(&one_time_prekey));
+
+let (message, sent) = initiate(&alice, &bundle).unwrap();
+let received = respond(&bob, &signed_prekey, Some(&one_time_prekey), &message).unwrap();
+
+assert_eq!(sent.secret.as_bytes(), received.secret.as_bytes());
+assert_eq!(sent.associated_data, received.associated_data);
+```
+*/
+#[cfg(feature = "getrandom")]
+pub fn initiate(
+    identity: &KeyPair,
+    bundle: &PreKeyBundle,
+) -> Result<(InitialMessage, Agreement), Error> {
+    use crate::utils::random::OsRng;
+
+    initiate_with(identity, bundle, &mut OsRng)
+}
+
+/// ## Same as [`initiate`], with the ephemeral key generated from *`rng`*
+pub fn initiate_with<R: CryptoRng + RngCore>(
+    identity: &KeyPair,
+    bundle: &PreKeyBundle,
+    rng: &mut R,
+) -> Result<(InitialMessage, Agreement), Error> {
+    bundle.verify()?;
+
+    let ephemeral = KeyPair::generate_with(rng);
+
+    // Wiped on every return, the capacity is never exceeded so nothing is left behind.
+    let mut dh = Zeroizing::new(Vec::with_capacity(32 * 5));
+    dh.extend_from_slice(&[0xff; 32]);
+    dh.extend_from_slice(identity.diffie_hellman(&bundle.signed_prekey)?.as_bytes());
+    dh.extend_from_slice(ephemeral.diffie_hellman(&bundle.identity_key)?.as_bytes());
+    dh.extend_from_slice(ephemeral.diffie_hellman(&bundle.signed_prekey)?.as_bytes());
+    if let Some((_, key)) = &bundle.one_time_prekey {
+        dh.extend_from_slice(ephemeral.diffie_hellman(key)?.as_bytes());
+    }
+
+    let message = InitialMessage {
+        identity_key: identity.pubk,
+        ephemeral_key: ephemeral.pubk,
+        signed_prekey_id: bundle.signed_prekey_id,
+        one_time_prekey_id: bundle.one_time_prekey.map(|(id, _)| id),
+    };
+    Ok((
+        message,
+        agreement(&dh, &identity.pubk, &bundle.identity_key),
+    ))
+}
+
+/**
+# X3DH
+## Agree the secret of an initiator from its *InitialMessage*
+
+- The prekeys must be the ones named by the message, otherwise it fails with
+  [`Error::UnknownPreKey`]; the one-time prekey should be deleted afterwards
+*/
+pub fn respond(
+    identity: &KeyPair,
+    signed_prekey: &SignedPreKey,
+    one_time_prekey: Option<&OneTimePreKey>,
+    message: &InitialMessage,
+) -> Result<Agreement, Error> {
+    if message.signed_prekey_id != signed_prekey.id {
+        return Err(Error::UnknownPreKey {
+            id: message.signed_prekey_id,
+        });
+    }
+
+    // Wiped on every return, the capacity is never exceeded so nothing is left behind.
+    let mut dh = Zeroizing::new(Vec::with_capacity(32 * 5));
+    dh.extend_from_slice(&[0xff; 32]);
+    dh.extend_from_slice(
+        signed_prekey
+            .keys
+            .diffie_hellman(&message.identity_key)?
+            .as_bytes(),
+    );
+    dh.extend_from_slice(identity.diffie_hellman(&message.ephemeral_key)?.as_bytes());
+    dh.extend_from_slice(
+        signed_prekey
+            .keys
+            .diffie_hellman(&message.ephemeral_key)?
+            .as_bytes(),
+    );
+
+    match (message.one_time_prekey_id, one_time_prekey) {
+        (Some(id), Some(prekey)) if id == prekey.id => {
+            dh.extend_from_slice(
+                prekey
+                    .keys
+                    .diffie_hellman(&message.ephemeral_key)?
+                    .as_bytes(),
+            );
+        }
+        (Some(id), _) => return Err(Error::UnknownPreKey { id }),
+        (None, _) => {}
+    }
+
+    Ok(agreement(&dh, &message.identity_key, &identity.pubk))
+}
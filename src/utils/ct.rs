@@ -0,0 +1,124 @@
+use core::ops::{BitAnd, BitOr, Not};
+
+/// # Secret boolean
+///
+/// - Holds 0 or 1, combined and consumed without branching
+/// - Converting it to `bool` declassifies it, only do so for public results
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Choice(u8);
+
+impl Choice {
+    /// ## Create from a bit, 0 or 1, hidden from the optimizer
+    pub(crate) fn from_bit(bit: u8) -> Choice {
+        Choice(core::hint::black_box(bit))
+    }
+
+    /// ## The bit, 0 or 1
+    pub(crate) fn unwrap_u8(self) -> u8 {
+        self.0
+    }
+
+    /// ## All ones when true, all zeros when false
+    pub(crate) fn mask_u64(self) -> u64 {
+        0u64.wrapping_sub(self.0 as u64)
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> bool {
+        choice.0 != 0
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, other: Choice) -> Choice {
+        Choice(self.0 & other.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Choice;
+
+    fn bitor(self, other: Choice) -> Choice {
+        Choice(self.0 | other.0)
+    }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Choice {
+        Choice(self.0 ^ 1)
+    }
+}
+
+/// # Selection without branching
+pub(crate) trait ConditionallySelectable: Copy {
+    /// ## *`a`* when *`choice`* is false, *`b`* when it is true
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    /// ## Replace *`self`* by *`other`* when *`choice`* is true
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        *self = Self::conditional_select(self, other, choice);
+    }
+
+    /// ## Swap *`a`* and *`b`* when *`choice`* is true
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let t = *a;
+        a.conditional_assign(b, choice);
+        b.conditional_assign(&t, choice);
+    }
+}
+
+impl ConditionallySelectable for u8 {
+    fn conditional_select(a: &u8, b: &u8, choice: Choice) -> u8 {
+        let mask = choice.mask_u64() as u8;
+        a ^ (mask & (a ^ b))
+    }
+}
+
+impl ConditionallySelectable for u64 {
+    fn conditional_select(a: &u64, b: &u64, choice: Choice) -> u64 {
+        a ^ (choice.mask_u64() & (a ^ b))
+    }
+}
+
+impl<T: ConditionallySelectable, const N: usize> ConditionallySelectable for [T; N] {
+    fn conditional_select(a: &[T; N], b: &[T; N], choice: Choice) -> [T; N] {
+        let mut o = *a;
+        for i in 0..N {
+            o[i] = T::conditional_select(&a[i], &b[i], choice);
+        }
+        o
+    }
+}
+
+/// # Comparison without branching
+pub(crate) trait ConstantTimeEq {
+    /// ## True when both values are equal, reading all of them
+    fn ct_eq(&self, other: &Self) -> Choice;
+}
+
+impl ConstantTimeEq for u8 {
+    fn ct_eq(&self, other: &u8) -> Choice {
+        // x - 1 only borrows when x = 0.
+        let x = (self ^ other) as u32;
+        Choice::from_bit((x.wrapping_sub(1) >> 31) as u8)
+    }
+}
+
+impl ConstantTimeEq for [u8] {
+    fn ct_eq(&self, other: &[u8]) -> Choice {
+        if self.len() != other.len() {
+            return Choice::from_bit(0);
+        }
+
+        let mut d = 0u8;
+        for (x, y) in self.iter().zip(other) {
+            d |= x ^ y;
+        }
+        d.ct_eq(&0)
+    }
+}
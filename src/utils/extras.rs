@@ -8,1155 +8,1559 @@
 // https://moderncrypto.org/mail-archive/curves/2014/000205.html
 //
 // Derived from axlsign.js written by Dmitry Chestnykh. https://github.com/wavesplatform/curve25519-js
-#![allow(unused, non_snake_case, non_upper_case_globals)]
-use rand::Rng; // 0.8.0
+#![allow(
+    unused,
+    non_snake_case,
+    non_upper_case_globals,
+    clippy::needless_range_loop
+)]
+use crate::hash::Sha512;
+use crate::utils::ct::{Choice, ConditionallySelectable, ConstantTimeEq};
+use crate::Error;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use zeroize::Zeroize;
+
+mod base;
+
+// Field element modulo 2^255 - 19, as five limbs of 51 bits.
+// Every operation returns limbs below 2^52, the inputs of the next one.
+type Gf = [u64; 5];
+
+// Point of the Edwards curve in extended coordinates, x = X/Z, y = Y/Z, xy = T/Z.
+#[derive(Clone, Copy)]
+struct Point {
+    x: Gf,
+    y: Gf,
+    z: Gf,
+    t: Gf,
+}
+
+// Affine point as (y + x, y - x, 2dxy), precomputed for additions.
+#[derive(Clone, Copy)]
+struct Niels {
+    y_plus_x: Gf,
+    y_minus_x: Gf,
+    xy2d: Gf,
+}
 
-fn gf() -> Vec<i64> {
-    let mut r: Vec<i64> = vec![0; 16];
-    r
+// Point as (Y + X, Y - X, 2Z, 2dT), precomputed for variable base additions.
+#[derive(Clone, Copy)]
+struct Cached {
+    y_plus_x: Gf,
+    y_minus_x: Gf,
+    z2: Gf,
+    t2d: Gf,
 }
 
-const _9: [u32; 32] = [
+const MASK: u64 = (1 << 51) - 1;
+
+const _9: [u8; 32] = [
     0x9, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
     0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
 ];
 
-const gf0: [i64; 16] = [
-    0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
-];
+const gf0: Gf = [0, 0, 0, 0, 0];
 
-const gf1: [i64; 16] = [
-    1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
-];
+const gf1: Gf = [1, 0, 0, 0, 0];
 
-const _121665: [i64; 16] = [
-    0xdb41, 1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
-];
+const _121665: Gf = [121665, 0, 0, 0, 0];
 
-const D: [i64; 16] = [
-    0x78a3, 0x1359, 0x4dca, 0x75eb, 0xd8ab, 0x4141, 0x0a4d, 0x0070, 0xe898, 0x7779, 0x4079, 0x8cc7,
-    0xfe73, 0x2b6f, 0x6cee, 0x5203,
+const D: Gf = [
+    0x34dca135978a3,
+    0x1a8283b156ebd,
+    0x5e7a26001c029,
+    0x739c663a03cbb,
+    0x52036cee2b6ff,
 ];
 
-const D2: [i64; 16] = [
-    0xf159, 0x26b2, 0x9b94, 0xebd6, 0xb156, 0x8283, 0x149a, 0x00e0, 0xd130, 0xeef3, 0x80f2, 0x198e,
-    0xfce7, 0x56df, 0xd9dc, 0x2406,
+const D2: Gf = [
+    0x69b9426b2f159,
+    0x35050762add7a,
+    0x3cf44c0038052,
+    0x6738cc7407977,
+    0x2406d9dc56dff,
 ];
 
-const X: [i64; 16] = [
-    0xd51a, 0x8f25, 0x2d60, 0xc956, 0xa7b2, 0x9525, 0xc760, 0x692c, 0xdc5c, 0xfdd6, 0xe231, 0xc0a4,
-    0x53fe, 0xcd6e, 0x36d3, 0x2169,
+const X: Gf = [
+    0x62d608f25d51a,
+    0x412a4b4f6592a,
+    0x75b7171a4b31d,
+    0x1ff60527118fe,
+    0x216936d3cd6e5,
 ];
 
-const Y: [i64; 16] = [
-    0x6658, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666, 0x6666,
-    0x6666, 0x6666, 0x6666, 0x6666,
+const Y: Gf = [
+    0x6666666666658,
+    0x4cccccccccccc,
+    0x1999999999999,
+    0x3333333333333,
+    0x6666666666666,
 ];
 
-const I: [i64; 16] = [
-    0xa0b0, 0x4a0e, 0x1b27, 0xc4ee, 0xe478, 0xad2f, 0x1806, 0x2f43, 0xd7a7, 0x3dfb, 0x0099, 0x2b4d,
-    0xdf0b, 0x4fc1, 0x2480, 0x2b83,
+const I: Gf = [
+    0x61b274a0ea0b0,
+    0x0d5a5fc8f189d,
+    0x7ef5e9cbd0c60,
+    0x78595a6804c9e,
+    0x2b8324804fc1d,
 ];
 
-fn ts64(x: &mut Vec<u32>, i: usize, h: u32, l: u32) {
-    x[i] = (h >> 24) & 0xff;
-    x[i + 1] = (h >> 16) & 0xff;
-    x[i + 2] = (h >> 8) & 0xff;
-    x[i + 3] = h & 0xff;
-    x[i + 4] = (l >> 24) & 0xff;
-    x[i + 5] = (l >> 16) & 0xff;
-    x[i + 6] = (l >> 8) & 0xff;
-    x[i + 7] = l & 0xff;
+// Montgomery curve coefficient A.
+const _486662: Gf = [486662, 0, 0, 0, 0];
+
+const IDENTITY: Point = Point {
+    x: gf0,
+    y: gf1,
+    z: gf1,
+    t: gf0,
+};
+
+const BASE: Point = Point {
+    x: X,
+    y: Y,
+    z: gf1,
+    t: [
+        0x68ab3a5b7dda3,
+        0x00eea2a5eadbb,
+        0x2af8df483c27e,
+        0x332b375274732,
+        0x67875f0fd78b7,
+    ],
+};
+
+impl ConditionallySelectable for Point {
+    fn conditional_select(a: &Point, b: &Point, choice: Choice) -> Point {
+        Point {
+            x: Gf::conditional_select(&a.x, &b.x, choice),
+            y: Gf::conditional_select(&a.y, &b.y, choice),
+            z: Gf::conditional_select(&a.z, &b.z, choice),
+            t: Gf::conditional_select(&a.t, &b.t, choice),
+        }
+    }
 }
 
-fn vn(x: &Vec<u32>, xi: usize, y: &Vec<u32>, yi: usize, n: usize) -> isize {
-    let mut d: u32 = 0;
-    for i in 1..n {
-        d = d | (x[xi + i] ^ y[yi + i]);
+impl ConditionallySelectable for Niels {
+    fn conditional_select(a: &Niels, b: &Niels, choice: Choice) -> Niels {
+        Niels {
+            y_plus_x: Gf::conditional_select(&a.y_plus_x, &b.y_plus_x, choice),
+            y_minus_x: Gf::conditional_select(&a.y_minus_x, &b.y_minus_x, choice),
+            xy2d: Gf::conditional_select(&a.xy2d, &b.xy2d, choice),
+        }
     }
-    let _d: i32 = d as i32 - 1;
-    let _r = (1 & (_d as u32 >> 8)) as i32 - 1;
-
-    _r as isize
 }
 
-fn crypto_verify_32(x: &Vec<u32>, xi: usize, y: &Vec<u32>, yi: usize) -> isize {
-    vn(x, xi, y, yi, 32)
+// True when the 32 bytes at x[xi..] and y[yi..] are equal.
+fn crypto_verify_32(x: &[u8], xi: usize, y: &[u8], yi: usize) -> Choice {
+    x[xi..xi + 32].ct_eq(&y[yi..yi + 32])
 }
 
-fn set25519(r: &mut Vec<i64>, a: &Vec<i64>) {
-    for i in 0..16 {
-        r[i] = a[i] | 0;
+fn car25519(o: &mut Gf) {
+    for i in 0..4 {
+        o[i + 1] += o[i] >> 51;
+        o[i] &= MASK;
     }
+    o[0] += 19 * (o[4] >> 51);
+    o[4] &= MASK;
 }
 
-fn car25519(o: &mut Vec<i64>) {
-    let mut v: i64;
-    let mut c: i64 = 1;
-    for i in 0..16 {
-        v = o[i] + c + 65535;
-        c = v / 65536;
-        o[i] = v - c * 65536;
+// Carries the 102 bit limbs of a product.
+fn car25519_wide(r: [u128; 5]) -> Gf {
+    let mut o = [0u64; 5];
+    let mut c: u128 = 0;
+    for i in 0..5 {
+        let v = r[i] + c;
+        o[i] = v as u64 & MASK;
+        c = v >> 51;
     }
-    o[0] += c - 1 + 37 * (c - 1);
-}
+    let v = o[0] as u128 + 19 * c;
+    o[0] = v as u64 & MASK;
+    o[1] += (v >> 51) as u64;
 
-fn sel25519(p: &mut Vec<i64>, q: &mut Vec<i64>, b: isize) {
-    let mut t: i64;
-    let c: i64 = (!(b - 1)) as i64;
-    for i in 0..16 {
-        t = c & (p[i] ^ q[i]);
-        p[i] = p[i] ^ t;
-        q[i] = q[i] ^ t;
-    }
+    o
 }
 
-fn pack25519(o: &mut Vec<u32>, n: &Vec<i64>) {
-    let mut b: i64;
-    let mut m = gf();
-    let mut t = gf();
-
-    for i in 0..16 {
-        t[i] = n[i];
-    }
-    car25519(&mut t);
-    car25519(&mut t);
+fn pack25519(o: &mut [u8], n: &Gf) {
+    let mut t = *n;
     car25519(&mut t);
 
-    for j in 0..2 {
-        m[0] = t[0] - 0xffed;
-        for i in 1..15 {
-            m[i] = t[i] - 0xffff - ((m[i - 1] >> 16) & 1);
-            m[i - 1] = m[i - 1] & 0xffff;
-        }
-        m[15] = t[15] - 0x7fff - ((m[14] >> 16) & 1);
-        b = (m[15] >> 16) & 1;
-        m[14] = m[14] & 0xffff;
-        sel25519(&mut t, &mut m, 1 - b as isize);
+    // t < 2p, subtract p when t + 19 overflows 2^255.
+    let mut q = (t[0] + 19) >> 51;
+    for i in 1..5 {
+        q = (t[i] + q) >> 51;
     }
-
-    for i in 0..16 {
-        o[2 * i] = (t[i] & 0xff) as u32;
-        o[2 * i + 1] = (t[i] >> 8) as u32;
+    t[0] += 19 * q;
+    for i in 0..4 {
+        t[i + 1] += t[i] >> 51;
+        t[i] &= MASK;
+    }
+    t[4] &= MASK;
+
+    let mut acc: u128 = 0;
+    let mut bits = 0;
+    let mut j = 0;
+    for limb in t {
+        acc |= (limb as u128) << bits;
+        bits += 51;
+        while bits >= 8 {
+            o[j] = acc as u8;
+            acc >>= 8;
+            bits -= 8;
+            j += 1;
+        }
     }
+    o[31] = acc as u8;
 }
 
-fn neq25519(a: &Vec<i64>, b: &Vec<i64>) -> isize {
-    let mut c: Vec<u32> = vec![0; 32];
-    let mut d: Vec<u32> = vec![0; 32];
+fn eq25519(a: &Gf, b: &Gf) -> Choice {
+    let mut c = [0u8; 32];
+    let mut d = [0u8; 32];
     pack25519(&mut c, a);
     pack25519(&mut d, b);
 
     crypto_verify_32(&c, 0, &d, 0)
 }
 
-fn par25519(a: &Vec<i64>) -> u32 {
-    let mut d: Vec<u32> = vec![0; 32];
+fn par25519(a: &Gf) -> u8 {
+    let mut d = [0u8; 32];
     pack25519(&mut d, a);
 
     d[0] & 1
 }
 
-fn unpack25519(o: &mut Vec<i64>, n: &Vec<u32>) {
-    for i in 0..16 {
-        let value: u32 = n[2 * i] + (n[2 * i + 1] << 8);
-        o[i] = value as i64;
-    }
-    o[15] = o[15] & 0x7fff;
+fn unpack25519(n: &[u8]) -> Gf {
+    let load = |i: usize| {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(&n[i..i + 8]);
+        u64::from_le_bytes(b)
+    };
+
+    [
+        load(0) & MASK,
+        (load(6) >> 3) & MASK,
+        (load(12) >> 6) & MASK,
+        (load(19) >> 1) & MASK,
+        (load(24) >> 12) & MASK,
+    ]
 }
 
-fn A(o: &mut Vec<i64>, a: &Vec<i64>, b: &Vec<i64>) {
-    for i in 0..16 {
+fn A(a: &Gf, b: &Gf) -> Gf {
+    let mut o = [0u64; 5];
+    for i in 0..5 {
         o[i] = a[i] + b[i];
     }
+    car25519(&mut o);
+
+    o
+}
+
+// a - b, adding 4p so that limbs stay positive.
+fn Z(a: &Gf, b: &Gf) -> Gf {
+    let mut o = [
+        a[0] + 0x1fffffffffffb4 - b[0],
+        a[1] + 0x1ffffffffffffc - b[1],
+        a[2] + 0x1ffffffffffffc - b[2],
+        a[3] + 0x1ffffffffffffc - b[3],
+        a[4] + 0x1ffffffffffffc - b[4],
+    ];
+    car25519(&mut o);
+
+    o
+}
+
+fn M(a: &Gf, b: &Gf) -> Gf {
+    let m = |x: u64, y: u64| x as u128 * y as u128;
+    let b1 = 19 * b[1];
+    let b2 = 19 * b[2];
+    let b3 = 19 * b[3];
+    let b4 = 19 * b[4];
+
+    car25519_wide([
+        m(a[0], b[0]) + m(a[1], b4) + m(a[2], b3) + m(a[3], b2) + m(a[4], b1),
+        m(a[0], b[1]) + m(a[1], b[0]) + m(a[2], b4) + m(a[3], b3) + m(a[4], b2),
+        m(a[0], b[2]) + m(a[1], b[1]) + m(a[2], b[0]) + m(a[3], b4) + m(a[4], b3),
+        m(a[0], b[3]) + m(a[1], b[2]) + m(a[2], b[1]) + m(a[3], b[0]) + m(a[4], b4),
+        m(a[0], b[4]) + m(a[1], b[3]) + m(a[2], b[2]) + m(a[3], b[1]) + m(a[4], b[0]),
+    ])
 }
 
-fn Z(o: &mut Vec<i64>, a: &Vec<i64>, b: &Vec<i64>) {
-    for i in 0..16 {
-        o[i] = a[i] - b[i];
+fn S(a: &Gf) -> Gf {
+    let m = |x: u64, y: u64| x as u128 * y as u128;
+    let d0 = 2 * a[0];
+    let d1 = 2 * a[1];
+    let a3 = 19 * a[3];
+    let a4 = 19 * a[4];
+
+    car25519_wide([
+        m(a[0], a[0]) + m(d1, a4) + m(2 * a[2], a3),
+        m(d0, a[1]) + m(2 * a[2], a4) + m(a[3], a3),
+        m(d0, a[2]) + m(a[1], a[1]) + m(2 * a[3], a4),
+        m(d0, a[3]) + m(d1, a[2]) + m(a[4], a4),
+        m(d0, a[4]) + m(d1, a[3]) + m(a[2], a[2]),
+    ])
+}
+
+// a^(2^n)
+fn Sn(a: &Gf, n: usize) -> Gf {
+    let mut o = *a;
+    for _ in 0..n {
+        o = S(&o);
     }
+
+    o
+}
+
+// Returns (i^(2^250 - 1), i^11), shared by inversion and square roots.
+fn pow22501(i: &Gf) -> (Gf, Gf) {
+    let t0 = S(i);
+    let t1 = M(i, &Sn(&t0, 2));
+    let t0 = M(&t0, &t1);
+    let t1 = M(&t1, &S(&t0));
+    let t1 = M(&Sn(&t1, 5), &t1);
+    let t2 = M(&Sn(&t1, 10), &t1);
+    let t2 = M(&Sn(&t2, 20), &t2);
+    let t1 = M(&Sn(&t2, 10), &t1);
+    let t2 = M(&Sn(&t1, 50), &t1);
+    let t2 = M(&Sn(&t2, 100), &t2);
+    let t1 = M(&Sn(&t2, 50), &t1);
+
+    (t1, t0)
+}
+
+// i^(p - 2)
+fn inv25519(i: &Gf) -> Gf {
+    let (t1, t0) = pow22501(i);
+
+    M(&Sn(&t1, 5), &t0)
 }
 
-// optimized by Miguel
-fn M(o: &mut Vec<i64>, a: &Vec<i64>, b: &Vec<i64>) {
-    let mut at: Vec<i64> = vec![0; 32];
-    let mut ab: Vec<i64> = vec![0; 16];
+// i^((p - 5) / 8)
+fn pow2523(i: &Gf) -> Gf {
+    let (t1, _) = pow22501(i);
 
-    for i in 0..16 {
-        ab[i] = b[i];
+    M(&Sn(&t1, 2), i)
+}
+
+fn crypto_scalarmult(q: &mut [u8], n: &[u8], p: &[u8]) -> usize {
+    let mut z = [0u8; 32];
+    let mut r: Choice;
+
+    z[..31].copy_from_slice(&n[..31]);
+    z[31] = (n[31] & 127) | 64;
+    z[0] &= 248;
+
+    let x = unpack25519(p);
+    let mut a = gf1;
+    let mut b = x;
+    let mut c = gf0;
+    let mut d = gf1;
+    let mut e: Gf;
+    let mut f: Gf;
+
+    for i in (0..=254).rev() {
+        r = Choice::from_bit((z[i >> 3] >> (i & 7)) & 1);
+
+        Gf::conditional_swap(&mut a, &mut b, r);
+        Gf::conditional_swap(&mut c, &mut d, r);
+        e = A(&a, &c);
+        a = Z(&a, &c);
+        c = A(&b, &d);
+        b = Z(&b, &d);
+        d = S(&e);
+        f = S(&a);
+        a = M(&c, &a);
+        c = M(&b, &e);
+        e = A(&a, &c);
+        a = Z(&a, &c);
+        b = S(&a);
+        c = Z(&d, &f);
+        a = M(&c, &_121665);
+        a = A(&a, &d);
+        c = M(&c, &a);
+        a = M(&d, &f);
+        d = M(&b, &x);
+        b = S(&e);
+        Gf::conditional_swap(&mut a, &mut b, r);
+        Gf::conditional_swap(&mut c, &mut d, r);
+    }
+
+    pack25519(q, &M(&a, &inv25519(&c)));
+
+    z.zeroize();
+
+    0
+}
+
+// p = p + q
+fn add(p: &mut Point, q: &Point) {
+    let a = M(&Z(&p.y, &p.x), &Z(&q.y, &q.x));
+    let b = M(&A(&p.x, &p.y), &A(&q.x, &q.y));
+    let c = M(&M(&p.t, &q.t), &D2);
+    let d = M(&p.z, &q.z);
+    let d = A(&d, &d);
+    let e = Z(&b, &a);
+    let f = Z(&d, &c);
+    let g = A(&d, &c);
+    let h = A(&b, &a);
+
+    p.x = M(&e, &f);
+    p.y = M(&h, &g);
+    p.z = M(&g, &f);
+    p.t = M(&e, &h);
+}
+
+// p = 2p, without the T coordinate of p.
+fn double(p: &mut Point) {
+    let a = S(&p.x);
+    let b = S(&p.y);
+    let c = S(&p.z);
+    let c = A(&c, &c);
+    let e = Z(&Z(&S(&A(&p.x, &p.y)), &a), &b);
+    let g = Z(&b, &a);
+    let f = Z(&g, &c);
+    let h = Z(&Z(&gf0, &a), &b);
+
+    p.x = M(&e, &f);
+    p.y = M(&g, &h);
+    p.z = M(&f, &g);
+    p.t = M(&e, &h);
+}
+
+fn pack(r: &mut [u8], p: &Point) {
+    let zi = inv25519(&p.z);
+    let tx = M(&p.x, &zi);
+    let ty = M(&p.y, &zi);
+
+    pack25519(r, &ty);
+
+    r[31] ^= par25519(&tx) << 7
+}
+
+fn scalarmult(p: &mut Point, q: &Point, s: &[u8]) {
+    let mut b: Choice;
+    let mut q = *q;
+
+    *p = IDENTITY;
+
+    for i in (0..=255).rev() {
+        b = Choice::from_bit((s[i / 8] >> (i & 7)) & 1);
+        Point::conditional_swap(p, &mut q, b);
+        add(&mut q, p);
+        double(p);
+        Point::conditional_swap(p, &mut q, b);
     }
+}
 
-    let mut v: i64;
-    for i in 0..16 {
-        v = a[i];
-        for j in 0..16 {
-            at[j + i] += v * ab[j];
-        }
+// p = p + q
+fn madd(p: &mut Point, q: &Niels) {
+    let a = M(&Z(&p.y, &p.x), &q.y_minus_x);
+    let b = M(&A(&p.y, &p.x), &q.y_plus_x);
+    let c = M(&p.t, &q.xy2d);
+    let d = A(&p.z, &p.z);
+    let e = Z(&b, &a);
+    let f = Z(&d, &c);
+    let g = A(&d, &c);
+    let h = A(&b, &a);
+
+    p.x = M(&e, &f);
+    p.y = M(&h, &g);
+    p.z = M(&g, &f);
+    p.t = M(&e, &h);
+}
+
+// b B 256^i for a signed digit -8 <= b <= 8, reading every entry of the table.
+fn select(i: usize, b: i8) -> Niels {
+    let negative = Choice::from_bit(b as u8 >> 7);
+    let babs = (b as u8).wrapping_sub((0u8.wrapping_sub(negative.unwrap_u8()) & b as u8) << 1);
+    let mut t = Niels {
+        y_plus_x: gf1,
+        y_minus_x: gf1,
+        xy2d: gf0,
+    };
+
+    for (j, entry) in base::BASE_TABLE[i].iter().enumerate() {
+        t.conditional_assign(entry, babs.ct_eq(&(j as u8 + 1)));
     }
 
-    for i in 0..15 {
-        at[i] += 38 * at[i + 16];
+    let neg = Niels {
+        y_plus_x: t.y_minus_x,
+        y_minus_x: t.y_plus_x,
+        xy2d: Z(&gf0, &t.xy2d),
+    };
+    t.conditional_assign(&neg, negative);
+
+    t
+}
+
+// Fixed base comb over signed radix 16 digits, s must be below 2^255.
+fn scalarbase(p: &mut Point, s: &[u8]) {
+    let mut e = [0i8; 64];
+    for i in 0..32 {
+        e[2 * i] = (s[i] & 15) as i8;
+        e[2 * i + 1] = (s[i] >> 4) as i8;
     }
-    // t15 left as is
 
-    // first car
-    let mut c: i64 = 1;
-    for i in 0..16 {
-        v = at[i] + c + 65535;
-        c = (v as f64 / 65536.0).floor() as i64;
-        at[i] = v - c * 65536;
+    // Digits from -8 to 8.
+    let mut carry = 0i8;
+    for i in 0..63 {
+        e[i] += carry;
+        carry = (e[i] + 8) >> 4;
+        e[i] -= carry << 4;
     }
-    at[0] += c - 1 + 37 * (c - 1);
+    e[63] += carry;
 
-    // second car
-    c = 1;
-    for i in 0..16 {
-        v = at[i] + c + 65535;
-        c = (v as f64 / 65536.0).floor() as i64;
-        at[i] = v - c * 65536;
+    *p = IDENTITY;
+    for i in (1..64).step_by(2) {
+        madd(p, &select(i / 2, e[i]));
+    }
+    for _ in 0..4 {
+        double(p);
+    }
+    for i in (0..64).step_by(2) {
+        madd(p, &select(i / 2, e[i]));
     }
-    at[0] += c - 1 + 37 * (c - 1);
 
-    for i in 0..16 {
-        o[i] = at[i];
+    e.zeroize();
+}
+
+fn cached(p: &Point) -> Cached {
+    Cached {
+        y_plus_x: A(&p.y, &p.x),
+        y_minus_x: Z(&p.y, &p.x),
+        z2: A(&p.z, &p.z),
+        t2d: M(&p.t, &D2),
     }
 }
 
-fn S(o: &mut Vec<i64>, a: &Vec<i64>) {
-    M(o, &a, &a);
+// p = p + q, or p - q when negative.
+fn add_cached(p: &mut Point, q: &Cached, negative: bool) {
+    let (y_plus_x, y_minus_x) = match negative {
+        true => (&q.y_minus_x, &q.y_plus_x),
+        false => (&q.y_plus_x, &q.y_minus_x),
+    };
+    let a = M(&Z(&p.y, &p.x), y_minus_x);
+    let b = M(&A(&p.y, &p.x), y_plus_x);
+    let c = match negative {
+        true => Z(&gf0, &M(&p.t, &q.t2d)),
+        false => M(&p.t, &q.t2d),
+    };
+    let d = M(&p.z, &q.z2);
+    let e = Z(&b, &a);
+    let f = Z(&d, &c);
+    let g = A(&d, &c);
+    let h = A(&b, &a);
+
+    p.x = M(&e, &f);
+    p.y = M(&h, &g);
+    p.z = M(&g, &f);
+    p.t = M(&e, &h);
+}
+
+// Scalar of a multiscalarmult in width 5 non-adjacent form, odd digits from -15 to 15,
+// with the odd multiples P, 3P, .., 15P of its point.
+struct Term {
+    naf: [i8; 256],
+    table: [Cached; 8],
 }
 
-fn inv25519(o: &mut Vec<i64>, i: &Vec<i64>) {
-    let mut c = gf();
-    for a in 0..16 {
-        c[a] = i[a];
+// s must be below 2^255.
+fn term(s: &[u8], q: &Point) -> Term {
+    let mut x = [0u64; 5];
+    for i in 0..4 {
+        x[i] = u64::from_le_bytes(s[8 * i..8 * i + 8].try_into().unwrap());
     }
 
-    for a in (0..=253).rev() {
-        let cc = c.clone();
-        S(&mut c, &cc);
-        if a != 2 && a != 4 {
-            let cc = c.clone();
-            M(&mut c, &cc, i);
+    let mut naf = [0i8; 256];
+    let mut carry = 0;
+    let mut pos = 0;
+    while pos < 256 {
+        let (i, bit) = (pos / 64, pos % 64);
+        let bits = match bit < 59 {
+            true => x[i] >> bit,
+            false => (x[i] >> bit) | (x[i + 1] << (64 - bit)),
+        };
+        let window = carry + (bits & 31);
+        if window & 1 == 0 {
+            pos += 1;
+            continue;
         }
+        carry = window >> 4;
+        naf[pos] = window as i8 - (carry << 5) as i8;
+        pos += 5;
     }
-    for a in 0..16 {
-        o[a] = c[a];
+
+    let mut q2 = *q;
+    double(&mut q2);
+    let q2 = cached(&q2);
+    let mut table = [cached(q); 8];
+    let mut p = *q;
+    for k in 1..8 {
+        add_cached(&mut p, &q2, false);
+        table[k] = cached(&p);
     }
+
+    Term { naf, table }
 }
 
-fn pow2523(o: &mut Vec<i64>, i: &Vec<i64>) {
-    let mut c = gf();
-    for a in 0..16 {
-        c[a] = i[a];
-    }
-    for a in (0..=250).rev() {
-        let cc = c.clone();
-        S(&mut c, &cc);
-        if a != 1 {
-            let cc = c.clone();
-            M(&mut c, &cc, &i);
+// Variable time sum of [s_i] P_i, sharing the doublings between the terms.
+fn multiscalarmult(p: &mut Point, terms: &[Term]) {
+    *p = IDENTITY;
+
+    let top = terms
+        .iter()
+        .filter_map(|term| term.naf.iter().rposition(|d| *d != 0))
+        .max();
+    let Some(top) = top else {
+        return;
+    };
+
+    for i in (0..=top).rev() {
+        double(p);
+        for term in terms {
+            let digit = term.naf[i];
+            if digit != 0 {
+                let entry = &term.table[(digit.unsigned_abs() / 2) as usize];
+                add_cached(p, entry, digit < 0);
+            }
         }
     }
-    for a in 0..16 {
-        o[a] = c[a];
-    }
 }
 
-fn crypto_scalarmult(q: &mut Vec<u32>, n: &Vec<u32>, p: &Vec<u32>) -> usize {
-    let mut z: Vec<u32> = vec![0; 32];
-    let mut x: Vec<i64> = vec![0; 80];
-    let mut r: u32;
+// Scalars modulo L = 2^252 + 27742317777372353535851937790883648493,
+// as five limbs of 52 bits in Montgomery form with R = 2^260.
+type Scalar = [u64; 5];
+
+const L: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+];
 
-    let mut a = gf();
-    let mut b = gf();
-    let mut c = gf();
-    let mut d = gf();
-    let mut e = gf();
-    let mut f = gf();
+const SC_MASK: u64 = (1 << 52) - 1;
 
-    for i in 0..31 {
-        z[i] = n[i];
-    }
-    z[31] = (n[31] & 127) | 64;
-    z[0] = z[0] & 248;
+const L52: Scalar = [
+    0x2631a5cf5d3ed,
+    0xdea2f79cd6581,
+    0x000000014def9,
+    0x0000000000000,
+    0x0100000000000,
+];
 
-    unpack25519(&mut x, p);
+// R mod L
+const R52: Scalar = [
+    0xf48bd6721e6ed,
+    0x3bab5ac67e45a,
+    0xfffffeb35e51b,
+    0xfffffffffffff,
+    0x00fffffffffff,
+];
 
-    for i in 0..16 {
-        b[i] = x[i];
-        d[i] = 0;
-        a[i] = 0;
-        c[i] = 0;
-    }
-    a[0] = 1;
-    d[0] = 1;
+// R^2 mod L
+const RR52: Scalar = [
+    0x9d265e952d13b,
+    0xd63c715bea69f,
+    0x5be65cb687604,
+    0x3dceec73d217f,
+    0x009411b7c309a,
+];
 
-    for i in (0..=254).rev() {
-        r = (z[i >> 3] >> (i & 7)) & 1; // *** R r=(z[i>>>3]>>>(i&7))&1;
-
-        sel25519(&mut a, &mut b, r as isize);
-        sel25519(&mut c, &mut d, r as isize);
-        A(&mut e, &a, &c);
-        let aa = a.clone();
-        Z(&mut a, &aa, &c);
-        A(&mut c, &b, &d);
-        let bb = b.clone();
-        Z(&mut b, &bb, &d);
-        S(&mut d, &e);
-        S(&mut f, &a);
-        let aa = a.clone();
-        M(&mut a, &c, &aa);
-        M(&mut c, &b, &e);
-        A(&mut e, &a, &c);
-        let aa = a.clone();
-        Z(&mut a, &aa, &c);
-        S(&mut b, &a);
-        Z(&mut c, &d, &f);
-        M(&mut a, &c, &_121665.to_vec());
-        let aa = a.clone();
-        A(&mut a, &aa, &d);
-        let cc = c.clone();
-        M(&mut c, &cc, &a);
-        M(&mut a, &d, &f);
-        M(&mut d, &b, &x);
-        S(&mut b, &e);
-        sel25519(&mut a, &mut b, r as isize);
-        sel25519(&mut c, &mut d, r as isize);
-    }
-
-    for i in 0..16 {
-        x[i + 16] = a[i];
-        x[i + 32] = c[i];
-        x[i + 48] = b[i];
-        x[i + 64] = d[i];
-    }
-
-    let mut x32: Vec<i64> = (&x[32..]).to_vec();
-    let mut x16: Vec<i64> = (&x[16..]).to_vec();
-
-    let xx32 = x32.clone();
-    inv25519(&mut x32, &xx32);
-    let xx16 = x16.clone();
-    M(&mut x16, &xx16, &x32);
-
-    pack25519(q, &x16);
+// -1 / L mod 2^52
+const LFACTOR: u64 = 0x51da312547e1b;
 
-    0
+fn sc_load(n: &[u8], i: usize) -> u64 {
+    let mut b = [0u8; 8];
+    b.copy_from_slice(&n[i..i + 8]);
+    u64::from_le_bytes(b)
 }
 
-// Constantes de cada ronda del SHA-512
-const K: [i64; 160] = [
-    0x428a2f98, 0xd728ae22, 0x71374491, 0x23ef65cd, 0xb5c0fbcf, 0xec4d3b2f, 0xe9b5dba5, 0x8189dbbc,
-    0x3956c25b, 0xf348b538, 0x59f111f1, 0xb605d019, 0x923f82a4, 0xaf194f9b, 0xab1c5ed5, 0xda6d8118,
-    0xd807aa98, 0xa3030242, 0x12835b01, 0x45706fbe, 0x243185be, 0x4ee4b28c, 0x550c7dc3, 0xd5ffb4e2,
-    0x72be5d74, 0xf27b896f, 0x80deb1fe, 0x3b1696b1, 0x9bdc06a7, 0x25c71235, 0xc19bf174, 0xcf692694,
-    0xe49b69c1, 0x9ef14ad2, 0xefbe4786, 0x384f25e3, 0x0fc19dc6, 0x8b8cd5b5, 0x240ca1cc, 0x77ac9c65,
-    0x2de92c6f, 0x592b0275, 0x4a7484aa, 0x6ea6e483, 0x5cb0a9dc, 0xbd41fbd4, 0x76f988da, 0x831153b5,
-    0x983e5152, 0xee66dfab, 0xa831c66d, 0x2db43210, 0xb00327c8, 0x98fb213f, 0xbf597fc7, 0xbeef0ee4,
-    0xc6e00bf3, 0x3da88fc2, 0xd5a79147, 0x930aa725, 0x06ca6351, 0xe003826f, 0x14292967, 0x0a0e6e70,
-    0x27b70a85, 0x46d22ffc, 0x2e1b2138, 0x5c26c926, 0x4d2c6dfc, 0x5ac42aed, 0x53380d13, 0x9d95b3df,
-    0x650a7354, 0x8baf63de, 0x766a0abb, 0x3c77b2a8, 0x81c2c92e, 0x47edaee6, 0x92722c85, 0x1482353b,
-    0xa2bfe8a1, 0x4cf10364, 0xa81a664b, 0xbc423001, 0xc24b8b70, 0xd0f89791, 0xc76c51a3, 0x0654be30,
-    0xd192e819, 0xd6ef5218, 0xd6990624, 0x5565a910, 0xf40e3585, 0x5771202a, 0x106aa070, 0x32bbd1b8,
-    0x19a4c116, 0xb8d2d0c8, 0x1e376c08, 0x5141ab53, 0x2748774c, 0xdf8eeb99, 0x34b0bcb5, 0xe19b48a8,
-    0x391c0cb3, 0xc5c95a63, 0x4ed8aa4a, 0xe3418acb, 0x5b9cca4f, 0x7763e373, 0x682e6ff3, 0xd6b2b8a3,
-    0x748f82ee, 0x5defb2fc, 0x78a5636f, 0x43172f60, 0x84c87814, 0xa1f0ab72, 0x8cc70208, 0x1a6439ec,
-    0x90befffa, 0x23631e28, 0xa4506ceb, 0xde82bde9, 0xbef9a3f7, 0xb2c67915, 0xc67178f2, 0xe372532b,
-    0xca273ece, 0xea26619c, 0xd186b8c7, 0x21c0c207, 0xeada7dd6, 0xcde0eb1e, 0xf57d4f7f, 0xee6ed178,
-    0x06f067aa, 0x72176fba, 0x0a637dc5, 0xa2c898a6, 0x113f9804, 0xbef90dae, 0x1b710b35, 0x131c471b,
-    0x28db77f5, 0x23047d84, 0x32caab7b, 0x40c72493, 0x3c9ebe0a, 0x15c9bebc, 0x431d67c4, 0x9c100d4c,
-    0x4cc5d4be, 0xcb3e42b6, 0x597f299c, 0xfc657e2a, 0x5fcb6fab, 0x3ad6faec, 0x6c44198c, 0x4a475817,
-];
+// 32 bytes, not reduced.
+fn sc_unpack(n: &[u8]) -> Scalar {
+    let w = [sc_load(n, 0), sc_load(n, 8), sc_load(n, 16), sc_load(n, 24)];
+
+    [
+        w[0] & SC_MASK,
+        ((w[0] >> 52) | (w[1] << 12)) & SC_MASK,
+        ((w[1] >> 40) | (w[2] << 24)) & SC_MASK,
+        ((w[2] >> 28) | (w[3] << 36)) & SC_MASK,
+        w[3] >> 16,
+    ]
+}
 
-// optimized by miguel
-fn crypto_hashblocks_hl(hh: &mut Vec<u32>, hl: &mut Vec<u32>, m: &Vec<u32>, _n: usize) -> usize {
-    let mut wh: Vec<u32> = vec![0; 16];
-    let mut wl: Vec<u32> = vec![0; 16];
+fn sc_pack(o: &mut [u8], a: &Scalar) {
+    let mut acc: u128 = 0;
+    let mut bits = 0;
+    let mut j = 0;
+    for limb in a {
+        acc |= (*limb as u128) << bits;
+        bits += 52;
+        while bits >= 8 && j < 32 {
+            o[j] = acc as u8;
+            acc >>= 8;
+            bits -= 8;
+            j += 1;
+        }
+    }
+}
 
-    let mut bh: Vec<u32> = vec![0; 8];
-    let mut bl: Vec<u32> = vec![0; 8];
+// a - b, for a and b below L.
+fn sc_sub(a: &Scalar, b: &Scalar) -> Scalar {
+    let mut o = [0u64; 5];
+    let mut borrow: u64 = 0;
+    for i in 0..5 {
+        borrow = a[i].wrapping_sub(b[i] + (borrow >> 63));
+        o[i] = borrow & SC_MASK;
+    }
 
-    let mut th: u32;
-    let mut tl: u32;
-    let mut h: u32;
-    let mut l: u32;
-    let mut a: u32;
-    let mut b: u32;
-    let mut c: u32;
-    let mut d: u32;
+    // Add L back when the difference is negative.
+    let negative = Choice::from_bit((borrow >> 63) as u8);
+    let mut carry: u64 = 0;
+    for i in 0..5 {
+        let l = u64::conditional_select(&0, &L52[i], negative);
+        carry = (carry >> 52) + o[i] + l;
+        o[i] = carry & SC_MASK;
+    }
 
-    let mut ah: Vec<u32> = vec![0; 8];
-    let mut al: Vec<u32> = vec![0; 8];
+    o
+}
 
-    for i in 0..8 {
-        ah[i] = hh[i];
-        al[i] = hl[i];
+// a + b, for a and b below L.
+fn sc_add(a: &Scalar, b: &Scalar) -> Scalar {
+    let mut o = [0u64; 5];
+    let mut carry: u64 = 0;
+    for i in 0..5 {
+        carry = a[i] + b[i] + (carry >> 52);
+        o[i] = carry & SC_MASK;
     }
 
-    let mut pos = 0;
-    let mut n = _n;
-    while n >= 128 {
-        for i in 0..16 {
-            let j = 8 * i + pos;
-            wh[i] = (m[j + 0] << 24) | (m[j + 1] << 16) | (m[j + 2] << 8) | m[j + 3];
-            wl[i] = (m[j + 4] << 24) | (m[j + 5] << 16) | (m[j + 6] << 8) | m[j + 7];
+    sc_sub(&o, &L52)
+}
+
+fn sc_mul_wide(a: &Scalar, b: &Scalar) -> [u128; 9] {
+    let mut o = [0u128; 9];
+    for i in 0..5 {
+        for j in 0..5 {
+            o[i + j] += a[i] as u128 * b[j] as u128;
         }
+    }
 
-        for i in 0..80 {
-            for j in 0..7 {
-                bh[j] = ah[j];
-                bl[j] = al[j];
-            }
+    o
+}
 
-            // add
-            h = ah[7];
-            l = al[7];
-
-            a = l & 0xffff;
-            b = l as u32 >> 16;
-            c = h & 0xffff;
-            d = h as u32 >> 16;
-
-            // Sigma1
-            h = ((ah[4] as u32 >> 14) | (al[4] << (32 - 14)))
-                ^ ((ah[4] as u32 >> 18) | (al[4] << (32 - 18)))
-                ^ ((al[4] as u32 >> (41 - 32)) | (ah[4] << (32 - (41 - 32))));
-            l = ((al[4] as u32 >> 14) | (ah[4] << (32 - 14)))
-                ^ ((al[4] as u32 >> 18) | (ah[4] << (32 - 18)))
-                ^ ((ah[4] as u32 >> (41 - 32)) | (al[4] << (32 - (41 - 32))));
-
-            a += l & 0xffff;
-            b += l as u32 >> 16;
-            c += h & 0xffff;
-            d += h as u32 >> 16;
-
-            // Ch
-            h = (ah[4] & ah[5]) ^ (!ah[4] & ah[6]);
-            l = (al[4] & al[5]) ^ (!al[4] & al[6]);
-
-            a += l & 0xffff;
-            b += l as u32 >> 16;
-            c += h & 0xffff;
-            d += h as u32 >> 16;
-
-            // K
-            h = K[i * 2] as u32;
-            l = K[i * 2 + 1] as u32;
-
-            a += l & 0xffff;
-            b += l as u32 >> 16;
-            c += h & 0xffff;
-            d += h as u32 >> 16;
-
-            // w
-            h = wh[i % 16];
-            l = wl[i % 16];
-
-            a += l & 0xffff;
-            b += l as u32 >> 16;
-            c += h & 0xffff;
-            d += h as u32 >> 16;
-
-            b += a as u32 >> 16;
-            c += b as u32 >> 16;
-            d += c as u32 >> 16;
-
-            // *** R
-            th = c & 0xffff | (d << 16);
-            tl = a & 0xffff | (b << 16);
-
-            // add
-            h = th;
-            l = tl;
-
-            a = l & 0xffff;
-            b = l as u32 >> 16;
-            c = h & 0xffff;
-            d = h as u32 >> 16;
-
-            // Sigma0
-            h = ((ah[0] as u32 >> 28) | (al[0] << (32 - 28)))
-                ^ ((al[0] as u32 >> (34 - 32)) | (ah[0] << (32 - (34 - 32))))
-                ^ ((al[0] as u32 >> (39 - 32)) | (ah[0] << (32 - (39 - 32))));
-            l = ((al[0] as u32 >> 28) | (ah[0] << (32 - 28)))
-                ^ ((ah[0] as u32 >> (34 - 32)) | (al[0] << (32 - (34 - 32))))
-                ^ ((ah[0] as u32 >> (39 - 32)) | (al[0] << (32 - (39 - 32))));
-
-            a += l & 0xffff;
-            b += l as u32 >> 16;
-            c += h & 0xffff;
-            d += h as u32 >> 16;
-
-            // Maj
-            h = (ah[0] & ah[1]) ^ (ah[0] & ah[2]) ^ (ah[1] & ah[2]);
-            l = (al[0] & al[1]) ^ (al[0] & al[2]) ^ (al[1] & al[2]);
-
-            a += l & 0xffff;
-            b += l as u32 >> 16;
-            c += h & 0xffff;
-            d += h as u32 >> 16;
-
-            b += a as u32 >> 16;
-            c += b as u32 >> 16;
-            d += c as u32 >> 16;
-
-            bh[7] = (c & 0xffff) | (d << 16);
-            bl[7] = (a & 0xffff) | (b << 16);
-
-            // add
-            h = bh[3];
-            l = bl[3];
-
-            a = l & 0xffff;
-            b = l as u32 >> 16;
-            c = h & 0xffff;
-            d = h as u32 >> 16;
-
-            h = th;
-            l = tl;
-
-            a += l & 0xffff;
-            b += l as u32 >> 16;
-            c += h & 0xffff;
-            d += h as u32 >> 16;
-
-            b += a as u32 >> 16;
-            c += b as u32 >> 16;
-            d += c as u32 >> 16;
-
-            bh[3] = (c & 0xffff) | (d << 16);
-            bl[3] = (a & 0xffff) | (b << 16);
-
-            for j in 0..8 {
-                let k = (j + 1) % 8;
-                ah[k] = bh[j];
-                al[k] = bl[j];
-            }
+// x / R mod L, for x below R L.
+fn sc_montgomery_reduce(x: &[u128; 9]) -> Scalar {
+    let m = |a: u64, b: u64| a as u128 * b as u128;
+    let l = &L52;
+
+    // Adds a multiple of L clearing the low 52 bits.
+    let part1 = |sum: u128| {
+        let p = (sum as u64).wrapping_mul(LFACTOR) & SC_MASK;
+        ((sum + m(p, l[0])) >> 52, p)
+    };
+    let part2 = |sum: u128| ((sum >> 52), sum as u64 & SC_MASK);
+
+    let (c, n0) = part1(x[0]);
+    let (c, n1) = part1(c + x[1] + m(n0, l[1]));
+    let (c, n2) = part1(c + x[2] + m(n0, l[2]) + m(n1, l[1]));
+    let (c, n3) = part1(c + x[3] + m(n1, l[2]) + m(n2, l[1]));
+    let (c, n4) = part1(c + x[4] + m(n0, l[4]) + m(n2, l[2]) + m(n3, l[1]));
+
+    let (c, r0) = part2(c + x[5] + m(n1, l[4]) + m(n3, l[2]) + m(n4, l[1]));
+    let (c, r1) = part2(c + x[6] + m(n2, l[4]) + m(n4, l[2]));
+    let (c, r2) = part2(c + x[7] + m(n3, l[4]));
+    let (c, r3) = part2(c + x[8] + m(n4, l[4]));
+    let r4 = c as u64;
+
+    // The result is below 2L.
+    sc_sub(&[r0, r1, r2, r3, r4], &L52)
+}
 
-            if i % 16 == 15 {
-                for j in 0..16 {
-                    // add
-                    h = wh[j];
-                    l = wl[j];
-
-                    a = l & 0xffff;
-                    b = l as u32 >> 16;
-                    c = h & 0xffff;
-                    d = h as u32 >> 16;
-
-                    h = wh[(j + 9) % 16];
-                    l = wl[(j + 9) % 16];
-
-                    a += l & 0xffff;
-                    b += l as u32 >> 16;
-                    c += h & 0xffff;
-                    d += h as u32 >> 16;
-
-                    // sigma0
-                    th = wh[(j + 1) % 16];
-                    tl = wl[(j + 1) % 16];
-
-                    h = ((th as u32 >> 1) | (tl << (32 - 1)))
-                        ^ ((th as u32 >> 8) | (tl << (32 - 8)))
-                        ^ (th as u32 >> 7);
-                    l = ((tl as u32 >> 1) | (th << (32 - 1)))
-                        ^ ((tl as u32 >> 8) | (th << (32 - 8)))
-                        ^ ((tl as u32 >> 7) | (th << (32 - 7)));
-
-                    a += l & 0xffff;
-                    b += l as u32 >> 16;
-                    c += h & 0xffff;
-                    d += h as u32 >> 16;
-
-                    // sigma1
-                    th = wh[(j + 14) % 16];
-                    tl = wl[(j + 14) % 16];
-
-                    h = ((th as u32 >> 19) | (tl << (32 - 19)))
-                        ^ ((tl as u32 >> (61 - 32)) | (th << (32 - (61 - 32))))
-                        ^ (th as u32 >> 6);
-                    l = ((tl as u32 >> 19) | (th << (32 - 19)))
-                        ^ ((th as u32 >> (61 - 32)) | (tl << (32 - (61 - 32))))
-                        ^ ((tl as u32 >> 6) | (th << (32 - 6)));
-
-                    a += l & 0xffff;
-                    b += l as u32 >> 16;
-                    c += h & 0xffff;
-                    d += h as u32 >> 16;
-
-                    b += a as u32 >> 16;
-                    c += b as u32 >> 16;
-                    d += c as u32 >> 16;
-
-                    wh[j] = (c & 0xffff) | (d << 16);
-                    wl[j] = (a & 0xffff) | (b << 16);
-                }
-            }
-        }
+// a b mod L
+fn sc_mul(a: &Scalar, b: &Scalar) -> Scalar {
+    let ab = sc_montgomery_reduce(&sc_mul_wide(a, b));
 
-        // add
-        a = 0;
-        b = 0;
-        c = 0;
-        d = 0;
-        for k in 0..8 {
-            if k == 0 {
-                h = ah[0];
-                l = al[0];
-                a = l & 0xffff;
-                b = l as u32 >> 16;
-                c = h & 0xffff;
-                d = h as u32 >> 16;
-            }
+    sc_montgomery_reduce(&sc_mul_wide(&ab, &RR52))
+}
 
-            h = hh[k];
-            l = hl[k];
+// Reduces the 64 bytes of r modulo L into its first 32 bytes, zeroing the rest.
+fn reduce(r: &mut [u8]) {
+    let mut w = [0u64; 8];
+    for i in 0..8 {
+        w[i] = sc_load(r, 8 * i);
+    }
+
+    // lo + hi R, splitting r at bit 260.
+    let lo = [
+        w[0] & SC_MASK,
+        ((w[0] >> 52) | (w[1] << 12)) & SC_MASK,
+        ((w[1] >> 40) | (w[2] << 24)) & SC_MASK,
+        ((w[2] >> 28) | (w[3] << 36)) & SC_MASK,
+        ((w[3] >> 16) | (w[4] << 48)) & SC_MASK,
+    ];
+    let hi = [
+        (w[4] >> 4) & SC_MASK,
+        ((w[4] >> 56) | (w[5] << 8)) & SC_MASK,
+        ((w[5] >> 44) | (w[6] << 20)) & SC_MASK,
+        ((w[6] >> 32) | (w[7] << 32)) & SC_MASK,
+        w[7] >> 20,
+    ];
+    let lo = sc_montgomery_reduce(&sc_mul_wide(&lo, &R52));
+    let hi = sc_montgomery_reduce(&sc_mul_wide(&hi, &RR52));
 
-            a += l & 0xffff;
-            b += l as u32 >> 16;
-            c += h & 0xffff;
-            d += h as u32 >> 16;
+    for i in 0..64 {
+        r[i] = 0;
+    }
+    sc_pack(r, &sc_add(&lo, &hi));
+}
 
-            b += a as u32 >> 16;
-            c += b as u32 >> 16;
-            d += c as u32 >> 16;
+// r = -a mod L
+fn sc_neg(r: &mut [u8], a: &[u8]) {
+    let a = sc_montgomery_reduce(&sc_mul_wide(&sc_unpack(a), &R52));
 
-            hh[k] = (c & 0xffff) | (d << 16);
-            ah[k] = (c & 0xffff) | (d << 16);
+    sc_pack(r, &sc_sub(&[0; 5], &a));
+}
 
-            hl[k] = (a & 0xffff) | (b << 16);
-            al[k] = (a & 0xffff) | (b << 16);
+// s = r + h * a mod L, for r below L.
+fn sc_muladd(s: &mut [u8], h: &[u8], a: &[u8], r: &[u8]) {
+    let ha = sc_mul(&sc_unpack(h), &sc_unpack(a));
 
-            if k < 7 {
-                h = ah[k + 1];
-                l = al[k + 1];
+    sc_pack(s, &sc_add(&ha, &sc_unpack(r)));
+}
 
-                a = l & 0xffff;
-                b = l as u32 >> 16;
-                c = h & 0xffff;
-                d = h as u32 >> 16;
-            }
-        }
+// Signs m with the Ed25519 secret key sk, the scalar then the public key. The nonce is
+// SHA-512(sk[..32] || m) for an empty rnd, else SHA-512(0xfe 0xff^31 || sk[..32] || m || rnd).
+fn crypto_sign_detached(sig: &mut [u8], m: &[u8], sk: &[u8], rnd: &[u8]) {
+    let mut h = [0u8; 64];
+    let mut r = [0u8; 64];
+    let mut p = IDENTITY;
+
+    let mut hasher = Sha512::new();
+    if !rnd.is_empty() {
+        // Hash separation.
+        let mut prefix = [0xffu8; 32];
+        prefix[0] = 0xfe;
+        hasher.update(&prefix);
+    }
+    hasher.update(&sk[..32]);
+    hasher.update(m);
+    if !rnd.is_empty() {
+        // Random suffix.
+        hasher.update(&rnd[..64]);
+    }
+    r.copy_from_slice(&hasher.finalize());
+    reduce(&mut r);
 
-        pos += 128;
-        n -= 128;
-    }
+    scalarbase(&mut p, &r);
+    pack(sig, &p);
+
+    // h = SHA-512(R || A || M)
+    let mut hasher = Sha512::new();
+    hasher.update(&sig[..32]);
+    hasher.update(&sk[32..64]);
+    hasher.update(m);
+    h.copy_from_slice(&hasher.finalize());
+    reduce(&mut h);
 
-    n
+    sc_muladd(&mut sig[32..], &h, sk, &r);
+
+    // Wipe out nonce.
+    r.zeroize();
+    h.zeroize();
 }
 
-fn crypto_hash(out: &mut Vec<u32>, m: &Vec<u32>, _n: usize) -> usize {
-    let mut hh: Vec<u32> = ([
-        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
-        0x5be0cd19,
-    ])
-    .to_vec();
-    let mut hl: Vec<u32> = ([
-        0xf3bcc908, 0x84caa73b, 0xfe94f82b, 0x5f1d36f1, 0xade682d1, 0x2b3e6c1f, 0xfb41bd6b,
-        0x137e2179,
-    ])
-    .to_vec();
-    let mut x: Vec<u32> = vec![0; 256];
-    let mut n = _n;
-    let b = n;
+// Note: sig must be 64 bytes, rnd empty or 64 bytes.
+pub fn curve25519_sign_detached(sig: &mut [u8], m: &[u8], sk: &[u8], rnd: &[u8]) {
+    // Convert Curve25519 secret key into Ed25519 secret key (includes pub key).
+    let mut edsk = [0u8; 64];
+    let mut p = IDENTITY;
 
-    crypto_hashblocks_hl(&mut hh, &mut hl, &m, n);
+    edsk[..32].copy_from_slice(&sk[..32]);
 
-    n %= 128;
+    // Ensure private key is in the correct format.
+    edsk[0] &= 248;
+    edsk[31] &= 127;
+    edsk[31] |= 64;
 
-    for i in 0..n {
-        x[i] = m[b - n + i];
-    }
-    x[n] = 128;
+    scalarbase(&mut p, &edsk);
 
-    if n < 112 {
-        n = 256 - 128 * 1;
-    } else {
-        n = 256 - 128 * 0;
-    }
-    x[n - 9] = 0;
+    pack(&mut edsk[32..], &p);
 
-    ts64(
-        &mut x,
-        n - 8,
-        ((b / 0x20000000) | 0) as u32,
-        (b << 3) as u32,
-    );
+    // Remember sign bit.
+    let sign_bit = edsk[63] & 128;
+    crypto_sign_detached(sig, m, &edsk, rnd);
 
-    crypto_hashblocks_hl(&mut hh, &mut hl, &x, n);
+    // Copy sign bit from public key into signature.
+    sig[63] |= sign_bit;
 
-    for i in 0..8 {
-        ts64(out, 8 * i, hh[i], hl[i]);
-    }
+    // Wipe out Ed25519 secret key.
+    edsk.zeroize();
+}
 
-    0
+// Note: sm must have n + 64 bytes, rnd empty or 64 bytes.
+pub fn curve25519_sign(sm: &mut [u8], m: &[u8], sk: &[u8], opt_rnd: &[u8]) -> usize {
+    let n = m.len();
+
+    curve25519_sign_detached(&mut sm[..64], m, sk, opt_rnd);
+    sm[64..64 + n].copy_from_slice(m);
+
+    n + 64
 }
 
-fn add(p: &mut Vec<Vec<i64>>, q: &mut Vec<Vec<i64>>) {
-    let mut a = gf();
-    let mut b = gf();
-    let mut c = gf();
-    let mut d = gf();
-    let mut e = gf();
-    let mut f = gf();
-    let mut g = gf();
-    let mut h = gf();
-    let mut t = gf();
-
-    Z(&mut a, &p[1], &p[0]);
-    Z(&mut t, &q[1], &q[0]);
-    let aa = a.clone();
-    M(&mut a, &aa, &t);
-    A(&mut b, &p[0], &p[1]);
-    A(&mut t, &q[0], &q[1]);
-    let bb = b.clone();
-    M(&mut b, &bb, &t);
-    M(&mut c, &p[3], &q[3]);
-    let cc = c.clone();
-    M(&mut c, &cc, &D2.to_vec());
-    M(&mut d, &p[2], &q[2]);
-    let dd = d.clone();
-    A(&mut d, &dd, &dd);
-    Z(&mut e, &b, &a);
-    Z(&mut f, &d, &c);
-    A(&mut g, &d, &c);
-    A(&mut h, &b, &a);
-
-    M(&mut p[0], &e, &f);
-    M(&mut p[1], &h, &g);
-    M(&mut p[2], &g, &f);
-    M(&mut p[3], &e, &h);
-}
-
-fn cswap(p: &mut Vec<Vec<i64>>, q: &mut Vec<Vec<i64>>, b: isize) {
-    for i in 0..4 {
-        sel25519(&mut p[i], &mut q[i], b)
-    }
+// Unpacks the negation of p, true when p is on the curve.
+fn unpackneg(r: &mut Point, p: &[u8]) -> Choice {
+    unpackneg_yz(r, &unpack25519(p), &gf1, p[31] >> 7)
 }
 
-fn pack(r: &mut Vec<u32>, p: &Vec<Vec<i64>>) {
-    let mut tx = gf();
-    let mut ty = gf();
-    let mut zi = gf();
+// Same as unpackneg for y = Y/Z and the sign bit of x, without inverting Z.
+fn unpackneg_yz(r: &mut Point, y: &Gf, z: &Gf, sign: u8) -> Choice {
+    r.z = *z;
+    r.y = *y;
 
-    inv25519(&mut zi, &p[2]);
+    // x^2 = (y^2 - 1) / (dy^2 + 1) = (Y^2 - Z^2) / (dY^2 + Z^2)
+    let z2 = S(z);
+    let mut num = S(&r.y);
+    let mut den = M(&num, &D);
+    num = Z(&num, &z2);
+    den = A(&z2, &den);
 
-    M(&mut tx, &p[0], &zi);
-    M(&mut ty, &p[1], &zi);
+    let den2 = S(&den);
+    let den4 = S(&den2);
+    let den6 = M(&den4, &den2);
+    let mut t = M(&den6, &num);
+    t = M(&t, &den);
 
-    pack25519(r, &ty);
+    t = pow2523(&t);
+    t = M(&t, &num);
+    t = M(&t, &den);
+    t = M(&t, &den);
+    r.x = M(&t, &den);
+
+    let chk = M(&S(&r.x), &den);
+    let rx = M(&r.x, &I);
+    r.x.conditional_assign(&rx, !eq25519(&chk, &num));
+
+    let chk = M(&S(&r.x), &den);
+    let valid = eq25519(&chk, &num);
 
-    r[31] = r[31] ^ (par25519(&tx) << 7)
+    let nx = Z(&gf0, &r.x);
+    r.x.conditional_assign(&nx, par25519(&r.x).ct_eq(&sign));
+
+    r.t = M(&r.x, &r.y);
+    r.x = M(&r.x, &r.z);
+
+    valid
 }
 
-fn scalarmult(p: &mut Vec<Vec<i64>>, q: &mut Vec<Vec<i64>>, s: &Vec<u32>) {
-    let mut b: u32;
+// The encoding of sB - hA must be R, without the cofactor as the Lunes node checks it.
+// The hash streams R || A || M.
+fn crypto_verify_detached(m: &[u8], sig: &[u8], pk: &[u8]) -> Result<(), Error> {
+    let mut t = [0u8; 32];
+    let mut h = [0u8; 64];
+    let mut s = [0u8; 32];
+    let mut p = IDENTITY;
+    let mut q = IDENTITY;
 
-    set25519(&mut p[0], &gf0.to_vec());
-    set25519(&mut p[1], &gf1.to_vec());
-    set25519(&mut p[2], &gf1.to_vec());
-    set25519(&mut p[3], &gf0.to_vec());
+    if !bool::from(unpackneg(&mut q, pk)) {
+        return Err(Error::InvalidPublicKey);
+    }
 
-    for i in (0..=255).rev() {
-        b = (s[(i / 8) | 0] >> (i & 7)) & 1;
-        cswap(p, q, b as isize);
-        add(q, p);
-        let mut pp = p.clone();
-        add(p, &mut pp);
-        cswap(p, q, b as isize);
+    let mut hasher = Sha512::new();
+    hasher.update(&sig[..32]);
+    hasher.update(&pk[..32]);
+    hasher.update(m);
+    h.copy_from_slice(&hasher.finalize());
+    reduce(&mut h);
+
+    // sB - hA, in variable time as every input is public.
+    let mut k = [0u8; 32];
+    k.copy_from_slice(&h[..32]);
+    s.copy_from_slice(&sig[32..64]);
+    multiscalarmult(&mut p, &[term(&k, &q), term(&s, &BASE)]);
+
+    pack(&mut t, &p);
+    if !bool::from(crypto_verify_32(sig, 0, &t, 0)) {
+        return Err(Error::InvalidSignature);
     }
+
+    Ok(())
 }
 
-fn scalarbase(p: &mut Vec<Vec<i64>>, s: &Vec<u32>) {
-    let mut q: Vec<Vec<i64>> = ([gf(), gf(), gf(), gf()]).to_vec();
-    set25519(&mut q[0], &X.to_vec());
-    set25519(&mut q[1], &Y.to_vec());
-    set25519(&mut q[2], &gf1.to_vec());
-    M(&mut q[3], &X.to_vec(), &Y.to_vec());
-    scalarmult(p, &mut q, s);
+// Converts Curve25519 public key back to Ed25519 public key.
+// edwardsY = (montgomeryX - 1) / (montgomeryX + 1)
+fn convert_public_key(pk: &[u8]) -> [u8; 32] {
+    let mut z = [0u8; 32];
+
+    pack25519(&mut z, &mont_to_edy(&unpack25519(pk)));
+
+    z
 }
 
-const L: [i32; 32] = [
-    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
-];
+fn mont_to_edy(u: &Gf) -> Gf {
+    M(&inv25519(&A(u, &gf1)), &Z(u, &gf1))
+}
 
-fn mod_l(r: &mut Vec<u32>, x: &mut Vec<i32>) {
-    let mut carry: i32;
-
-    for i in (32..=63).rev() {
-        carry = 0;
-        let mut j = i - 32;
-        let k = i - 12;
-        while j < k {
-            x[j] += carry - 16 * x[i] * L[j - (i - 32)];
-            carry = (x[j] + 128) >> 8;
-            x[j] -= carry * 256;
-            j += 1;
-        }
-        x[j] += carry;
-        x[i] = 0;
+// Note: m must have sm.len() - 64 bytes.
+pub fn curve25519_sign_open(m: &mut [u8], sm: &[u8], pk: &[u8]) -> Result<usize, Error> {
+    if sm.len() < 64 {
+        return Err(Error::InvalidLength {
+            expected: 64,
+            actual: sm.len(),
+        });
     }
-
-    carry = 0;
-    for j in 0..32 {
-        x[j] += carry - (x[31] >> 4) * L[j];
-        carry = x[j] >> 8;
-        x[j] = x[j] & 255;
+    let n = sm.len() - 64;
+    if m.len() < n {
+        return Err(Error::InvalidLength {
+            expected: n,
+            actual: m.len(),
+        });
     }
 
-    for j in 0..32 {
-        x[j] -= carry * L[j];
-    }
+    curve25519_verify(&sm[64..], &sm[..64], pk)?;
+    m[..n].copy_from_slice(&sm[64..]);
 
-    for i in 0..32 {
-        x[i + 1] += x[i] >> 8;
-        r[i] = (x[i] & 255) as u32;
-    }
+    Ok(n)
 }
 
-fn reduce(r: &mut Vec<u32>) {
-    let mut x: Vec<i32> = vec![0; 64];
-    for i in 0..64 {
-        x[i] = r[i] as i32;
+pub fn curve25519_verify(m: &[u8], sig: &[u8], pk: &[u8]) -> Result<(), Error> {
+    if sig.len() != 64 {
+        return Err(Error::InvalidLength {
+            expected: 64,
+            actual: sig.len(),
+        });
     }
-    for i in 0..64 {
-        r[i] = 0;
+    if pk.len() != 32 {
+        return Err(Error::InvalidLength {
+            expected: 32,
+            actual: pk.len(),
+        });
     }
-    mod_l(r, &mut x);
+
+    // Convert Curve25519 public key into Ed25519 public key.
+    let mut edpk = convert_public_key(pk);
+
+    // Restore sign bit from signature.
+    edpk[31] |= sig[63] & 128;
+
+    // Remove sign bit from signature.
+    let mut rs = [0u8; 64];
+    rs.copy_from_slice(sig);
+    rs[63] &= 127;
+
+    crypto_verify_detached(m, &rs, &edpk)
 }
 
-// Like crypto_sign, but uses secret key directly in hash.
-fn crypto_sign_direct(sm: &mut Vec<u32>, m: &Vec<u32>, n: usize, sk: &Vec<u32>) -> usize {
-    let mut h: Vec<u32> = vec![0; 64];
-    let mut r: Vec<u32> = vec![0; 64];
-    let mut x: Vec<i32> = vec![0; 64];
-    let mut p: Vec<Vec<i64>> = ([gf(), gf(), gf(), gf()]).to_vec();
+pub fn shared_key(secret_key: &[u8], public_key: &[u8]) -> [u8; 32] {
+    let mut shared_key = [0u8; 32];
+    crypto_scalarmult(&mut shared_key, secret_key, public_key);
 
-    for i in 0..n {
-        sm[64 + i] = m[i];
-    }
+    shared_key
+}
 
-    for i in 0..32 {
-        sm[32 + i] = sk[i];
-    }
+// Same as crypto_scalarmult(q, n, _9), with the Edwards basepoint table.
+// montgomeryX = (1 + edwardsY) / (1 - edwardsY)
+pub fn crypto_scalarmult_base(q: &mut [u8], n: &[u8]) -> usize {
+    let mut z = [0u8; 32];
+    let mut p = IDENTITY;
 
-    let mut x32: Vec<u32> = (&sm[32..]).to_vec();
+    z[..31].copy_from_slice(&n[..31]);
+    z[31] = (n[31] & 127) | 64;
+    z[0] &= 248;
 
-    crypto_hash(&mut r, &x32, n + 32);
+    scalarbase(&mut p, &z);
+    pack25519(q, &M(&A(&p.z, &p.y), &inv25519(&Z(&p.z, &p.y))));
 
-    reduce(&mut r);
+    z.zeroize();
 
-    scalarbase(&mut p, &r);
+    0
+}
 
-    pack(sm, &p);
+// XEdDSA and VXEdDSA signatures by Trevor Perrin.
+// https://signal.org/docs/specifications/xeddsa/
 
-    for i in 0..32 {
-        sm[i + 32] = sk[32 + i];
+// hash_i(X) = SHA-512(2^256 - 1 - i || X), for X the concatenation of the parts.
+fn hash_i(out: &mut [u8], i: u8, x: &[&[u8]]) {
+    let mut prefix = [0xffu8; 32];
+    prefix[0] = 0xff - i;
+
+    let mut hasher = Sha512::new();
+    hasher.update(&prefix);
+    for part in x {
+        hasher.update(part);
     }
+    out[..64].copy_from_slice(&hasher.finalize());
+}
 
-    crypto_hash(&mut h, &sm, n + 64);
-    reduce(&mut h);
+// Like unpackneg, but keeps the sign of the encoding.
+fn unpack(r: &mut Point, p: &[u8]) -> Choice {
+    let mut q = [0u8; 32];
+    q.copy_from_slice(&p[..32]);
+    q[31] ^= 128;
 
-    for i in 0..64 {
-        x[i] = 0;
-    }
+    unpackneg(r, &q)
+}
 
-    for i in 0..32 {
-        x[i] = r[i] as i32;
+fn mul_cofactor(p: &mut Point) {
+    for _ in 0..3 {
+        double(p);
     }
+}
 
-    for i in 0..32 {
-        for j in 0..32 {
-            x[i + j] += (h[i] * sk[j]) as i32;
-        }
-    }
+// X = 0 and Y = Z, without inverting Z.
+fn is_identity(p: &Point) -> bool {
+    bool::from(eq25519(&p.x, &gf0) & eq25519(&p.y, &p.z))
+}
 
-    let mut tmp: Vec<u32> = (&sm[32..]).to_vec();
-    mod_l(&mut tmp, &mut x);
-    for i in 0..tmp.len() {
-        sm[32 + i] = tmp[i];
+// Little endian x must be below p - d.
+fn is_below_p(x: &[u8], d: u8) -> bool {
+    for i in (0..32).rev() {
+        let max = match i {
+            31 => 0x7f,
+            0 => 0xed - d,
+            _ => 0xff,
+        };
+        if x[i] != max {
+            return x[i] < max;
+        }
     }
+    false
+}
 
-    n + 64
+// Montgomery u must be below p - 1, which has no Edwards counterpart.
+fn is_canonical_u(u: &[u8]) -> bool {
+    is_below_p(u, 1)
 }
 
-// Note: sm must be n+128.
-fn crypto_sign_direct_rnd(
-    sm: &mut Vec<u32>,
-    m: &Vec<u32>,
-    n: usize,
-    sk: &Vec<u32>,
-    rnd: &Vec<u32>,
-) -> usize {
-    let mut h: Vec<u32> = vec![0; 64];
-    let mut r: Vec<u32> = vec![0; 64];
-    let mut x: Vec<i32> = vec![0; 64];
-    let mut p: Vec<Vec<i64>> = ([gf(), gf(), gf(), gf()]).to_vec();
+// Edwards y, without the sign bit, must be below p.
+fn is_canonical_y(y: &[u8]) -> bool {
+    let mut u = [0u8; 32];
+    u.copy_from_slice(&y[..32]);
+    u[31] &= 127;
 
-    // Hash separation.
-    sm[0] = 0xfe;
-    for i in 1..32 {
-        sm[i] = 0xff;
-    }
+    is_below_p(&u, 0)
+}
 
-    // Secret key.
-    for i in 0..32 {
-        sm[32 + i] = sk[i];
+// Scalar must be below L.
+fn is_canonical_scalar(s: &[u8]) -> bool {
+    for i in (0..32).rev() {
+        if s[i] != L[i] {
+            return s[i] < L[i];
+        }
     }
+    false
+}
 
-    // Message.
-    for i in 0..n {
-        sm[64 + i] = m[i];
-    }
+// i^((p - 1) / 2), which is -1 when i is not a square.
+fn chi25519(i: &Gf) -> Gf {
+    M(&Sn(&pow2523(i), 2), &S(i))
+}
 
-    // Random suffix.
-    for i in 0..64 {
-        sm[n + 64 + i] = rnd[i];
-    }
+// u = -A / (1 + 2r^2), or -A - u when that is on the twist.
+fn elligator2(r: &Gf) -> Gf {
+    let mut t = S(r);
+    t = A(&t, &t);
+    t = A(&t, &gf1);
+    let mut u = Z(&gf0, &M(&inv25519(&t), &_486662));
 
-    crypto_hash(&mut r, &sm, n + 128);
-    reduce(&mut r);
-    scalarbase(&mut p, &r);
-    pack(sm, &p);
+    // w = u (u^2 + A u + 1)
+    t = A(&S(&u), &M(&_486662, &u));
+    t = A(&t, &gf1);
+    let w = M(&t, &u);
 
-    for i in 0..32 {
-        sm[i + 32] = sk[32 + i];
-    }
+    let v = Z(&Z(&gf0, &_486662), &u);
 
-    crypto_hash(&mut h, &sm, n + 64);
-    reduce(&mut h);
+    let chi = chi25519(&w);
+    let minus_one = Z(&gf0, &gf1);
+    u.conditional_assign(&v, eq25519(&chi, &minus_one));
 
-    // Wipe out random suffix.
-    for i in 0..64 {
-        sm[n + 64 + i] = 0;
-    }
+    u
+}
 
-    for i in 0..64 {
-        x[i] = 0;
-    }
+fn hash_to_point(p: &mut Point, x: &[&[u8]]) {
+    let mut h = [0u8; 64];
+    let mut e = [0u8; 32];
 
-    for i in 0..32 {
-        x[i] = r[i] as i32;
-    }
+    hash_i(&mut h, 2, x);
+    let u = elligator2(&unpack25519(&h));
+    pack25519(&mut e, &mont_to_edy(&u));
+    e[31] |= h[31] & 128;
 
-    for i in 0..32 {
-        for j in 0..32 {
-            x[i + j] += (h[i] * sk[j]) as i32;
-        }
-    }
+    unpack(p, &e);
+    mul_cofactor(p);
+}
 
-    let mut tmp: Vec<u32> = (&sm[32..]).to_vec();
-    mod_l(&mut tmp, &mut x);
-    for i in 0..tmp.len() {
-        sm[32 + i] = tmp[i];
-    }
+// Edwards public key A with sign bit zero and private scalar a with A = aB.
+fn calculate_key_pair(A: &mut [u8], a: &mut [u8], sk: &[u8]) {
+    let mut p = IDENTITY;
+    let mut neg = [0u8; 32];
 
-    n + 64
-}
+    a[..32].copy_from_slice(&sk[..32]);
 
-pub fn curve25519_sign(sm: &mut Vec<u32>, m: Vec<u32>, sk: Vec<u32>, opt_rnd: Vec<u32>) -> usize {
-    // If opt_rnd is provided, sm must have n + 128,
-    // otherwise it must have n + 64 bytes.
+    // Ensure private key is in the correct format.
+    a[0] &= 248;
+    a[31] &= 127;
+    a[31] |= 64;
 
-    // Convert Curve25519 secret key into Ed25519 secret key (includes pub key).
-    let mut edsk: Vec<u32> = vec![0; 64];
-    let mut p: Vec<Vec<i64>> = ([gf(), gf(), gf(), gf()]).to_vec();
+    scalarbase(&mut p, a);
+    pack(A, &p);
 
+    // Negate the private key when the sign bit is set, without branching.
+    sc_neg(&mut neg, a);
+    let negative = Choice::from_bit(A[31] >> 7);
     for i in 0..32 {
-        edsk[i] = sk[i];
+        a[i].conditional_assign(&neg[i], negative);
     }
+    neg.zeroize();
 
-    // Ensure private key is in the correct format.
-    edsk[0] = edsk[0] & 248;
-    edsk[31] = edsk[31] & 127;
-    edsk[31] = edsk[31] | 64;
+    A[31] &= 127;
+}
 
-    scalarbase(&mut p, &edsk);
+// Note: sig must be 64 bytes and rnd 64 bytes.
+pub fn xeddsa_sign(sig: &mut [u8], m: &[u8], sk: &[u8], rnd: &[u8]) {
+    let mut A = [0u8; 32];
+    let mut a = [0u8; 32];
+    let mut r = [0u8; 64];
+    let mut h = [0u8; 64];
+    let mut p = IDENTITY;
 
-    let mut tmp: Vec<u32> = (&edsk[32..]).to_vec();
-    pack(&mut tmp, &p);
-    for i in 0..tmp.len() {
-        edsk[32 + i] = tmp[i];
-    }
+    calculate_key_pair(&mut A, &mut a, sk);
 
-    // Remember sign bit.
-    let sign_bit = edsk[63] & 128;
-    let mut smlen: usize;
+    // r = hash1(a || M || Z)
+    hash_i(&mut r, 1, &[&a, m, &rnd[..64]]);
+    reduce(&mut r);
+
+    scalarbase(&mut p, &r);
+    pack(sig, &p);
+
+    // h = hash(R || A || M)
+    let mut hasher = Sha512::new();
+    hasher.update(&sig[..32]);
+    hasher.update(&A);
+    hasher.update(m);
+    h.copy_from_slice(&hasher.finalize());
+    reduce(&mut h);
 
-    if opt_rnd.len() > 0 {
-        smlen = crypto_sign_direct_rnd(sm, &m, m.len(), &edsk, &opt_rnd);
-    } else {
-        smlen = crypto_sign_direct(sm, &m, m.len(), &edsk);
+    sc_muladd(&mut sig[32..], &h, &a, &r);
+
+    // Wipe out private scalar and nonce.
+    a.zeroize();
+    r.zeroize();
+}
+
+pub fn xeddsa_verify(m: &[u8], sig: &[u8], pk: &[u8]) -> Result<(), Error> {
+    if !is_canonical_u(pk) {
+        return Err(Error::InvalidPublicKey);
     }
 
-    // Copy sign bit from public key into signature.
-    sm[63] = sm[63] | sign_bit;
+    // s must be below 2^253, the top bit may carry the sign of A.
+    if sig[63] & 0x60 != 0 {
+        return Err(Error::InvalidSignature);
+    }
 
-    smlen
+    // Byte comparison of R, as the specification and libsignal do.
+    curve25519_verify(m, sig, pk)
 }
 
-fn unpackneg(r: &mut Vec<Vec<i64>>, p: &Vec<u32>) -> isize {
-    let mut t = gf();
-    let mut chk = gf();
-    let mut num = gf();
-    let mut den = gf();
-    let mut den2 = gf();
-    let mut den4 = gf();
-    let mut den6 = gf();
+// Note: sig must be 96 bytes, v 32 bytes and rnd 64 bytes.
+pub fn vxeddsa_sign(sig: &mut [u8], v: &mut [u8], m: &[u8], sk: &[u8], rnd: &[u8]) {
+    let mut A = [0u8; 32];
+    let mut a = [0u8; 32];
+    let mut r = [0u8; 64];
+    let mut h = [0u8; 64];
+    let mut cv = [0u8; 64];
+    let mut R = [0u8; 32];
+    let mut Rv = [0u8; 32];
+    let mut p = IDENTITY;
+    let mut bv = IDENTITY;
+
+    calculate_key_pair(&mut A, &mut a, sk);
+
+    // V = a Bv
+    hash_to_point(&mut bv, &[&A, m]);
+    scalarmult(&mut p, &bv, &a);
+    pack(sig, &p);
+
+    // r = hash3(a || V || Z)
+    hash_i(&mut r, 3, &[&a, &sig[..32], &rnd[..64]]);
+    reduce(&mut r);
 
-    set25519(&mut r[2], &gf1.to_vec());
-    unpack25519(&mut r[1], &p);
+    scalarbase(&mut p, &r);
+    pack(&mut R, &p);
+    scalarmult(&mut p, &bv, &r);
+    pack(&mut Rv, &p);
 
-    S(&mut num, &r[1]);
-    M(&mut den, &num, &D.to_vec());
-    let _num = num.clone();
-    Z(&mut num, &_num, &r[2]);
-    let _den = den.clone();
-    A(&mut den, &r[2], &_den);
+    // h = hash4(A || V || R || Rv || M)
+    hash_i(&mut h, 4, &[&A, &sig[..32], &R, &Rv, m]);
+    reduce(&mut h);
+    sig[32..64].copy_from_slice(&h[..32]);
 
-    S(&mut den2, &den);
-    S(&mut den4, &den2);
-    M(&mut den6, &den4, &den2);
-    M(&mut t, &den6, &num);
-    let _t = t.clone();
-    M(&mut t, &_t, &den);
+    sc_muladd(&mut sig[64..], &h, &a, &r);
 
-    let _t = t.clone();
-    pow2523(&mut t, &_t);
-    let _t = t.clone();
-    M(&mut t, &_t, &num);
-    let _t = t.clone();
-    M(&mut t, &_t, &den);
-    let _t = t.clone();
-    M(&mut t, &_t, &den);
-    M(&mut r[0], &t, &den);
+    // v = hash5(cV)
+    unpack(&mut p, sig);
+    mul_cofactor(&mut p);
+    let mut c = [0u8; 32];
+    pack(&mut c, &p);
+    hash_i(&mut cv, 5, &[&c]);
+    v[..32].copy_from_slice(&cv[..32]);
+
+    // Wipe out private scalar and nonce.
+    a.zeroize();
+    r.zeroize();
+}
 
-    S(&mut chk, &r[0]);
-    let _chk = chk.clone();
-    M(&mut chk, &_chk, &den);
+pub fn vxeddsa_verify(v: &mut [u8], m: &[u8], sig: &[u8], pk: &[u8]) -> Result<(), Error> {
+    let mut h = [0u8; 64];
+    let mut cv = [0u8; 64];
+    let mut c = [0u8; 32];
+    let mut R = [0u8; 32];
+    let mut Rv = [0u8; 32];
+    let mut p = IDENTITY;
+    let mut na = IDENTITY;
+    let mut nv = IDENTITY;
+    let mut bv = IDENTITY;
+
+    if !is_canonical_u(pk) {
+        return Err(Error::InvalidPublicKey);
+    }
+
+    // h and s must be below 2^253.
+    if sig[63] & 0xe0 != 0 || sig[95] & 0xe0 != 0 {
+        return Err(Error::InvalidSignature);
+    }
 
-    if neq25519(&chk, &num) != 0 {
-        let _r0 = r[0].clone();
-        M(&mut r[0], &_r0, &I.to_vec());
+    let A = convert_public_key(pk);
+    if !bool::from(unpackneg(&mut na, &A)) {
+        return Err(Error::InvalidPublicKey);
+    }
+    if !bool::from(unpackneg(&mut nv, sig)) {
+        return Err(Error::InvalidSignature);
     }
 
-    S(&mut chk, &r[0]);
-    let _chk = chk.clone();
-    M(&mut chk, &_chk, &den);
+    hash_to_point(&mut bv, &[&A, m]);
 
-    if neq25519(&chk, &num) != 0 {
-        return -1;
+    // Low order A or V would make the proof meaningless.
+    p = na;
+    mul_cofactor(&mut p);
+    if is_identity(&p) {
+        return Err(Error::LowOrderPoint);
+    }
+    p = nv;
+    mul_cofactor(&mut p);
+    if is_identity(&p) || is_identity(&bv) {
+        return Err(Error::InvalidSignature);
     }
 
-    if par25519(&r[0]) == (p[31] >> 7) {
-        let _r0 = r[0].clone();
-        Z(&mut r[0], &gf0.to_vec(), &_r0);
+    let (hs, s) = (&sig[32..64], &sig[64..96]);
+
+    // R = sB - hA
+    multiscalarmult(&mut p, &[term(hs, &na), term(s, &BASE)]);
+    pack(&mut R, &p);
+
+    // Rv = sBv - hV
+    multiscalarmult(&mut p, &[term(hs, &nv), term(s, &bv)]);
+    pack(&mut Rv, &p);
+
+    hash_i(&mut h, 4, &[&A, &sig[..32], &R, &Rv, m]);
+    reduce(&mut h);
+
+    if !bool::from(crypto_verify_32(sig, 32, &h, 0)) {
+        return Err(Error::InvalidSignature);
     }
 
-    let _r0 = r[0].clone();
-    let _r1 = r[1].clone();
-    M(&mut r[3], &_r0, &_r1);
+    // v = hash5(cV)
+    unpack(&mut p, sig);
+    mul_cofactor(&mut p);
+    pack(&mut c, &p);
+    hash_i(&mut cv, 5, &[&c]);
+    v[..32].copy_from_slice(&cv[..32]);
 
-    0
+    Ok(())
 }
 
-fn crypto_sign_open(m: &mut Vec<u32>, sm: &Vec<u32>, _n: usize, pk: &Vec<u32>) -> isize {
-    let mut t: Vec<u32> = vec![0; 32];
-    let mut h: Vec<u32> = vec![0; 64];
-    let mut p: Vec<Vec<i64>> = ([gf(), gf(), gf(), gf()]).to_vec();
-    let mut q: Vec<Vec<i64>> = ([gf(), gf(), gf(), gf()]).to_vec();
-    let mut n = _n;
+// Strict verification
 
-    let mut mlen = -1;
-    if n < 64 {
-        return mlen;
+// Strict checks of a signature, unpacking -A and -R. A is left with Z = u + 1, its
+// encoding needs the inverse of Z, shared by all the entries of a batch.
+fn unpack_strict(na: &mut Point, nr: &mut Point, sig: &[u8], pk: &[u8]) -> Result<(), Error> {
+    if sig.len() != 64 {
+        return Err(Error::InvalidLength {
+            expected: 64,
+            actual: sig.len(),
+        });
     }
-
-    if unpackneg(&mut q, &pk) != 0 {
-        return mlen;
+    if pk.len() != 32 {
+        return Err(Error::InvalidLength {
+            expected: 32,
+            actual: pk.len(),
+        });
     }
 
-    for i in 0..n {
-        m[i] = sm[i];
+    // Only one encoding per key, signature point and scalar.
+    if !is_canonical_u(pk) {
+        return Err(Error::InvalidPublicKey);
+    }
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&sig[32..64]);
+    s[31] &= 127;
+    if !is_canonical_scalar(&s) {
+        return Err(Error::NonCanonicalScalar);
+    }
+    if !is_canonical_y(sig) {
+        return Err(Error::InvalidSignature);
     }
 
-    for i in 0..32 {
-        m[i + 32] = pk[i];
+    // Low order A or R would let the same signature hold for other keys or messages.
+    // edwardsY = (montgomeryX - 1) / (montgomeryX + 1)
+    let u = unpack25519(pk);
+    if !bool::from(unpackneg_yz(na, &Z(&u, &gf1), &A(&u, &gf1), sig[63] >> 7)) {
+        return Err(Error::InvalidPublicKey);
+    }
+    let mut p = *na;
+    mul_cofactor(&mut p);
+    if is_identity(&p) {
+        return Err(Error::LowOrderPoint);
+    }
+    if !bool::from(unpackneg(nr, sig)) {
+        return Err(Error::InvalidSignature);
+    }
+    p = *nr;
+    mul_cofactor(&mut p);
+    if is_identity(&p) {
+        return Err(Error::InvalidSignature);
     }
 
-    crypto_hash(&mut h, &m, n);
-    reduce(&mut h);
-    scalarmult(&mut p, &mut q, &h);
+    Ok(())
+}
 
-    let mut tmp: Vec<u32> = (&sm[32..]).to_vec();
-    scalarbase(&mut q, &tmp);
-    add(&mut p, &mut q);
-    pack(&mut t, &p);
+// Encoding of A from -A and the inverse zi of its Z, with the sign bit of the signature.
+fn encode_strict(na: &Point, zi: &Gf, sig: &[u8]) -> [u8; 32] {
+    let mut A = [0u8; 32];
+    pack25519(&mut A, &M(&na.y, zi));
+    A[31] |= sig[63] & 128;
 
-    n -= 64;
-    if crypto_verify_32(&sm, 0, &t, 0) != 0 {
-        for i in 0..n {
-            m[i] = 0;
-        }
+    A
+}
 
-        return -1;
+// Inverts every element with a single inversion, none may be zero.
+#[cfg(feature = "alloc")]
+fn batch_inv25519(v: &mut [Gf]) {
+    let mut prefix = Vec::with_capacity(v.len());
+    let mut acc = gf1;
+    for x in v.iter() {
+        prefix.push(acc);
+        acc = M(&acc, x);
     }
 
-    for i in 0..n {
-        m[i] = sm[i + 64];
+    let mut inv = inv25519(&acc);
+    for (x, before) in v.iter_mut().zip(prefix).rev() {
+        let xi = M(&inv, &before);
+        inv = M(&inv, x);
+        *x = xi;
     }
+}
+
+pub fn curve25519_verify_strict(m: &[u8], sig: &[u8], pk: &[u8]) -> Result<(), Error> {
+    let mut na = IDENTITY;
+    let mut nr = IDENTITY;
 
-    mlen = n as isize;
+    unpack_strict(&mut na, &mut nr, sig, pk)?;
 
-    mlen
+    curve25519_verify(m, sig, pk)
 }
 
-// Converts Curve25519 public key back to Ed25519 public key.
-// edwardsY = (montgomeryX - 1) / (montgomeryX + 1)
-fn convert_public_key(pk: &Vec<u32>) -> Vec<u32> {
-    let mut z: Vec<u32> = vec![0; 32];
-    let mut x = gf();
-    let mut a = gf();
-    let mut b = gf();
+// Batch verification
 
-    unpack25519(&mut x, &pk);
+// Checks [8]([S]B - R - [h]A) = 0 with S = sum z_i S_i, R = sum z_i R_i and
+// h A = sum z_i h_i A_i, for 16 bytes z_i per signature in z.
+#[cfg(feature = "alloc")]
+fn verify_combination(
+    items: &[(&[u8], &[u8], [u8; 32])],
+    na: &[Point],
+    nr: &[Point],
+    z: &[u8],
+) -> bool {
+    let mut h = [0u8; 64];
+    let mut zi = [0u8; 32];
+    let mut sum = [0u8; 32];
+    let mut terms: Vec<Term> = Vec::with_capacity(2 * items.len() + 1);
+    let mut p = IDENTITY;
 
-    A(&mut a, &x, &gf1.to_vec());
-    Z(&mut b, &x, &gf1.to_vec());
-    let _a = a.clone();
-    inv25519(&mut a, &_a);
-    let _a = a.clone();
-    M(&mut a, &_a, &b);
+    for (i, (m, sig, A)) in items.iter().enumerate() {
+        zi[..16].copy_from_slice(&z[16 * i..16 * i + 16]);
 
-    pack25519(&mut z, &a);
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&sig[32..64]);
+        s[31] &= 127;
+        let acc = sum;
+        sc_muladd(&mut sum, &zi, &s, &acc);
 
-    z
-}
+        let mut hasher = Sha512::new();
+        hasher.update(&sig[..32]);
+        hasher.update(A);
+        hasher.update(m);
+        h.copy_from_slice(&hasher.finalize());
+        reduce(&mut h);
 
-pub fn curve25519_sign_open(m: &mut Vec<u32>, sm: &mut Vec<u32>, pk: Vec<u32>) -> isize {
-    // Convert Curve25519 public key into Ed25519 public key.
-    let mut edpk = convert_public_key(&pk);
+        let mut zh = [0u8; 32];
+        sc_muladd(&mut zh, &zi, &h, &[0u8; 32]);
 
-    // Restore sign bit from signature.
-    edpk[31] = edpk[31] | (sm[63] & 128);
+        terms.push(term(&zi, &nr[i]));
+        terms.push(term(&zh, &na[i]));
+    }
 
-    // Remove sign bit from signature.
-    sm[63] = sm[63] & 127;
+    terms.push(term(&sum, &BASE));
 
-    // Verify signed message.
+    multiscalarmult(&mut p, &terms);
+    mul_cofactor(&mut p);
 
-    crypto_sign_open(m, &sm, sm.len(), &edpk)
+    is_identity(&p)
 }
 
-fn shared_key(secret_key: &Vec<u32>, public_key: &Vec<u32>) -> Vec<u32> {
-    let mut shared_key: Vec<u32> = vec![0; 32];
-    crypto_scalarmult(&mut shared_key, secret_key, public_key);
+#[cfg(feature = "alloc")]
+pub fn curve25519_verify_batch(items: &[(&[u8], &[u8], &[u8])], z: &[u8]) -> Vec<(usize, Error)> {
+    let mut failures = Vec::new();
+    let mut indices = Vec::with_capacity(items.len());
+    let mut na = Vec::with_capacity(items.len());
+    let mut nr = Vec::with_capacity(items.len());
+
+    for (i, (_, sig, pk)) in items.iter().enumerate() {
+        let mut a = IDENTITY;
+        let mut r = IDENTITY;
+        match unpack_strict(&mut a, &mut r, sig, pk) {
+            Ok(()) => {
+                indices.push(i);
+                na.push(a);
+                nr.push(r);
+            }
+            Err(e) => failures.push((i, e)),
+        }
+    }
 
-    shared_key
-}
+    // One inversion for the encodings of every A.
+    let mut zi: Vec<Gf> = na.iter().map(|a| a.z).collect();
+    batch_inv25519(&mut zi);
+    let checked: Vec<(&[u8], &[u8], [u8; 32])> = indices
+        .iter()
+        .zip(&na)
+        .zip(&zi)
+        .map(|((i, a), zi)| {
+            let (m, sig, _) = items[*i];
+            (m, sig, encode_strict(a, zi, sig))
+        })
+        .collect();
+
+    if checked.is_empty() || verify_combination(&checked, &na, &nr, z) {
+        return failures;
+    }
+
+    // Find the failing signatures one by one, with z = 1.
+    let mut one = [0u8; 16];
+    one[0] = 1;
+    for (k, i) in indices.into_iter().enumerate() {
+        let j = k..k + 1;
+        if !verify_combination(&checked[j.clone()], &na[j.clone()], &nr[j], &one) {
+            failures.push((i, Error::InvalidSignature));
+        }
+    }
+    failures.sort_by_key(|(i, _)| *i);
 
-pub fn crypto_scalarmult_base(q: &mut Vec<u32>, n: &Vec<u32>) -> usize {
-    crypto_scalarmult(q, n, &_9.to_vec())
+    failures
 }
@@ -1,3 +1,5 @@
+/// Constant time primitives
+pub(crate) mod ct;
 /// Base math cryptography funtions
 pub mod extras;
 /// Random vectors functions
@@ -1,7 +1,17 @@
+#[cfg(feature = "getrandom")]
+pub use rand::rngs::OsRng;
+pub use rand::{CryptoRng, RngCore};
+
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+use alloc::vec::Vec;
+
 /**
 # Return a vector with *`n`* random numbers
 
-- The function takes three random numbers in the range from 0 to 255
+- The function takes random numbers in the range from 0 to 255
+- Numbers come from the operating system entropy
 
 ## Example
 
@@ -18,11 +28,57 @@ assert_eq!(
 );
 ```
 */
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
 pub fn random_bytes(size: usize) -> Vec<u32> {
-    use rand::Rng;
+    let mut bytes = vec![0u8; size];
+    fill_bytes(&mut bytes);
+    bytes.iter().map(|x| *x as u32).collect::<Vec<u32>>()
+}
+
+/**
+# Fill *`bytes`* with random bytes from the operating system
+
+## Example
+
+```rust
+use ed25519_axolotl::utils::random::fill_bytes;
+
+let mut seed = [0u8; 32];
+fill_bytes(&mut seed);
+```
+*/
+#[cfg(feature = "getrandom")]
+pub fn fill_bytes(bytes: &mut [u8]) {
+    fill_bytes_with(&mut OsRng, bytes)
+}
+
+/**
+# Fill *`bytes`* with random bytes from *`rng`*
+
+- Any cryptographically secure generator can be used, e.g. a seeded one in tests
+
+## Example
+
+```rust
+use ed25519_axolotl::utils::random::{fill_bytes_with, OsRng};
+
+let mut seed = [0u8; 32];
+fill_bytes_with(&mut OsRng, &mut seed);
+```
+*/
+pub fn fill_bytes_with<R: CryptoRng + RngCore>(rng: &mut R, bytes: &mut [u8]) {
+    rng.fill_bytes(bytes)
+}
+
+/// Return an array of `N` random bytes from *`rng`*
+pub(crate) fn random_array_with<R: CryptoRng + RngCore, const N: usize>(rng: &mut R) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    fill_bytes_with(rng, &mut bytes);
+    bytes
+}
 
-    let mut rng = rand::thread_rng();
-    (0..size)
-        .map(|_| rng.gen_range(0..255))
-        .collect::<Vec<u32>>()
+/// Return an array of `N` random bytes from the operating system
+#[cfg(all(feature = "alloc", feature = "getrandom"))]
+pub(crate) fn random_array<const N: usize>() -> [u8; N] {
+    random_array_with(&mut OsRng)
 }
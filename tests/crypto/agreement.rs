@@ -0,0 +1,156 @@
+use crate::hex;
+
+fn x25519(scalar: &str, point: &str) -> [u8; 32] {
+    use ed25519_axolotl::crypto::keys::{PublicKey, SecretKey};
+
+    let secret_key = SecretKey::try_from(&hex(scalar)[..]).unwrap();
+    let public_key = PublicKey::try_from(&hex(point)[..]).unwrap();
+    secret_key.diffie_hellman(&public_key).unwrap().to_bytes()
+}
+
+#[test]
+fn rfc7748_vectors() {
+    // RFC 7748, section 5.2
+    assert_eq!(
+        hex("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552"),
+        x25519(
+            "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+            "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"
+        )
+    );
+    assert_eq!(
+        hex("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957"),
+        x25519(
+            "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+            "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493"
+        )
+    );
+}
+
+/// RFC 7748, section 5.2: k and u start at 9, then u = k and k = output
+fn rfc7748_iterate(iterations: usize) -> [u8; 32] {
+    use ed25519_axolotl::crypto::keys::{PublicKey, SecretKey};
+
+    let mut k = [0u8; 32];
+    k[0] = 9;
+    let mut u = k;
+
+    for _ in 0..iterations {
+        let output = SecretKey::from_bytes(k)
+            .diffie_hellman(&PublicKey::from_bytes(u))
+            .unwrap();
+        u = k;
+        k = output.to_bytes();
+    }
+    k
+}
+
+#[test]
+fn rfc7748_iterations() {
+    assert_eq!(
+        hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"),
+        rfc7748_iterate(1)
+    );
+}
+
+#[test]
+fn rfc7748_thousand_iterations() {
+    assert_eq!(
+        hex("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"),
+        rfc7748_iterate(1000)
+    );
+}
+
+#[test]
+fn alice_and_bob() {
+    use ed25519_axolotl::crypto::keys::SecretKey;
+
+    // RFC 7748, section 6.1
+    let alice = SecretKey::try_from(
+        &hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a")[..],
+    )
+    .unwrap();
+    let bob = SecretKey::try_from(
+        &hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb")[..],
+    )
+    .unwrap();
+
+    assert_eq!(
+        hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"),
+        alice.public_key().as_bytes()
+    );
+    assert_eq!(
+        hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f"),
+        bob.public_key().as_bytes()
+    );
+
+    let shared = hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+    assert_eq!(
+        shared,
+        alice.diffie_hellman(&bob.public_key()).unwrap().as_bytes()
+    );
+    assert_eq!(
+        shared,
+        bob.diffie_hellman(&alice.public_key()).unwrap().as_bytes()
+    );
+}
+
+#[test]
+fn key_pairs_agree() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let alice = KeyPair::from_seed(&[1; 32]);
+    let bob = KeyPair::from_seed(&[2; 32]);
+
+    assert_eq!(
+        alice.diffie_hellman(&bob.pubk).unwrap().as_bytes(),
+        bob.diffie_hellman(&alice.pubk).unwrap().as_bytes()
+    );
+
+    // The same keys still sign
+    let signature = alice.fast_signature(b"Lunes");
+    assert!(alice.pubk.validate_signature(b"Lunes", &signature));
+}
+
+#[test]
+fn low_order_points() {
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+
+    for point in [
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800",
+        "5f9c95bca3508c24b1d0b1559c83ef5b04445cc4581c8e86d8224eddd09f1157",
+        "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    ] {
+        let public_key = PublicKey::try_from(&hex(point)[..]).unwrap();
+        assert_eq!(
+            Some(Error::LowOrderPoint),
+            keys.diffie_hellman(&public_key).err()
+        );
+    }
+}
+
+#[test]
+fn base_table_matches_ladder() {
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+
+    let mut nine = [0u8; 32];
+    nine[0] = 9;
+    let base = PublicKey::from_bytes(nine);
+
+    // Seeds with every digit of the comb, including the carries of 8 and 15
+    for i in 0..=255u8 {
+        let mut seed = [i; 32];
+        seed[i as usize % 32] ^= 0x88;
+        let keys = KeyPair::from_seed(&seed);
+
+        assert_eq!(
+            keys.pubk.to_bytes(),
+            keys.prvk.diffie_hellman(&base).unwrap().to_bytes()
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{hex, sevens};
+use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+use ed25519_axolotl::crypto::signatures::{verify_batch, verify_batch_with, Signature};
+use ed25519_axolotl::Error;
+
+fn signed(count: u8) -> Vec<(PublicKey, Vec<u8>, Signature)> {
+    (0..count)
+        .map(|i| {
+            let keys = KeyPair::from_seed(&[i; 32]);
+            let message = vec![i; i as usize];
+            let signature = keys.fast_signature(&message);
+            (keys.pubk, message, signature)
+        })
+        .collect()
+}
+
+fn batch(signed: &[(PublicKey, Vec<u8>, Signature)]) -> Vec<(PublicKey, &[u8], Signature)> {
+    signed
+        .iter()
+        .map(|(key, message, signature)| (*key, &message[..], *signature))
+        .collect()
+}
+
+#[test]
+fn accepted() {
+    let signed = signed(16);
+
+    assert_eq!(Ok(()), verify_batch(&batch(&signed)));
+    assert_eq!(Ok(()), verify_batch_with(&batch(&signed), &mut sevens()));
+    assert_eq!(Ok(()), verify_batch(&[]));
+}
+
+#[test]
+fn failures_listed() {
+    let signed = signed(8);
+    let mut items = batch(&signed);
+
+    // Wrong message
+    items[1].1 = b"Lunes";
+
+    // S + L
+    let order = hex("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+    let mut bytes = items[3].2.to_bytes();
+    let mut carry = 0;
+    for i in 0..32 {
+        let sum = bytes[32 + i] as u16 + order[i] as u16 + carry;
+        bytes[32 + i] = sum as u8;
+        carry = sum >> 8;
+    }
+    items[3].2 = Signature::from_bytes(bytes);
+
+    // Point of order 2
+    items[4].0 = PublicKey::from_bytes([0; 32]);
+
+    // Signature of another key
+    items[6].2 = signed[5].2;
+
+    let error = verify_batch(&items).unwrap_err();
+    assert_eq!(
+        vec![
+            (1, Error::InvalidSignature),
+            (3, Error::NonCanonicalScalar),
+            (4, Error::LowOrderPoint),
+            (6, Error::InvalidSignature),
+        ],
+        error.failures
+    );
+    assert_eq!(vec![1, 3, 4, 6], error.indices());
+    assert_eq!(Err(error), verify_batch_with(&items, &mut sevens()));
+}
+
+#[test]
+fn single_failure() {
+    let signed = signed(32);
+
+    for i in [0, 17, 31] {
+        let mut items = batch(&signed);
+        let mut bytes = items[i].2.to_bytes();
+        bytes[0] ^= 1;
+        items[i].2 = Signature::from_bytes(bytes);
+
+        assert_eq!(vec![i], verify_batch(&items).unwrap_err().indices());
+    }
+}
+
+#[test]
+fn cofactored() {
+    // R = rB + T with T of order 4, signed by the key of seed [1; 32]
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = Signature::from_bytes(
+        hex(
+            "937bf8c94581538ba43ea9a0d4df52e33040748d553200c28e2d06a6f4b6e38e\
+             1a0f7126aa17372900f5733958346bb6bcb01e4aabfd447cb9e8c1d1f5552e09",
+        )
+        .try_into()
+        .unwrap(),
+    );
+
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        keys.pubk.verify_strict(b"Lunes", &signature)
+    );
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        keys.pubk.verify(b"Lunes", &signature)
+    );
+    assert_eq!(Ok(()), verify_batch(&[(keys.pubk, b"Lunes", signature)]));
+}
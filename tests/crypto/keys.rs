@@ -61,8 +61,57 @@ fn test_keys() {
     ];
 
     for x in response {
-        let keys = KeyPair::new(Some(x[0].to_vec()));
-        assert_eq!(keys.prvk, x[1]);
-        assert_eq!(keys.pubk, x[2]);
+        let keys = KeyPair::from_seed(&x[0]);
+        assert_eq!(keys.prvk.expose_secret(), &x[1]);
+        assert_eq!(keys.pubk.as_bytes(), &x[2]);
     }
 }
+
+#[test]
+fn keys_from_slices() {
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey, SecretKey};
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+
+    let prvk = SecretKey::try_from(&keys.prvk.expose_secret()[..]).unwrap();
+    let pubk = PublicKey::try_from(&keys.pubk.as_bytes()[..]).unwrap();
+    assert_eq!(keys.prvk, prvk);
+    assert_eq!(keys.pubk, pubk);
+    assert_eq!(pubk, prvk.public_key());
+
+    assert!(SecretKey::try_from(&[1u8; 31][..]).is_err());
+    assert!(PublicKey::try_from(&[1u8; 33][..]).is_err());
+}
+
+#[test]
+fn generate_with_rng() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let a = KeyPair::generate_with(&mut StdRng::seed_from_u64(808));
+    let b = KeyPair::generate_with(&mut StdRng::seed_from_u64(808));
+    assert_eq!(a.prvk, b.prvk);
+    assert_eq!(a.pubk, b.pubk);
+
+    let c = KeyPair::generate();
+    assert_ne!(a.prvk, c.prvk);
+    assert_eq!(c.pubk, c.prvk.public_key());
+}
+
+#[test]
+fn secrets_redacted() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let secret = format!("{:?}", keys.prvk.expose_secret());
+
+    assert_eq!("780b1d91951363c6", keys.pubk.fingerprint());
+    assert_eq!("KeyPair(780b1d91951363c6)", keys.to_string());
+    assert_eq!("SecretKey(<redacted>)", format!("{:?}", keys.prvk));
+    assert_eq!(
+        "KeyPair { prvk: SecretKey(<redacted>), fingerprint: \"780b1d91951363c6\" }",
+        format!("{keys:?}")
+    );
+    assert!(!format!("{keys:?} {keys} {:#?}", keys.prvk).contains(&secret[1..20]));
+}
@@ -0,0 +1,182 @@
+#![allow(deprecated)]
+
+fn widen(bytes: &[u8]) -> Vec<u32> {
+    bytes.iter().map(|x| *x as u32).collect::<Vec<u32>>()
+}
+
+#[test]
+fn legacy_keys() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::new(Some(vec![1; 32]));
+    let expected = KeyPair::from_seed(&[1; 32]);
+    assert_eq!(keys.prvk, expected.prvk);
+    assert_eq!(keys.pubk, expected.pubk);
+}
+
+#[test]
+fn legacy_long_seed() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    // Only the first 32 bytes are used, as the legacy function did.
+    let mut seed = vec![1; 32];
+    seed.extend([2; 32]);
+    let keys = KeyPair::new(Some(seed));
+    assert_eq!(KeyPair::from_seed(&[1; 32]).pubk, keys.pubk);
+
+    let mut seed = vec![1; 32];
+    seed.push(300);
+    assert_eq!(
+        Ok(KeyPair::from_seed(&[1; 32]).pubk),
+        KeyPair::try_new(Some(seed)).map(|keys| keys.pubk)
+    );
+}
+
+#[test]
+fn legacy_fast_sign() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::{fast_signature, validate_signature};
+    use ed25519_axolotl::utils::random::random_bytes;
+
+    let keys = KeyPair::new(Some(vec![1; 32]));
+    let msg = widen("Lunes".as_bytes());
+    let signature = fast_signature(
+        widen(keys.prvk.expose_secret()),
+        msg.clone(),
+        Some(random_bytes(64)),
+    );
+
+    assert!(validate_signature(
+        widen(keys.pubk.as_bytes()),
+        msg,
+        signature
+    ));
+}
+
+#[test]
+fn legacy_full_sign() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::{full_signature, validate_signature};
+
+    let keys = KeyPair::new(Some(vec![1; 32]));
+    let msg = widen("Lunes".as_bytes());
+
+    let signature = full_signature(widen(keys.prvk.expose_secret()), msg.clone(), None);
+
+    assert!(validate_signature(
+        widen(keys.pubk.as_bytes()),
+        msg,
+        signature
+    ));
+}
+
+#[test]
+fn legacy_decoded() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::{decode_signature, full_signature};
+    use ed25519_axolotl::utils::random::random_bytes;
+
+    let keys = KeyPair::new(Some(vec![1; 32]));
+    let msg = widen("Lunes".as_bytes());
+
+    let signature = full_signature(
+        widen(keys.prvk.expose_secret()),
+        msg.clone(),
+        Some(random_bytes(64)),
+    );
+
+    assert_eq!(
+        msg,
+        decode_signature(widen(keys.pubk.as_bytes()), signature)
+    )
+}
+
+#[test]
+fn legacy_known_signature() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::fast_signature;
+
+    let keys = KeyPair::new(Some(vec![1; 32]));
+    let msg = widen("Lunes".as_bytes());
+    let random = (0..64).map(|i| i * 7 % 256).collect::<Vec<u32>>();
+
+    let signature = fast_signature(widen(keys.prvk.expose_secret()), msg, Some(random));
+
+    assert_eq!(
+        signature,
+        [
+            157, 103, 254, 20, 29, 239, 161, 251, 103, 148, 61, 52, 204, 37, 27, 13, 0, 132, 40,
+            151, 155, 1, 92, 82, 57, 205, 84, 242, 51, 100, 78, 103, 76, 147, 231, 87, 173, 24,
+            117, 78, 241, 169, 139, 146, 9, 53, 79, 112, 233, 117, 230, 193, 24, 138, 236, 13, 13,
+            11, 82, 172, 82, 196, 200, 15,
+        ]
+    );
+}
+
+#[test]
+fn legacy_malformed() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::{
+        decode_signature, try_decode_signature, try_fast_signature, try_validate_signature,
+        validate_signature,
+    };
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::new(Some(vec![1; 32]));
+    let msg = widen("Lunes".as_bytes());
+
+    assert_eq!(
+        Some(Error::InvalidLength {
+            expected: 32,
+            actual: 16
+        }),
+        KeyPair::try_new(Some(vec![1; 16])).err()
+    );
+    assert_eq!(
+        Some(Error::ByteOutOfRange {
+            index: 3,
+            value: 300
+        }),
+        KeyPair::try_new(Some([1, 1, 1, 300].repeat(8))).err()
+    );
+
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 64,
+            actual: 8
+        }),
+        try_fast_signature(
+            widen(keys.prvk.expose_secret()),
+            msg.clone(),
+            Some(vec![1; 8])
+        )
+    );
+
+    // Short signatures and huge bytes used to panic
+    assert!(!validate_signature(
+        widen(keys.pubk.as_bytes()),
+        msg.clone(),
+        vec![1; 10]
+    ));
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 64,
+            actual: 10
+        }),
+        try_validate_signature(widen(keys.pubk.as_bytes()), msg.clone(), vec![1; 10])
+    );
+    assert_eq!(
+        Err(Error::ByteOutOfRange {
+            index: 0,
+            value: 256
+        }),
+        try_validate_signature(widen(keys.pubk.as_bytes()), vec![256], vec![1; 64])
+    );
+
+    // A forged signature used to be decoded into a gigantic allocation
+    assert!(decode_signature(widen(keys.pubk.as_bytes()), vec![1; 80]).is_empty());
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        try_decode_signature(widen(keys.pubk.as_bytes()), vec![1; 80])
+    );
+}
@@ -0,0 +1,160 @@
+use crate::hex;
+use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+use ed25519_axolotl::crypto::signatures::Signature;
+use ed25519_axolotl::Error;
+
+/// Group order L, little endian
+const ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+];
+
+/// Basepoint encoding, used as `R` of forgeries with `S = 1`
+const BASEPOINT: &str = "5866666666666666666666666666666666666666666666666666666666666666";
+
+fn signature(text: &str) -> Signature {
+    Signature::from_bytes(hex(text).try_into().unwrap())
+}
+
+fn public_key(text: &str) -> PublicKey {
+    PublicKey::from_bytes(hex(text).try_into().unwrap())
+}
+
+#[test]
+fn honest_signatures() {
+    for seed in 0..4u8 {
+        let keys = KeyPair::from_seed(&[seed; 32]);
+        let message = [seed; 100];
+
+        for signature in [
+            keys.fast_signature(&message),
+            keys.prvk.sign_deterministic(&message),
+        ] {
+            assert_eq!(Ok(()), keys.pubk.verify(&message, &signature));
+            assert_eq!(Ok(()), keys.pubk.verify_strict(&message, &signature));
+            assert!(keys.pubk.validate_signature_strict(&message, &signature));
+        }
+    }
+}
+
+#[test]
+fn scalar_plus_order() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.fast_signature(b"Lunes");
+
+    let mut bytes = signature.to_bytes();
+    let sign = bytes[63] & 128;
+    bytes[63] &= 127;
+    let mut carry = 0;
+    for i in 0..32 {
+        let sum = bytes[32 + i] as u16 + ORDER[i] as u16 + carry;
+        bytes[32 + i] = sum as u8;
+        carry = sum >> 8;
+    }
+    assert_eq!(0, bytes[63] & 128);
+    bytes[63] |= sign;
+    let malleated = Signature::from_bytes(bytes);
+
+    assert_ne!(signature, malleated);
+    assert_eq!(Ok(()), keys.pubk.verify(b"Lunes", &malleated));
+    assert_eq!(
+        Err(Error::NonCanonicalScalar),
+        keys.pubk.verify_strict(b"Lunes", &malleated)
+    );
+}
+
+#[test]
+fn public_key_top_bit() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.fast_signature(b"Lunes");
+
+    let mut bytes = keys.pubk.to_bytes();
+    bytes[31] |= 128;
+    let malleated = PublicKey::from_bytes(bytes);
+
+    assert_eq!(Ok(()), malleated.verify(b"Lunes", &signature));
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        malleated.verify_strict(b"Lunes", &signature)
+    );
+}
+
+#[test]
+fn low_order_r() {
+    // R is the identity and S = h * a, signed by the key of seed [1; 32]
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = signature(
+        "0100000000000000000000000000000000000000000000000000000000000000\
+         f29eac162ca1b6d8e65f59e50184155a6e99af2a5ce763e14978f9b8967fda0e",
+    );
+
+    assert_eq!(Ok(()), keys.pubk.verify(b"Lunes", &signature));
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        keys.pubk.verify_strict(b"Lunes", &signature)
+    );
+}
+
+#[test]
+fn non_canonical_r() {
+    // R is the identity encoded with y = p + 1
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = signature(
+        "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f\
+         99d9fe2ae657ed1a168e0af60dc1098e016f9e6c0e1d8573beec2215ed9f6808",
+    );
+
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        keys.pubk.verify(b"Lunes", &signature)
+    );
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        keys.pubk.verify_strict(b"Lunes", &signature)
+    );
+}
+
+#[test]
+fn low_order_public_key() {
+    // u = 0 is the point of order 2, so R = B and S = 1 hold whenever h is even
+    let forged = signature(&format!("{BASEPOINT}01{}", "00".repeat(31)));
+    let even = [4, 6, 7, 8];
+
+    for key in [
+        public_key(&"00".repeat(32)),
+        // u = p
+        public_key(&format!("ed{}7f", "ff".repeat(30))),
+    ] {
+        for i in 0..10 {
+            let message = format!("Lunes {i}");
+            let expected = match even.contains(&i) {
+                true => Ok(()),
+                false => Err(Error::InvalidSignature),
+            };
+
+            assert_eq!(expected, key.verify(message.as_bytes(), &forged));
+            assert!(key.verify_strict(message.as_bytes(), &forged).is_err());
+        }
+    }
+
+    let key = public_key(&"00".repeat(32));
+    assert_eq!(
+        Err(Error::LowOrderPoint),
+        key.verify_strict(b"Lunes 4", &forged)
+    );
+}
+
+#[test]
+fn every_byte_checked() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.fast_signature(b"Lunes");
+
+    for i in 0..64 {
+        let mut bytes = signature.to_bytes();
+        bytes[i] ^= 1 << (i % 8);
+        let tampered = Signature::from_bytes(bytes);
+
+        assert!(!keys.pubk.validate_signature(b"Lunes", &tampered));
+        assert!(!keys.pubk.validate_signature_strict(b"Lunes", &tampered));
+    }
+}
@@ -1,2 +1,10 @@
+mod agreement;
+mod batch;
 mod keys;
+mod legacy;
+mod malleability;
+#[cfg(feature = "serde")]
+mod serialization;
 mod signatures;
+mod traits;
+mod xeddsa;
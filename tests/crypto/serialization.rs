@@ -0,0 +1,100 @@
+use crate::hex;
+use ed25519_axolotl::crypto::keys::{Exposed, KeyPair, PublicKey, SecretKey};
+use ed25519_axolotl::crypto::signatures::{Signature, SignedMessage};
+use ed25519_axolotl::encoding::base58;
+use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+const PUBLIC_KEY: &str = "a4e09292b651c278b9772c569f5fa9bb13d906b46ab68c9df9dc2b4409f8a209";
+const PUBLIC_KEY_BASE58: &str = "C6cUahgsea11j7dTmSFiNydnaPG9kg7yPFagXqerHGnC";
+
+/// Tokens borrow their strings for the whole test run
+fn leak_base58(bytes: &[u8]) -> &'static str {
+    base58::encode(bytes).leak()
+}
+
+fn tuple(bytes: &[u8]) -> Vec<Token> {
+    let mut tokens = vec![Token::Tuple { len: bytes.len() }];
+    tokens.extend(bytes.iter().map(|b| Token::U8(*b)));
+    tokens.push(Token::TupleEnd);
+    tokens
+}
+
+#[test]
+fn public_key() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+
+    assert_tokens(&keys.pubk.readable(), &[Token::Str(PUBLIC_KEY_BASE58)]);
+    assert_tokens(&keys.pubk.compact(), &tuple(&hex(PUBLIC_KEY)));
+
+    let json = serde_json::to_string(&keys.pubk).unwrap();
+    assert_eq!(format!("\"{}\"", keys.pubk), json);
+    assert_eq!(keys.pubk, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn signature() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.sign_deterministic(b"Lunes");
+
+    assert_tokens(
+        &signature.readable(),
+        &[Token::Str(leak_base58(signature.as_bytes()))],
+    );
+    assert_tokens(&signature.compact(), &tuple(signature.as_bytes()));
+    assert_eq!(
+        format!("\"{signature}\""),
+        serde_json::to_string(&signature).unwrap()
+    );
+}
+
+#[test]
+fn signed_message() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signed_msg = keys.full_signature(b"Lunes");
+    let bytes = signed_msg.to_bytes().leak();
+
+    assert_tokens(
+        &signed_msg.clone().readable(),
+        &[Token::Str(leak_base58(bytes))],
+    );
+    assert_tokens(&signed_msg.clone().compact(), &[Token::Bytes(bytes)]);
+
+    let json = serde_json::to_string(&signed_msg).unwrap();
+    let decoded: SignedMessage = serde_json::from_str(&json).unwrap();
+    assert_eq!(Ok(b"Lunes".to_vec()), keys.pubk.decode_signature(&decoded));
+}
+
+#[test]
+fn secret_key_opt_in() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let json = serde_json::to_string(&Exposed(&keys.prvk)).unwrap();
+    assert_eq!(format!("\"{}\"", keys.prvk.to_base58()), json);
+
+    let json = serde_json::to_string(&Exposed(&keys)).unwrap();
+    assert_eq!(
+        json,
+        serde_json::to_string(&Exposed(keys.prvk.clone())).unwrap()
+    );
+
+    let Exposed(loaded): Exposed<KeyPair> = serde_json::from_str(&json).unwrap();
+    assert_eq!(keys.prvk, loaded.prvk);
+    assert_eq!(keys.pubk, loaded.pubk);
+    let Exposed(prvk): Exposed<SecretKey> = serde_json::from_str(&json).unwrap();
+    assert_eq!(keys.prvk, prvk);
+}
+
+#[test]
+fn rejected() {
+    assert_de_tokens_error::<serde_test::Readable<PublicKey>>(
+        &[Token::Str("C6cU")],
+        "invalid length 3, expected 32 bytes or their Base58 string",
+    );
+    assert_de_tokens_error::<serde_test::Readable<Signature>>(
+        &[Token::Str("0O")],
+        "invalid value: string \"0O\", expected 64 bytes or their Base58 string",
+    );
+    assert_de_tokens_error::<serde_test::Compact<SignedMessage>>(
+        &[Token::Bytes(&[0; 63])],
+        "invalid length 63, expected 64 bytes or more, or their Base58 string",
+    );
+}
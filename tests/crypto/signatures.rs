@@ -1,52 +1,215 @@
 #[test]
 fn fast_sign() {
     use ed25519_axolotl::crypto::keys::KeyPair;
-    use ed25519_axolotl::crypto::signatures::{fast_signature, validate_signature};
-    use ed25519_axolotl::utils::random::random_bytes;
 
-    let keys = KeyPair::new(Some(vec![1; 32]));
-    let msg = "Lunes"
-        .as_bytes()
-        .iter()
-        .map(|x| *x as u32)
-        .collect::<Vec<u32>>();
-    let signature = fast_signature(keys.prvk, msg.clone(), Some(random_bytes(64)));
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let msg = "Lunes".as_bytes();
+    let signature = keys.prvk.fast_signature(msg);
 
-    assert_eq!(true, validate_signature(keys.pubk, msg, signature));
+    assert!(keys.pubk.validate_signature(msg, &signature));
 }
 
 #[test]
 fn full_sign() {
     use ed25519_axolotl::crypto::keys::KeyPair;
-    use ed25519_axolotl::crypto::signatures::{full_signature, validate_signature};
-    use ed25519_axolotl::utils::random::random_bytes;
 
-    let keys = KeyPair::new(Some(vec![1; 32]));
-    let msg = "Lunes"
-        .as_bytes()
-        .iter()
-        .map(|x| *x as u32)
-        .collect::<Vec<u32>>();
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let msg = "Lunes".as_bytes();
 
-    let signature = full_signature(keys.prvk, msg.clone(), Some(random_bytes(64)));
+    let signed_msg = keys.prvk.full_signature(msg);
 
-    assert_eq!(true, validate_signature(keys.pubk, msg, signature));
+    assert_eq!(msg, signed_msg.message());
+    assert!(keys.pubk.validate_signature(msg, &signed_msg.signature()));
 }
 
 #[test]
 fn decoded() {
     use ed25519_axolotl::crypto::keys::KeyPair;
-    use ed25519_axolotl::crypto::signatures::{decode_signature, full_signature};
-    use ed25519_axolotl::utils::random::random_bytes;
 
-    let keys = KeyPair::new(Some(vec![1; 32]));
-    let msg = "Lunes"
-        .as_bytes()
-        .iter()
-        .map(|x| *x as u32)
-        .collect::<Vec<u32>>();
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let msg = "Lunes".as_bytes();
 
-    let signature = full_signature(keys.prvk, msg.clone(), Some(random_bytes(64)));
+    let signed_msg = keys.prvk.full_signature(msg);
 
-    assert_eq!(msg, decode_signature(keys.pubk, signature))
+    assert_eq!(Ok(msg.to_vec()), keys.pubk.decode_signature(&signed_msg))
+}
+
+#[test]
+fn rejected() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::{Signature, SignedMessage};
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let other = KeyPair::from_seed(&[2; 32]);
+    let msg = "Lunes".as_bytes();
+
+    let signature = keys.fast_signature(msg);
+    assert!(!keys.pubk.validate_signature(b"Lunis", &signature));
+    assert!(!other.pubk.validate_signature(msg, &signature));
+
+    let mut bytes = signature.to_bytes();
+    bytes[40] ^= 1;
+    assert!(!keys
+        .pubk
+        .validate_signature(msg, &Signature::from_bytes(bytes)));
+
+    let mut signed_msg = keys.full_signature(msg).to_bytes();
+    signed_msg[64] ^= 1;
+    let signed_msg = SignedMessage::from_bytes(&signed_msg).unwrap();
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        keys.pubk.decode_signature(&signed_msg)
+    );
+}
+
+#[test]
+fn signatures_from_slices() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::signatures::{Signature, SignedMessage};
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signed_msg = keys.full_signature(b"Lunes");
+
+    let signature = Signature::try_from(&signed_msg.as_bytes()[..64]).unwrap();
+    assert_eq!(signed_msg.signature(), signature);
+    assert_eq!(
+        signed_msg,
+        SignedMessage::try_from(signed_msg.as_bytes()).unwrap()
+    );
+
+    assert!(Signature::try_from(&[0u8; 63][..]).is_err());
+    assert!(SignedMessage::try_from(&[0u8; 63][..]).is_err());
+    assert!(SignedMessage::try_from(&[0u8; 64][..])
+        .unwrap()
+        .message()
+        .is_empty());
+}
+
+#[test]
+fn malformed() {
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+    use ed25519_axolotl::crypto::signatures::{Signature, SignedMessage};
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.fast_signature(b"Lunes");
+
+    // u = 2 is not the x coordinate of a point of the curve
+    let mut not_a_point = [0u8; 32];
+    not_a_point[0] = 2;
+    assert_eq!(
+        Err(Error::InvalidPublicKey),
+        PublicKey::from_bytes(not_a_point).verify(b"Lunes", &signature)
+    );
+
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 64,
+            actual: 10
+        }),
+        Signature::try_from(&[0u8; 10][..])
+    );
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 64,
+            actual: 0
+        }),
+        SignedMessage::from_bytes(&[])
+    );
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        keys.pubk.verify(b"Lunes", &Signature::from_bytes([0; 64]))
+    );
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        keys.pubk
+            .decode_signature(&SignedMessage::from_bytes(&[255; 100]).unwrap())
+    );
+}
+
+#[test]
+fn sign_with_rng() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let msg = "Lunes".as_bytes();
+
+    let a = keys.fast_signature_with_rng(msg, &mut StdRng::seed_from_u64(808));
+    let b = keys.fast_signature_with_rng(msg, &mut StdRng::seed_from_u64(808));
+    let c = keys.fast_signature_with_rng(msg, &mut StdRng::seed_from_u64(809));
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(keys.pubk.validate_signature(msg, &a));
+    assert!(keys.pubk.validate_signature(msg, &c));
+
+    let signed_msg = keys.full_signature_with_rng(msg, &mut StdRng::seed_from_u64(808));
+    assert_eq!(a, signed_msg.signature());
+    assert_eq!(Ok(msg.to_vec()), keys.pubk.decode_signature(&signed_msg));
+}
+
+#[test]
+fn deterministic() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let msg = "Lunes".as_bytes();
+
+    let signature = keys.sign_deterministic(msg);
+    assert_eq!(signature, keys.prvk.sign_deterministic(msg));
+    assert_ne!(signature, keys.sign_deterministic(b"Lunis"));
+    assert_ne!(signature, keys.fast_signature(msg));
+    assert!(keys.pubk.validate_signature(msg, &signature));
+}
+
+#[test]
+fn deterministic_known_answers() {
+    use crate::hex;
+    use ed25519_axolotl::crypto::keys::{PublicKey, SecretKey};
+    use ed25519_axolotl::crypto::signatures::Signature;
+
+    // secret key, public key, message, signature, as published in the docs of
+    // `SecretKey::sign_deterministic`
+    let vectors = [
+        (
+            "0101010101010101010101010101010101010101010101010101010101010101",
+            "a4e09292b651c278b9772c569f5fa9bb13d906b46ab68c9df9dc2b4409f8a209",
+            Vec::new(),
+            "ee6fd8fdaa4515d5e03b58eef1bbf783cb63afa33454506d6d868866dba9d7a3\
+             01c1851e0c03da401d31feab88898992ef1e1dbb0990d39c7c9af32cb1b54503",
+        ),
+        (
+            "0202020202020202020202020202020202020202020202020202020202020202",
+            "ce8d3ad1ccb633ec7b70c17814a5c76ecd029685050d344745ba05870e587d59",
+            b"Lunes".to_vec(),
+            "dca408aa6a6504b4fea3f40c3736dbe39b3299af82a089997401320a7f7e8bb4\
+             d88be5c7b6385d846e8f8eca0fe078c80dbe52004ce2d7621a84439ee06a1203",
+        ),
+        (
+            "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+            "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a",
+            b"abc".to_vec(),
+            "3efc66f3ff33b3eada1835585bb9e8367666bfb4bccb5d79ff02f9694c6ca037\
+             3bf276640c558ec4502e19960a9766d8e280a345c1635bb9f84760d96231478c",
+        ),
+        (
+            "4242424242424242424242424242424242424242424242424242424242424242",
+            "132c442be010fbd57e72603328aa76e71fccc1503aae219327d14d9c9993f472",
+            (0..=255).collect::<Vec<u8>>(),
+            "7c862ea797ce51b5ea2c592179c03360bb5c4642210401fdafb0640d6826a5e2\
+             d6486508c2fc96efa470416c3800c8f7d3c46266bacb9ef300c96403d7560503",
+        ),
+    ];
+
+    for (secret_key, public_key, message, signature) in vectors {
+        let secret_key = SecretKey::try_from(&hex(secret_key)[..]).unwrap();
+        let public_key = PublicKey::try_from(&hex(public_key)[..]).unwrap();
+        let signature = Signature::try_from(&hex(signature)[..]).unwrap();
+
+        assert_eq!(public_key, secret_key.public_key());
+        assert_eq!(signature, secret_key.sign_deterministic(&message));
+        assert!(public_key.validate_signature(&message, &signature));
+    }
 }
@@ -0,0 +1,63 @@
+use crate::sevens;
+use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+use ed25519_axolotl::crypto::signatures::Signature;
+use ed25519_axolotl::signature::{Keypair, RandomizedSigner, SignatureEncoding, Signer, Verifier};
+
+/// Code generic over any signature algorithm
+fn sign_and_verify<K, S>(keys: &K, message: &[u8]) -> (S, bool)
+where
+    K: Signer<S> + Keypair,
+    K::VerifyingKey: Verifier<S>,
+{
+    let signature = keys.sign(message);
+    let valid = keys.verifying_key().verify(message, &signature).is_ok();
+    (signature, valid)
+}
+
+#[test]
+fn signer() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+
+    let (signature, valid): (Signature, bool) = sign_and_verify(&keys, b"Lunes");
+    assert!(valid);
+    assert_eq!(keys.sign_deterministic(b"Lunes"), signature);
+    assert_eq!(signature, Signer::sign(&keys.prvk, b"Lunes"));
+    assert_eq!(keys.pubk, keys.verifying_key());
+}
+
+#[test]
+fn randomized_signer() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+
+    let signature: Signature = keys.sign_with_rng(&mut sevens(), b"Lunes");
+    assert_eq!(
+        keys.fast_signature_with_rng(b"Lunes", &mut sevens()),
+        signature
+    );
+    assert_eq!(signature, keys.prvk.sign_with_rng(&mut sevens(), b"Lunes"));
+    assert!(Verifier::verify(&keys.pubk, b"Lunes", &signature).is_ok());
+}
+
+#[test]
+fn verifier_rejects() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature: Signature = keys.sign(b"Lunes");
+
+    assert!(Verifier::verify(&keys.pubk, b"Lunis", &signature).is_err());
+    let other = PublicKey::from_bytes([9; 32]);
+    assert!(Verifier::verify(&other, b"Lunes", &signature).is_err());
+}
+
+#[test]
+fn encoding() {
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature: Signature = keys.sign(b"Lunes");
+
+    assert_eq!(
+        signature.as_bytes(),
+        &SignatureEncoding::to_bytes(&signature)
+    );
+    assert_eq!(signature.as_bytes().to_vec(), signature.to_vec());
+    assert_eq!(64, signature.encoded_len());
+    assert_eq!(Ok(signature), Signature::try_from(&signature.to_vec()[..]));
+}
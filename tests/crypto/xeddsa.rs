@@ -0,0 +1,235 @@
+// Produced by an independent Python implementation of the specification, the
+// XEdDSA signatures also verify as Ed25519 signatures with OpenSSL.
+// seed, message, xeddsa signature, vxeddsa signature, vxeddsa output
+const VECTORS: [(&str, &[u8], &str, &str, &str); 4] = [
+    (
+        "0101010101010101010101010101010101010101010101010101010101010101",
+        b"Lunes",
+        "9d67fe141defa1fb67943d34cc251b0d008428979b015c5239cd54f233644e67\
+         4c93e757ad18754ef1a98b9209354f70e975e6c1188aec0d0d0b52ac52c4c80f",
+        "c10cc62039f65defb112a28cabee37e01c2cd4b13fe37c800de7d972039c0455\
+         8e9ba6005de638811e550c86b13c91d3550dff6183875f5c02e0a19ee7a6ac04\
+         9cfff13fb3d20a33c648268e4d2fc38d7016c82d0156e95a8f0a73847f910b0a",
+        "7a5d4004391954db6711a1a87a3313e7f798346fd2250014ee54599cea0f3841",
+    ),
+    (
+        "0202020202020202020202020202020202020202020202020202020202020202",
+        b"",
+        "92b932a5680f9d57b32ac6d1dafcd1d908a81bc43d2f00367a19961fd2cdba7f\
+         b398aa726d1c19b402d2d308cdede325c6d2a5098239c1397c2650f90064480e",
+        "45eb2d2fc7c641bfd26b0b0b871abd0189670b24c627984ccfd80940e4347143\
+         9f4ab8fffdc15290e6c373a0d2f4545c3881a6919080a29b93c01899eb81fe04\
+         b1df9df5948177a17321970f78906270590ce913af8db1198dbd4da75e5cdf0a",
+        "2419315124df51975b14dbad7e1dd6bd6798d1537d23d781d9ff8c2029bbf6d2",
+    ),
+    (
+        // Edwards sign bit set, so the private key is negated.
+        "0303030303030303030303030303030303030303030303030303030303030303",
+        b"Lunes",
+        "d20b8a68e7fe3c28d48a06be4af4b6d2ab4b90ae79e6ddea37071a173d3389ef\
+         11b80cc0e277d8c6b3338eed07eb6a796034e799786233847de868421adb1c01",
+        "ff96338ba9cf567d6c78e2728ce4bb446295297ff6f1be265dafcf638fc588ea\
+         93a59e0ffb312eba5bddb3ed80371e185420c872a1d63ef649b7537a9c41ed01\
+         88ef92cbaef000e387ef112343c6905eb7dd4be8208b735067b43a3ae938c607",
+        "a89681e6fd9cfc128e9b04d1969e1cdb59fcf0e2517b6800ff983ef629a783c0",
+    ),
+    (
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        b"XEdDSA and VXEdDSA",
+        "162bd49a7935d72825628a6bc686aa818e320ab2283c2eef417e128f0a209538\
+         4a54756f2bd8b0d99bac0d7ee5bc4c93fe7c0ca58f69ce226bebf8337aa3e805",
+        "a0728b43d79f9a49484dca1e73dfa79be6dc67db9f20450b31552cb060b31e1f\
+         be838bc105fbc57e9158c6d6d22490eae0c7f679072b0569ea5338d7b96def0e\
+         5277df802bec94454b908fea47ad98a2ab4bf5b01c2653fdd73d5dfed98b190f",
+        "5dc81a018d68a4e0726019737f9e0486d93681d3dad693603a265e2422b85b64",
+    ),
+];
+
+#[test]
+fn xeddsa_known_answers() {
+    use crate::hex;
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::xeddsa::{xeddsa_sign_with_rng, xeddsa_verify};
+
+    for (seed, message, signature, _, _) in VECTORS {
+        let keys = KeyPair::try_from_seed(&hex(seed)).unwrap();
+        let signed = xeddsa_sign_with_rng(&keys.prvk, message, &mut crate::sevens());
+
+        assert_eq!(hex(signature), signed.as_bytes());
+        assert_eq!(Ok(()), xeddsa_verify(&keys.pubk, message, &signed));
+    }
+}
+
+#[test]
+fn vxeddsa_known_answers() {
+    use crate::hex;
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::xeddsa::{vxeddsa_sign_with_rng, vxeddsa_verify};
+
+    for (seed, message, _, signature, output) in VECTORS {
+        let keys = KeyPair::try_from_seed(&hex(seed)).unwrap();
+        let (signed, vrf) = vxeddsa_sign_with_rng(&keys.prvk, message, &mut crate::sevens());
+
+        assert_eq!(hex(signature), signed.as_bytes());
+        assert_eq!(hex(output), vrf.as_bytes());
+        assert_eq!(Ok(vrf), vxeddsa_verify(&keys.pubk, message, &signed));
+    }
+}
+
+#[test]
+fn xeddsa_interoperates() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::xeddsa::{xeddsa_sign, xeddsa_verify};
+
+    for seed in [[1; 32], [3; 32]] {
+        let keys = KeyPair::from_seed(&seed);
+
+        // The older signatures carry the sign bit, as libsignal still accepts.
+        let signature = keys.fast_signature(b"Lunes");
+        assert_eq!(Ok(()), xeddsa_verify(&keys.pubk, b"Lunes", &signature));
+
+        let signature = xeddsa_sign(&keys.prvk, b"Lunes");
+        assert_eq!(Ok(()), keys.pubk.verify(b"Lunes", &signature));
+    }
+}
+
+#[test]
+fn xeddsa_rejected() {
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+    use ed25519_axolotl::crypto::signatures::Signature;
+    use ed25519_axolotl::crypto::xeddsa::{xeddsa_sign, xeddsa_verify};
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let other = KeyPair::from_seed(&[2; 32]);
+    let signature = xeddsa_sign(&keys.prvk, b"Lunes");
+
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        xeddsa_verify(&keys.pubk, b"Lunis", &signature)
+    );
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        xeddsa_verify(&other.pubk, b"Lunes", &signature)
+    );
+
+    let mut bytes = signature.to_bytes();
+    bytes[40] ^= 1;
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        xeddsa_verify(&keys.pubk, b"Lunes", &Signature::from_bytes(bytes))
+    );
+
+    // s at or above 2^253
+    let mut bytes = signature.to_bytes();
+    bytes[63] |= 0x20;
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        xeddsa_verify(&keys.pubk, b"Lunes", &Signature::from_bytes(bytes))
+    );
+
+    // u = p - 1 and u = p are not reduced Montgomery keys
+    let mut u = [0xff; 32];
+    u[31] = 0x7f;
+    for low in [0xec, 0xed] {
+        u[0] = low;
+        assert_eq!(
+            Err(Error::InvalidPublicKey),
+            xeddsa_verify(&PublicKey::from_bytes(u), b"Lunes", &signature)
+        );
+    }
+}
+
+#[test]
+fn vxeddsa_output() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::crypto::xeddsa::{vxeddsa_sign, vxeddsa_verify};
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let (first, output) = vxeddsa_sign(&keys.prvk, b"Lunes");
+    let (second, same) = vxeddsa_sign(&keys.prvk, b"Lunes");
+    let (_, other) = vxeddsa_sign(&keys.prvk, b"Lunis");
+
+    assert_ne!(first, second);
+    assert_eq!(output, same);
+    assert_ne!(output, other);
+    assert_eq!(Ok(output), vxeddsa_verify(&keys.pubk, b"Lunes", &second));
+}
+
+#[test]
+fn vxeddsa_rejected() {
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+    use ed25519_axolotl::crypto::xeddsa::{vxeddsa_sign, vxeddsa_verify, VrfSignature};
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let other = KeyPair::from_seed(&[2; 32]);
+    let (signature, _) = vxeddsa_sign(&keys.prvk, b"Lunes");
+
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        vxeddsa_verify(&keys.pubk, b"Lunis", &signature)
+    );
+    assert_eq!(
+        Err(Error::InvalidSignature),
+        vxeddsa_verify(&other.pubk, b"Lunes", &signature)
+    );
+
+    for index in [0, 40, 70] {
+        let mut bytes = signature.to_bytes();
+        bytes[index] ^= 2;
+        assert_eq!(
+            Err(Error::InvalidSignature),
+            vxeddsa_verify(&keys.pubk, b"Lunes", &VrfSignature::from_bytes(bytes))
+        );
+    }
+
+    assert_eq!(
+        Err(Error::LowOrderPoint),
+        vxeddsa_verify(&PublicKey::from_bytes([0; 32]), b"Lunes", &signature)
+    );
+    assert_eq!(
+        Some(Error::InvalidLength {
+            expected: 96,
+            actual: 64
+        }),
+        VrfSignature::try_from(&[0u8; 64][..]).err()
+    );
+}
+
+#[test]
+fn libsignal_vector() {
+    use ed25519_axolotl::crypto::keys::{PublicKey, SecretKey};
+    use ed25519_axolotl::crypto::signatures::Signature;
+    use ed25519_axolotl::crypto::xeddsa::xeddsa_verify;
+
+    // `testSignature` of the Curve25519 tests of libsignal: Alice signs her
+    // ephemeral key, 0x05 type byte included, with her identity key.
+    let private: [u8; 32] =
+        crate::hex("c097248412e58bf05df487968205132794178e367637f5818f81e0e6ce73e865")
+            .try_into()
+            .unwrap();
+    let public: [u8; 32] =
+        crate::hex("ab7e717d4a163b7d9a1d8071dfe9dcf8cdcd1cea3339b6356be84d887e322c64")
+            .try_into()
+            .unwrap();
+    let ephemeral =
+        crate::hex("05edce9d9c415ca78cb7252e72c2c4a554d3eb29485a0e1d503118d1a82d99fb4a");
+    let signature: [u8; 64] = crate::hex(
+        "5de88ca9a89b4a115da79109c67c9c7464a3e4180274f1cb8c63c2984e286dfb\
+         ede82deb9dcd9fae0bfbb821569b3d9001bd8130cd11d486cef047bd60b86e88",
+    )
+    .try_into()
+    .unwrap();
+
+    let public = PublicKey::from_bytes(public);
+    assert_eq!(public, SecretKey::from_bytes(private).public_key());
+
+    let signature = Signature::from_bytes(signature);
+    assert_eq!(Ok(()), xeddsa_verify(&public, &ephemeral, &signature));
+
+    let mut flipped = *signature.as_bytes();
+    flipped[63] ^= 0x80;
+    assert!(xeddsa_verify(&public, &ephemeral, &Signature::from_bytes(flipped)).is_err());
+    assert!(xeddsa_verify(&public, &ephemeral[1..], &signature).is_err());
+}
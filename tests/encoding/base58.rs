@@ -0,0 +1,120 @@
+#[test]
+fn bitcoin_vectors() {
+    use crate::hex;
+    use ed25519_axolotl::encoding::base58;
+
+    // base58_encode_decode.json of Bitcoin Core.
+    let vectors = [
+        ("", ""),
+        ("61", "2g"),
+        ("626262", "a3gV"),
+        ("636363", "aPEr"),
+        (
+            "73696d706c792061206c6f6e6720737472696e67",
+            "2cFupjhnEsSn59qHXstmK2ffpLv2",
+        ),
+        (
+            "00eb15231dfceb60925886b67d065299925915aeb172c06647",
+            "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+        ),
+        ("516b6fcd0f", "ABnLTmg"),
+        ("bf4f89001e670274dd", "3SEo3LWLoPntC"),
+        ("572e4794", "3EFU7m"),
+        ("ecac89cad93923c02321", "EJDM8drfXA6uyA"),
+        ("10c8511e", "Rt5zm"),
+        ("00000000000000000000", "1111111111"),
+    ];
+
+    for (bytes, text) in vectors {
+        assert_eq!(text, base58::encode(&hex(bytes)));
+        assert_eq!(Ok(hex(bytes)), base58::decode(text));
+    }
+}
+
+#[test]
+fn rejects_characters_outside_alphabet() {
+    use ed25519_axolotl::encoding::base58;
+    use ed25519_axolotl::Error;
+
+    for (index, character) in [(0, '0'), (3, 'O'), (1, 'I'), (5, 'l'), (2, ' '), (4, 'é')] {
+        let mut text = String::from("2cFupjhnEs");
+        text.replace_range(index..index + 1, &character.to_string());
+        assert_eq!(
+            Err(Error::InvalidCharacter { index, character }),
+            base58::decode(&text)
+        );
+    }
+}
+
+#[test]
+fn keys_and_signatures_round_trip() {
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey, SecretKey};
+    use ed25519_axolotl::crypto::signatures::Signature;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let signature = keys.sign_deterministic(b"Lunes");
+
+    let text = keys.pubk.to_base58();
+    assert_eq!(text, keys.pubk.to_string());
+    assert_eq!(Ok(keys.pubk), PublicKey::from_base58(&text));
+    assert_eq!(Ok(keys.pubk), text.parse());
+
+    let text = signature.to_base58();
+    assert_eq!(text, signature.to_string());
+    assert_eq!(Ok(signature), Signature::from_base58(&text));
+    assert_eq!(Ok(signature), text.parse());
+
+    let text = keys.prvk.to_base58();
+    assert_eq!(Ok(keys.prvk.clone()), SecretKey::from_base58(&text));
+    assert_eq!(Ok(keys.prvk.clone()), text.parse());
+    assert_eq!("SecretKey(<redacted>)", keys.prvk.to_string());
+    assert!(!format!("{}{:?}", keys.prvk, keys.prvk).contains(&text));
+}
+
+#[test]
+fn known_keys() {
+    use ed25519_axolotl::crypto::keys::PublicKey;
+
+    let text = "JEKNVnkbo3jma5nREBBJCDoXFVeKkD56V3xKrvRmWxFG";
+    assert_eq!(Ok(PublicKey::from_bytes([255; 32])), text.parse());
+    assert_eq!(
+        "11111111111111111111111111111111",
+        PublicKey::from_bytes([0; 32]).to_string()
+    );
+}
+
+#[test]
+fn rejects_wrong_lengths() {
+    use ed25519_axolotl::crypto::keys::{PublicKey, SecretKey};
+    use ed25519_axolotl::crypto::signatures::Signature;
+    use ed25519_axolotl::encoding::base58;
+    use ed25519_axolotl::Error;
+
+    let short = base58::encode(&[7; 31]);
+    let long = base58::encode(&[7; 33]);
+    let error = |actual| {
+        Some(Error::InvalidLength {
+            expected: 32,
+            actual,
+        })
+    };
+
+    assert_eq!(error(31), PublicKey::from_base58(&short).err());
+    assert_eq!(error(33), PublicKey::from_base58(&long).err());
+    assert_eq!(error(0), "".parse::<PublicKey>().err());
+    assert_eq!(error(31), SecretKey::from_base58(&short).err());
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 64,
+            actual: 32
+        }),
+        Signature::from_base58(&base58::encode(&[7; 32]))
+    );
+    assert_eq!(
+        Err(Error::InvalidCharacter {
+            index: 0,
+            character: '0'
+        }),
+        "0".parse::<Signature>()
+    );
+}
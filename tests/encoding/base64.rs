@@ -0,0 +1,46 @@
+#[test]
+fn rfc4648_vectors() {
+    use ed25519_axolotl::encoding::base64;
+
+    // Section 10 of RFC 4648.
+    let vectors = [
+        ("", ""),
+        ("f", "Zg=="),
+        ("fo", "Zm8="),
+        ("foo", "Zm9v"),
+        ("foob", "Zm9vYg=="),
+        ("fooba", "Zm9vYmE="),
+        ("foobar", "Zm9vYmFy"),
+    ];
+
+    for (bytes, text) in vectors {
+        assert_eq!(text, base64::encode(bytes.as_bytes()));
+        assert_eq!(Ok(bytes.as_bytes().to_vec()), base64::decode(text));
+    }
+    assert_eq!("+/8=", base64::encode(&[251, 255]));
+    assert_eq!(Ok(vec![251, 255]), base64::decode("+/8="));
+}
+
+#[test]
+fn rejects_invalid_text() {
+    use ed25519_axolotl::encoding::base64;
+    use ed25519_axolotl::Error;
+
+    for text in ["Zg", "Zg=", "Zm9vY", "Z===", "Zh==", "Zm9=", "===="] {
+        assert_eq!(Err(Error::InvalidEncoding), base64::decode(text), "{text}");
+    }
+    assert_eq!(
+        Err(Error::InvalidCharacter {
+            index: 2,
+            character: '='
+        }),
+        base64::decode("Zm=v")
+    );
+    assert_eq!(
+        Err(Error::InvalidCharacter {
+            index: 1,
+            character: '_'
+        }),
+        base64::decode("Z_9v")
+    );
+}
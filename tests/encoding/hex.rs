@@ -0,0 +1,33 @@
+#[test]
+fn round_trip() {
+    use ed25519_axolotl::encoding::hex;
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    let text = hex::encode(&bytes);
+    assert_eq!(512, text.len());
+    assert!(text.starts_with("000102") && text.ends_with("fdfeff"));
+    assert_eq!(Ok(bytes.clone()), hex::decode(&text));
+    assert_eq!(Ok(bytes), hex::decode(&text.to_uppercase()));
+}
+
+#[test]
+fn rejects_invalid_text() {
+    use ed25519_axolotl::encoding::hex;
+    use ed25519_axolotl::Error;
+
+    assert_eq!(Err(Error::InvalidEncoding), hex::decode("abc"));
+    assert_eq!(
+        Err(Error::InvalidCharacter {
+            index: 2,
+            character: 'g'
+        }),
+        hex::decode("abgd")
+    );
+    assert_eq!(
+        Err(Error::InvalidCharacter {
+            index: 0,
+            character: '+'
+        }),
+        hex::decode("+1")
+    );
+}
@@ -0,0 +1,3 @@
+mod base58;
+mod base64;
+mod hex;
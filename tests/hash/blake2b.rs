@@ -0,0 +1,71 @@
+#[test]
+fn known_vectors() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Blake2b256;
+
+    // Unkeyed BLAKE2b with a 32 byte digest, as Python `hashlib.blake2b` computes it.
+    let vectors: [(&[u8], &str); 3] = [
+        (
+            b"",
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+        ),
+        (
+            b"abc",
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+        ),
+        (
+            b"The quick brown fox jumps over the lazy dog",
+            "01718cec35cd3d796dd00020e0bfecb473ad23457d063b75eff29c0ffa2e58a9",
+        ),
+    ];
+
+    for (message, digest) in vectors {
+        assert_eq!(hex(digest), Blake2b256::digest(message));
+    }
+}
+
+#[test]
+fn million_a() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Blake2b256;
+
+    let mut hasher = Blake2b256::new();
+    for _ in 0..1000 {
+        hasher.update(&[b'a'; 1000]);
+    }
+
+    assert_eq!(
+        hex("0741850f36cba4259628355d1073e24ddb9ca0e1bfac36fd39ae5dc2101e23a4"),
+        hasher.finalize()
+    );
+}
+
+#[test]
+fn streaming() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Blake2b256;
+
+    let message: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+    let digest = Blake2b256::digest(&message);
+    assert_eq!(
+        hex("b372d0608f720c8c3dd41e9c8eecb10143b41abe520b616607e754bf79c08331"),
+        digest
+    );
+
+    // Pieces falling on, before and after the 128 byte block boundaries.
+    for size in [1, 7, 127, 128, 129, 256, 500] {
+        let mut hasher = Blake2b256::default();
+        for piece in message.chunks(size) {
+            hasher.update(piece);
+        }
+        assert_eq!(digest, hasher.finalize());
+    }
+
+    // A message ending on a block boundary keeps its last block for finalize.
+    for len in [0, 127, 128, 129, 256] {
+        let mut hasher = Blake2b256::new();
+        hasher.update(&message[..len]);
+        hasher.update(&[]);
+        assert_eq!(Blake2b256::digest(&message[..len]), hasher.finalize());
+    }
+}
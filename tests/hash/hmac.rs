@@ -0,0 +1,112 @@
+#[test]
+fn rfc4231_vectors() {
+    use crate::hex;
+    use ed25519_axolotl::hash::HmacSha512;
+
+    // Test cases 1 to 4, 6 and 7 of RFC 4231, case 5 truncates the tag.
+    let vectors = [
+        (
+            vec![0x0b; 20],
+            b"Hi There".to_vec(),
+            "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cde\
+             daa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854",
+        ),
+        (
+            b"Jefe".to_vec(),
+            b"what do ya want for nothing?".to_vec(),
+            "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554\
+             9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+        ),
+        (
+            vec![0xaa; 20],
+            vec![0xdd; 50],
+            "fa73b0089d56a284efb0f0756c890be9b1b5dbdd8ee81a3655f83e33b2279d39\
+             bf3e848279a722c806b485a47e67c807b946a337bee8942674278859e13292fb",
+        ),
+        (
+            hex("0102030405060708090a0b0c0d0e0f10111213141516171819"),
+            vec![0xcd; 50],
+            "b0ba465637458c6990e5a8c5f61d4af7e576d97ff94b872de76f8050361ee3db\
+             a91ca5c11aa25eb4d679275cc5788063a5f19741120c4f2de2adebeb10a298dd",
+        ),
+        (
+            vec![0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec(),
+            "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f352\
+             6b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598",
+        ),
+        (
+            vec![0xaa; 131],
+            b"This is a test using a larger than block-size key and a larger \
+              than block-size data. The key needs to be hashed before being \
+              used by the HMAC algorithm."
+                .to_vec(),
+            "e37b6a775dc87dbaa4dfa9f96e5e3ffddebd71f8867289865df5a32d20cdc944\
+             b6022cac3c4982b10d5eeb55c3e4de15134676fb6de0446065c97440fa8c6a58",
+        ),
+    ];
+
+    for (key, message, tag) in vectors {
+        assert_eq!(hex(tag), HmacSha512::mac(&key, &message));
+
+        let mut mac = HmacSha512::new(&key);
+        for piece in message.chunks(7) {
+            mac.update(piece);
+        }
+        assert_eq!(hex(tag), mac.finalize());
+    }
+}
+
+#[test]
+fn pbkdf2_vectors() {
+    use crate::hex;
+    use ed25519_axolotl::hash::pbkdf2::pbkdf2_sha512;
+
+    // PBKDF2-HMAC-SHA-512 as Python `hashlib.pbkdf2_hmac` computes it, the
+    // last one spans two blocks.
+    let vectors: [(&[u8], &[u8], u32, &str); 5] = [
+        (
+            b"password",
+            b"salt",
+            1,
+            "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252\
+             c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce",
+        ),
+        (
+            b"password",
+            b"salt",
+            2,
+            "e1d9c16aa681708a45f5c7c4e215ceb66e011a2e9f0040713f18aefdb866d53c\
+             f76cab2868a39b9f7840edce4fef5a82be67335c77a6068e04112754f27ccf4e",
+        ),
+        (
+            b"password",
+            b"salt",
+            4096,
+            "d197b1b33db0143e018b12f3d1d1479e6cdebdcc97c5c0f87f6902e072f457b5\
+             143f30602641b3d55cd335988cb36b84376060ecd532e039b742a239434af2d5",
+        ),
+        (
+            b"passwordPASSWORDpassword",
+            b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+            4096,
+            "8c0511f4c6e597c6ac6315d8f0362e225f3c501495ba23b868c005174dc4ee71\
+             115b59f9e60cd9532fa33e0f75aefe30225c583a186cd82bd4daea9724a3d3b8",
+        ),
+        (
+            b"password",
+            b"salt",
+            2,
+            "e1d9c16aa681708a45f5c7c4e215ceb66e011a2e9f0040713f18aefdb866d53c\
+             f76cab2868a39b9f7840edce4fef5a82be67335c77a6068e04112754f27ccf4e\
+             473e311ad827b68945f4e2dddb204c78e40e2495141e411cd272d020640d673c\
+             d34aa29f",
+        ),
+    ];
+
+    for (password, salt, rounds, key) in vectors {
+        let mut out = vec![0u8; key.len() / 2];
+        pbkdf2_sha512(password, salt, rounds, &mut out);
+        assert_eq!(hex(key), out);
+    }
+}
@@ -0,0 +1,47 @@
+#[test]
+fn known_vectors() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Keccak256;
+
+    // Keccak-256 with the original padding, as Ethereum uses it.
+    let vectors: [(&[u8], &str); 3] = [
+        (
+            b"",
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ),
+        (
+            b"abc",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        ),
+        (
+            b"The quick brown fox jumps over the lazy dog",
+            "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15",
+        ),
+    ];
+
+    for (message, digest) in vectors {
+        assert_eq!(hex(digest), Keccak256::digest(message));
+    }
+}
+
+#[test]
+fn streaming() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Keccak256;
+
+    let message: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+    let digest = Keccak256::digest(&message);
+    assert_eq!(
+        hex("af692982e84a5a9688359025660a7857cd28ee7c8d867cfa1677baf2e6d1f63b"),
+        digest
+    );
+
+    // Pieces falling on, before and after the 136 byte rate boundaries.
+    for size in [1, 7, 135, 136, 137, 272, 500] {
+        let mut hasher = Keccak256::default();
+        for piece in message.chunks(size) {
+            hasher.update(piece);
+        }
+        assert_eq!(digest, hasher.finalize());
+    }
+}
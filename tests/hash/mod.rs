@@ -0,0 +1,5 @@
+mod blake2b;
+mod hmac;
+mod keccak256;
+mod sha256;
+mod sha512;
@@ -0,0 +1,69 @@
+#[test]
+fn nist_vectors() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Sha256;
+
+    // FIPS 180-4 examples of the NIST Cryptographic Standards and Guidelines.
+    let vectors: [(&[u8], &str); 3] = [
+        (
+            b"abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            b"",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+    ];
+
+    for (message, digest) in vectors {
+        assert_eq!(hex(digest), Sha256::digest(message));
+    }
+}
+
+#[test]
+fn million_a() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Sha256;
+
+    let mut hasher = Sha256::new();
+    for _ in 0..1000 {
+        hasher.update(&[b'a'; 1000]);
+    }
+
+    assert_eq!(
+        hex("cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"),
+        hasher.finalize()
+    );
+}
+
+#[test]
+fn streaming() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Sha256;
+
+    let message: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+    let digest = Sha256::digest(&message);
+    assert_eq!(
+        hex("4e4c294b331f7a2099a379bec34b9f9fc03dc46ab465d998f4d683da53487e6d"),
+        digest
+    );
+
+    // Pieces falling on, before and after the 64 byte block boundaries.
+    for size in [1, 7, 55, 56, 63, 64, 65, 500] {
+        let mut hasher = Sha256::default();
+        for piece in message.chunks(size) {
+            hasher.update(piece);
+        }
+        assert_eq!(digest, hasher.finalize());
+    }
+
+    for len in 50..=70 {
+        let mut hasher = Sha256::new();
+        hasher.update(&message[..len]);
+        assert_eq!(Sha256::digest(&message[..len]), hasher.clone().finalize());
+    }
+}
@@ -0,0 +1,105 @@
+#[test]
+fn nist_vectors() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Sha512;
+
+    // FIPS 180-4 examples of the NIST Cryptographic Standards and Guidelines.
+    let vectors: [(&[u8], &str); 4] = [
+        (
+            b"abc",
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        ),
+        (
+            b"",
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+             47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+        ),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "204a8fc6dda82f0a0ced7beb8e08a41657c16ef468b228a8279be331a703c335\
+             96fd15c13b1b07f9aa1d3bea57789ca031ad85c7a71dd70354ec631238ca3445",
+        ),
+        (
+            b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmn\
+              hijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+            "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018\
+             501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909",
+        ),
+    ];
+
+    for (message, digest) in vectors {
+        assert_eq!(hex(digest), Sha512::digest(message));
+    }
+}
+
+#[test]
+fn million_a() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Sha512;
+
+    let mut hasher = Sha512::new();
+    for _ in 0..1000 {
+        hasher.update(&[b'a'; 1000]);
+    }
+
+    assert_eq!(
+        hex(
+            "e718483d0ce769644e2e42c7bc15b4638e1f98b13b2044285632a803afa973eb\
+             de0ff244877ea60a4cb0432ce577c31beb009c5c2c49aa2e4eadb217ad8cc09b"
+        ),
+        hasher.finalize()
+    );
+}
+
+#[test]
+fn streaming() {
+    use crate::hex;
+    use ed25519_axolotl::hash::Sha512;
+
+    let message: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+    let digest = Sha512::digest(&message);
+    assert_eq!(
+        hex(
+            "5096498d96f50f9a137c4db5b8b0cd38383ad55350fb5a98805fedc31fa1262f\
+             1f0cf4d6f12d7ecd8dedd933a4c9126344fe22e937a8ad35fdeae1e876ae698b"
+        ),
+        digest
+    );
+
+    // Pieces falling on, before and after the 128 byte block boundaries.
+    for size in [1, 7, 111, 112, 127, 128, 129, 500] {
+        let mut hasher = Sha512::default();
+        for piece in message.chunks(size) {
+            hasher.update(piece);
+        }
+        assert_eq!(digest, hasher.finalize());
+    }
+
+    for len in 110..=130 {
+        let mut hasher = Sha512::new();
+        hasher.update(&message[..len]);
+        assert_eq!(Sha512::digest(&message[..len]), hasher.clone().finalize());
+    }
+}
+
+#[test]
+fn io_write() {
+    use ed25519_axolotl::hash::Sha512;
+
+    let message: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+    let mut hasher = Sha512::new();
+    std::io::copy(&mut &message[..], &mut hasher).unwrap();
+
+    assert_eq!(Sha512::digest(&message), hasher.finalize());
+}
+
+#[test]
+fn redacted() {
+    use ed25519_axolotl::hash::Sha512;
+
+    let mut hasher = Sha512::new();
+    hasher.update(b"secret scalar");
+    assert_eq!("Sha512(<redacted>)", format!("{hasher:?}"));
+    assert_eq!(Sha512::digest(b"secret scalar"), hasher.clone().finalize());
+}
@@ -0,0 +1,2 @@
+mod path;
+mod slip10;
@@ -0,0 +1,53 @@
+#[test]
+fn parse_and_print() {
+    use ed25519_axolotl::hd::path::{DerivationPath, HARDENED};
+
+    let path: DerivationPath = "m/44'/808'/0'/0'".parse().unwrap();
+    assert_eq!(
+        [44 | HARDENED, 808 | HARDENED, HARDENED, HARDENED],
+        path.indices()
+    );
+    assert_eq!("m/44'/808'/0'/0'", path.to_string());
+    assert_eq!(Ok(path), "m/44h/808h/0h/0h".parse());
+
+    let root: DerivationPath = "m".parse().unwrap();
+    assert!(root.indices().is_empty());
+    assert_eq!(DerivationPath::default(), root);
+
+    let last: DerivationPath = "m/2147483647'".parse().unwrap();
+    assert_eq!([u32::MAX], last.indices());
+    assert_eq!(Ok(last), DerivationPath::new(&[u32::MAX]));
+}
+
+#[test]
+fn rejects_invalid_paths() {
+    use ed25519_axolotl::hd::path::DerivationPath;
+    use ed25519_axolotl::Error;
+
+    for text in [
+        "",
+        "M/0'",
+        "/0'",
+        "m/",
+        "m//0'",
+        "m/'",
+        "m/-1'",
+        "m/+1'",
+        "m/0x1'",
+        "m/1''",
+        "m/2147483648'",
+        "m/99999999999'",
+        "m/44'/808' ",
+    ] {
+        assert_eq!(
+            Err(Error::InvalidDerivationPath),
+            text.parse::<DerivationPath>(),
+            "{text}"
+        );
+    }
+
+    assert_eq!(
+        Err(Error::NonHardenedIndex { index: 0 }),
+        "m/44'/808'/0'/0".parse::<DerivationPath>()
+    );
+}
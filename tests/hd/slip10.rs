@@ -0,0 +1,173 @@
+#[test]
+fn curve25519_vectors() {
+    use crate::hex;
+    use ed25519_axolotl::hd::ExtendedKey;
+
+    // Path, chain code, secret and public key of the two test vectors for Curve25519
+    // published in SLIP-0010, without the 00 byte the specification puts before keys.
+    let trees = [
+        (
+            "000102030405060708090a0b0c0d0e0f",
+            [
+                (
+                    "m",
+                    "77997ca3588a1a34f3589279ea2962247abfe5277d52770a44c706378c710768",
+                    "d70a59c2e68b836cc4bbe8bcae425169b9e2384f3905091e3d60b890e90cd92c",
+                    "5c7289dc9f7f3ea1c8c2de7323b9fb0781f69c9ecd6de4f095ac89a02dc80577",
+                ),
+                (
+                    "m/0'",
+                    "349a3973aad771c628bf1f1b4d5e071f18eff2e492e4aa7972a7e43895d6597f",
+                    "cd7630d7513cbe80515f7317cdb9a47ad4a56b63c3f1dc29583ab8d4cc25a9b2",
+                    "cb8be6b256ce509008b43ae0dccd69960ad4f7ff2e2868c1fbc9e19ec3ad544b",
+                ),
+                (
+                    "m/0'/1'",
+                    "2ee5ba14faf2fe9d7ab532451c2be3a0a5375c5e8c44fb31d9ad7edc25cda000",
+                    "a95f97cfc1a61dd833b882c89d36a78a030ea6b2fbe3ae2a70e4f1fc9008d6b1",
+                    "e9506455dce2526df42e5e4eb5585eaef712e5f9c6a28bf9fb175d96595ea872",
+                ),
+                (
+                    "m/0'/1'/2'",
+                    "e1897d5a96459ce2a3d294cb2a6a59050ee61255818c50e03ac4263ef17af084",
+                    "3d6cce04a9175929da907a90b02176077b9ae050dcef9b959fed978bb2200cdc",
+                    "18f008fcbc6d1cd8b4fe7a9eba00f6570a9da02a9b0005028cb2731b12ee4118",
+                ),
+                (
+                    "m/0'/1'/2'/2'",
+                    "1cccc84e2737cfe81b51fbe4c97bbdb000f6a76eddffb9ed03108fbff3ff7e4f",
+                    "7ae7437efe0a3018999e6f00d72e810ebc50578dbf6728bfa1c7fe73501081a7",
+                    "512e288a8ef4d869620dc4b06bb06ad2524b350dee5a39fcfeb708dbac65c25c",
+                ),
+                (
+                    "m/0'/1'/2'/2'/1000000000'",
+                    "8ccf15d55b1dda246b0c1bf3e979a471a82524c1bd0c1eaecccf00dde72168bb",
+                    "7a59954d387abde3bc703f531f67d659ec2b8a12597ae82824547d7e27991e26",
+                    "a077fcf5af53d210257d44a86eb2031233ac7237da220434ac01a0bebccc1919",
+                ),
+            ],
+        ),
+        (
+            "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a2\
+             9f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+            [
+                (
+                    "m",
+                    "b62c0c81a80a0ee16b977abb3677eb47549d0eef090f7a6c2b2010e739875e34",
+                    "088491f5b4dfafbe956de471f3db10e02d784bc76050ee3b7c3f11b9706d3730",
+                    "60cc3b40567729af08757e1efe62536dc864a57ec582f98b96f484201a260c7a",
+                ),
+                (
+                    "m/0'",
+                    "341f386e571229e8adc52b82e824532817a31a35ba49ae334424e7228d020eed",
+                    "8e73218a1ba5c7b95e94b6e7cf7b37fb6240fb3b2ecd801402a4439da7067ee2",
+                    "7992b3f270ef15f266785fffb73246ad7f40d1fe8679b737fed0970d92cc5f39",
+                ),
+                (
+                    "m/0'/2147483647'",
+                    "942cbec088b4ae92e8db9336025e9185fec0985a3da89d7a408bc2a4e18a8134",
+                    "29262b215c961bae20274588b33955c36f265c1f626df9feebb51034ce63c19d",
+                    "2372feac417c38b833e1aba75f2420278122d698605b995cafc2fed7bb453d41",
+                ),
+                (
+                    "m/0'/2147483647'/1'",
+                    "fe02397ae2ca71efe455f470fb23928baf026360a9e9090e21958f6fba9efc30",
+                    "a4d2474bd98c5e9ff416f536697b89949627d6d2c384b81a86d29f1136f4c2d1",
+                    "eca4fd0458d3f729b6218eda871b350fa8870a744caf6d30cd84dad2b9dd9c2d",
+                ),
+                (
+                    "m/0'/2147483647'/1'/2147483646'",
+                    "b3b49d550e732ee629f4aeb4bf7213c3ae0f239fd10add513253cddbb8efb868",
+                    "d3500d9b30529c51d92497eded1d68d29f60c630c45c61a481c185e574c6e5cf",
+                    "edaa3d381a2b02f40a80d69b2ce7ba7c3c4a9421744808857cd48c50d29b5868",
+                ),
+                (
+                    "m/0'/2147483647'/1'/2147483646'/2'",
+                    "f6ded904046e9758b9388dbf95ea5db837ab98b03b00e4db7009a8e3ac077685",
+                    "e20fecd59312b63b37eee27714465aae1caa1c87840abd0d685ea88b3d598fdf",
+                    "aa705de68066e9534a238af35ea77c48016462a8aff358d22eaa6c7d5b034354",
+                ),
+            ],
+        ),
+    ];
+
+    for (seed, nodes) in trees {
+        let master = ExtendedKey::from_seed(&hex(seed));
+        let mut node = master.clone();
+        for (i, (path, chain_code, secret, public_key)) in nodes.into_iter().enumerate() {
+            if i > 0 {
+                let index = path.rsplit('/').next().unwrap().trim_end_matches('\'');
+                node = node
+                    .derive_child(index.parse::<u32>().unwrap() | 1 << 31)
+                    .unwrap();
+            }
+            assert_eq!(hex(chain_code), node.chain_code());
+            assert_eq!(hex(secret), node.expose_secret());
+            assert_eq!(hex(public_key), node.key_pair().pubk.as_bytes());
+
+            let derived = master.derive(&path.parse().unwrap());
+            assert_eq!(node.expose_secret(), derived.expose_secret());
+            assert_eq!(node.chain_code(), derived.chain_code());
+        }
+    }
+}
+
+#[test]
+fn ed25519_vector() {
+    use crate::hex;
+    use ed25519_axolotl::hd::ExtendedKey;
+
+    // Secrets and chain codes of the first test vector of SLIP-0010 for Ed25519,
+    // which derives them as for Curve25519 from another HMAC key.
+    let master = ExtendedKey::from_seed_with_curve(
+        &hex("000102030405060708090a0b0c0d0e0f"),
+        b"ed25519 seed",
+    );
+    assert_eq!(
+        hex("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"),
+        master.expose_secret()
+    );
+
+    let node = master.derive(&"m/0'/1'/2'/2'/1000000000'".parse().unwrap());
+    assert_eq!(
+        hex("68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230"),
+        node.chain_code()
+    );
+    assert_eq!(
+        hex("8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793"),
+        node.expose_secret()
+    );
+}
+
+#[test]
+fn lunes_accounts() {
+    use crate::hex;
+    use ed25519_axolotl::hd::path::HARDENED;
+    use ed25519_axolotl::hd::ExtendedKey;
+    use ed25519_axolotl::Error;
+
+    let master = ExtendedKey::from_seed(&hex("000102030405060708090a0b0c0d0e0f"));
+    let keys = master
+        .derive(&"m/44'/808'/0'/0'".parse().unwrap())
+        .key_pair();
+    assert_eq!(
+        hex("b1b4428184a19abaded8ff4a1e72ad14922991d3414c67f432322729a217721a"),
+        keys.pubk.as_bytes()
+    );
+
+    let accounts = master.derive(&"m/44'/808'".parse().unwrap());
+    let mut keys = Vec::new();
+    for account in 0..16 {
+        let node = accounts.derive_child(account | HARDENED).unwrap();
+        keys.push(node.key_pair().pubk);
+    }
+    keys.sort_by_key(|key| key.to_bytes());
+    keys.dedup();
+    assert_eq!(16, keys.len());
+
+    assert_eq!(
+        Some(Error::NonHardenedIndex { index: 7 }),
+        accounts.derive_child(7).err()
+    );
+    assert_eq!("ExtendedKey(<redacted>)", format!("{master:?}"));
+}
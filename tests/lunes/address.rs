@@ -0,0 +1,169 @@
+#[test]
+fn known_accounts() {
+    use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey};
+    use ed25519_axolotl::lunes::address::{Address, ChainId};
+
+    // Public key, mainnet and testnet addresses of the keys of seeds [1; 32] and [2; 32].
+    // Computed with a separate Python model of the Waves address layout, they are not
+    // accounts read from the Lunes explorer.
+    let vectors = [
+        (
+            [1; 32],
+            "C6cUahgsea11j7dTmSFiNydnaPG9kg7yPFagXqerHGnC",
+            "37wyAB6exbMYK37nVypJoSPGdiJeey522mY",
+            "37YdZBzMftB5SDgeQxPyUxFzr5oPiHiTYcY",
+        ),
+        (
+            [2; 32],
+            "EuHtd9yCn95edmJqLKFeyXeoPw9nPzp1HTwdJEWEw9YL",
+            "382GhPrWyQdv6rofyDfy6orWHaTsegTCQq9",
+            "37cw6QkDghTTE3NXtCFdnKjEVwxchxDzRFq",
+        ),
+    ];
+
+    for (seed, public_key, mainnet, testnet) in vectors {
+        let keys = KeyPair::from_seed(&seed);
+        assert_eq!(Ok(keys.pubk), public_key.parse::<PublicKey>());
+
+        let address = Address::from_public_key(&keys.pubk, ChainId::Mainnet);
+        assert_eq!(mainnet, address.to_string());
+        assert_eq!(Ok(address), mainnet.parse());
+        assert_eq!(ChainId::Mainnet, address.chain_id());
+
+        let address = Address::from_public_key(&keys.pubk, ChainId::Testnet);
+        assert_eq!(testnet, address.to_base58());
+        assert_eq!(Ok(address), Address::from_base58(testnet));
+        assert_eq!(ChainId::Testnet, address.chain_id());
+    }
+}
+
+#[test]
+fn waves_documentation_account() {
+    use ed25519_axolotl::crypto::keys::PublicKey;
+    use ed25519_axolotl::encoding::base58;
+    use ed25519_axolotl::lunes::address::{Address, ChainId};
+    use ed25519_axolotl::Error;
+
+    // Public key and Waves mainnet address of the example account in "Cryptographic
+    // practical details" of the Waves protocol documentation (docs.waves.tech). Lunes
+    // keeps the layout with its own chain bytes, so the public key hash must match.
+    let public_key: PublicKey = "HBqhfdFASRQ5eBBpu2y6c6KKi1az6bMx8v1JxX4iW1Q8"
+        .parse()
+        .unwrap();
+    let waves = base58::decode("3PPbMwqLtwBGcJrTA5whqJfY95GqnNnFMDX").unwrap();
+
+    for chain in [ChainId::Mainnet, ChainId::Testnet] {
+        let address = Address::from_public_key(&public_key, chain);
+        assert_eq!(&waves[2..22], address.public_key_hash());
+    }
+    assert_eq!(
+        Err(Error::UnknownChainId { id: b'W' }),
+        Address::from_bytes(&waves)
+    );
+}
+
+#[test]
+fn layout() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::hash::{Blake2b256, Keccak256};
+    use ed25519_axolotl::lunes::address::{Address, ChainId};
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let address = Address::from_public_key(&keys.pubk, ChainId::Mainnet);
+    let bytes = address.to_bytes();
+
+    assert_eq!([1, b'1'], bytes[..2]);
+    let hash = Keccak256::digest(&Blake2b256::digest(keys.pubk.as_bytes()));
+    assert_eq!(&hash[..20], address.public_key_hash());
+    let checksum = Keccak256::digest(&Blake2b256::digest(&bytes[..22]));
+    assert_eq!(checksum[..4], bytes[22..]);
+    assert_eq!(Ok(address), Address::try_from(address.as_ref()));
+}
+
+#[test]
+fn rejects_invalid_addresses() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::lunes::address::{Address, ChainId};
+    use ed25519_axolotl::Error;
+
+    let keys = KeyPair::from_seed(&[1; 32]);
+    let bytes = Address::from_public_key(&keys.pubk, ChainId::Mainnet).to_bytes();
+
+    // Any flipped bit breaks the checksum.
+    for i in 2..26 {
+        let mut forged = bytes;
+        forged[i] ^= 1 << (i % 8);
+        assert_eq!(Err(Error::InvalidChecksum), Address::from_bytes(&forged));
+    }
+
+    let mut forged = bytes;
+    forged[0] = 2;
+    assert_eq!(Err(Error::InvalidEncoding), Address::from_bytes(&forged));
+
+    let mut forged = bytes;
+    forged[1] = b'W';
+    assert_eq!(
+        Err(Error::UnknownChainId { id: b'W' }),
+        Address::from_bytes(&forged)
+    );
+
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 26,
+            actual: 25
+        }),
+        Address::from_bytes(&bytes[..25])
+    );
+    assert_eq!(
+        Err(Error::InvalidCharacter {
+            index: 2,
+            character: 'l'
+        }),
+        "37lyAB6exbMYK37nVypJoSPGdiJeey522mY".parse::<Address>()
+    );
+    assert_eq!(
+        Err(Error::InvalidChecksum),
+        "37wyAB6exbMYK37nVypJoSPGdiJeey522mZ".parse::<Address>()
+    );
+}
+
+#[test]
+fn aliases() {
+    use ed25519_axolotl::lunes::address::{Alias, ChainId};
+    use ed25519_axolotl::Error;
+
+    let alias = Alias::new("lunes-pay", ChainId::Testnet).unwrap();
+    assert_eq!("lunes-pay", alias.name());
+    assert_eq!(ChainId::Testnet, alias.chain_id());
+    assert_eq!(b"\x02\x30\x00\x09lunes-pay".to_vec(), alias.to_bytes());
+    assert_eq!(Ok(alias.clone()), Alias::from_bytes(&alias.to_bytes()));
+    assert_eq!("alias:0:lunes-pay", alias.to_string());
+    assert_eq!(Ok(alias), "alias:0:lunes-pay".parse());
+
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 4,
+            actual: 3
+        }),
+        Alias::new("abc", ChainId::Mainnet)
+    );
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 30,
+            actual: 31
+        }),
+        Alias::new(&"a".repeat(31), ChainId::Mainnet)
+    );
+    assert_eq!(
+        Err(Error::InvalidCharacter {
+            index: 4,
+            character: ' '
+        }),
+        Alias::new("lune s", ChainId::Mainnet)
+    );
+    assert_eq!(
+        Err(Error::UnknownChainId { id: b'W' }),
+        "alias:W:lunes-pay".parse::<Alias>()
+    );
+    assert_eq!(Err(Error::InvalidEncoding), "lunes-pay".parse::<Alias>());
+}
@@ -0,0 +1,3 @@
+mod address;
+mod seed;
+mod tx;
@@ -0,0 +1,104 @@
+#[test]
+fn known_phrases() {
+    use crate::hex;
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::lunes::address::{Address, ChainId};
+    use ed25519_axolotl::lunes::seed::account_seed;
+
+    const PHRASE: &str = "uncle push human bus echo drastic garden joke sand warfare \
+                          sentence fossil title color combine";
+
+    // Phrase, nonce, account seed, public key and mainnet address. Computed with a
+    // separate Python model of the lunes-js derivation, not with the official wallet.
+    let vectors = [
+        (
+            PHRASE,
+            0,
+            "517bcecdd994744c59dc6766ceee23a36ae08b5137dc662d6953fef2155b28dc",
+            "4KxUVD9NtyRJjU3BCvPgJSttoJX7cb3DMdDTNucLN121",
+            "37rUVcv62Z64ATSqTPwiA8nYnQaFaWGYWMD",
+        ),
+        (
+            PHRASE,
+            1,
+            "5ffd584287d9164ec04195e4832a19cd868d8bed330829a599510042345e7dcf",
+            "E7DhFaQ1xmpDP3huL26Mo1ZnFC2HveS7jmSWRQYYwFNd",
+            "37zCKEeFktkS9KKJLYosE2YsnZH3i1VaThe",
+        ),
+        (
+            "",
+            0,
+            "27e30298080439658768e6f5f6510551fe23609bacd36bbc8e9017aea3592555",
+            "6XFxxaWniuZBpPX3xx4h2YnCGzKwL5xgpggMNAtU77by",
+            "37zLbenCkE7xsJ1fKgPdG7Ahyvoy2TgVNQW",
+        ),
+        (
+            "sênha çom acentos",
+            7,
+            "b54d4e6d568ba05a8e09b244b42a4be9b94955b8a2e22ea073b90756f8cb6f50",
+            "3EfpnLc161CRM41MgX5jX4HfkkDJny7GsGNDayfi4qFe",
+            "37oJ1ZMd2ANMyJcxeNg4sjQwzXvWQJW8Nxp",
+        ),
+        (
+            "lunes",
+            u32::MAX,
+            "a5ed2444d259a1294fee11da31d76144447d3d93c99b847ade74d19c24d9d0fa",
+            "9GHhpK6rsjdXxY5Fr1FpSo4zbaXirry9GgXM4v4DMaTF",
+            "386TMpRawNXjnWfa69bW9KzviRArVAZALjg",
+        ),
+    ];
+
+    for (phrase, nonce, seed, public_key, address) in vectors {
+        assert_eq!(hex(seed), account_seed(phrase, nonce));
+
+        let keys = KeyPair::from_lunes_seed_phrase(phrase, nonce);
+        assert_eq!(public_key, keys.pubk.to_base58());
+        assert_eq!(
+            address,
+            Address::from_public_key(&keys.pubk, ChainId::Mainnet).to_string()
+        );
+    }
+}
+
+#[test]
+fn waves_documentation_phrase() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::encoding::base58;
+    use ed25519_axolotl::lunes::seed::account_seed;
+
+    // Example account of "Cryptographic practical details" in the Waves protocol
+    // documentation (docs.waves.tech), whose derivation the Lunes wallet kept: phrase,
+    // account seed of nonce 0, secret and public keys, all in Base58.
+    let phrase = "manage manual recall harvest series desert melt police rose hollow \
+                  moral pledge kitten position add";
+
+    assert_eq!(
+        "H4do9ZcPUASvtFJHvESapnxfmQ8tjBXMU7NtUARk9Jrf",
+        base58::encode(&account_seed(phrase, 0))
+    );
+
+    let keys = KeyPair::from_lunes_seed_phrase(phrase, 0);
+    assert_eq!(
+        "3kMEhU5z3v8bmer1ERFUUhW58Dtuhyo9hE5vrhjqAWYT",
+        keys.prvk.to_base58()
+    );
+    assert_eq!(
+        "HBqhfdFASRQ5eBBpu2y6c6KKi1az6bMx8v1JxX4iW1Q8",
+        keys.pubk.to_base58()
+    );
+}
+
+#[test]
+fn composes_the_hashes() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::hash::{Blake2b256, Keccak256, Sha256};
+
+    let phrase = "lunes";
+    let mut data = 3u32.to_be_bytes().to_vec();
+    data.extend_from_slice(phrase.as_bytes());
+
+    let seed = Keccak256::digest(&Blake2b256::digest(&data));
+    let keys = KeyPair::from_seed(&Sha256::digest(&seed));
+    assert_eq!(keys.pubk, KeyPair::from_lunes_seed_phrase(phrase, 3).pubk);
+    assert_eq!(keys.prvk, KeyPair::from_lunes_seed_phrase(phrase, 3).prvk);
+}
@@ -0,0 +1,372 @@
+use ed25519_axolotl::crypto::keys::KeyPair;
+use ed25519_axolotl::lunes::address::{Address, Alias, ChainId};
+use ed25519_axolotl::lunes::tx::*;
+
+const TIMESTAMP: u64 = 1_650_000_000_000;
+
+fn keys() -> KeyPair {
+    KeyPair::from_seed(&[1; 32])
+}
+
+fn address() -> Recipient {
+    let address: Address = "37YdZBzMftB5SDgeQxPyUxFzr5oPiHiTYcY".parse().unwrap();
+    Recipient::Address(address)
+}
+
+fn alias() -> Alias {
+    Alias::new("lunes-pay", ChainId::Mainnet).unwrap()
+}
+
+/// One transaction of every type and at least one version, with its body length and id
+///
+/// The bodies and ids come from a separate Python serializer of the Waves layouts,
+/// they are not mainnet or testnet transactions.
+fn transactions() -> Vec<(Transaction, usize, &'static str)> {
+    let sender = keys().pubk;
+    let asset: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let issue = |version| Issue {
+        version,
+        chain: ChainId::Mainnet,
+        sender,
+        name: "Token".into(),
+        description: "A test token".into(),
+        quantity: 1_000_000_000_000,
+        decimals: 8,
+        reissuable: true,
+        fee: 100_000_000,
+        timestamp: TIMESTAMP,
+    };
+    let lease = |version| Lease {
+        version,
+        sender,
+        recipient: address(),
+        amount: 1_000_000_000,
+        fee: 1_000_000,
+        timestamp: TIMESTAMP,
+    };
+    let cancel = |version| CancelLease {
+        version,
+        chain: ChainId::Mainnet,
+        sender,
+        fee: 1_000_000,
+        timestamp: TIMESTAMP,
+        lease: [7; 32],
+    };
+
+    vec![
+        (
+            Transfer {
+                version: 1,
+                sender,
+                asset: None,
+                fee_asset: None,
+                timestamp: TIMESTAMP,
+                amount: 100_000_000,
+                fee: 1_000_000,
+                recipient: address(),
+                attachment: b"Lunes".to_vec(),
+            }
+            .into(),
+            92,
+            "Hf8aBLo8cM3qJ4J5vYEgEKF4JDSmPCMDjrPkrUp2f63h",
+        ),
+        (
+            Transfer {
+                version: 2,
+                sender,
+                asset: Some(asset),
+                fee_asset: None,
+                timestamp: TIMESTAMP,
+                amount: 5,
+                fee: 1_000_000,
+                recipient: Recipient::Alias(alias()),
+                attachment: vec![],
+            }
+            .into(),
+            107,
+            "Gddv1vGYdPqnRP7PeRF6x4whbzxVHv7rZyVexXEADu9t",
+        ),
+        (
+            issue(1).into(),
+            80,
+            "J1gGjwg8YAqH8ctUK74X2Agn2ieQ8PjRLWqs6RZ6cgUV",
+        ),
+        (
+            issue(2).into(),
+            83,
+            "EHndgXFuy3Rn6ujCkq3uAirWko2hTCNgN2bL56JFgus5",
+        ),
+        (
+            Reissue {
+                version: 1,
+                chain: ChainId::Mainnet,
+                sender,
+                asset,
+                quantity: 500,
+                reissuable: false,
+                fee: 100_000_000,
+                timestamp: TIMESTAMP,
+            }
+            .into(),
+            90,
+            "GLqux5Qu7MmZxnqUrrN8v11QsKaw38jyt4pGa7piB7yy",
+        ),
+        (
+            Burn {
+                version: 2,
+                chain: ChainId::Mainnet,
+                sender,
+                asset,
+                quantity: 500,
+                fee: 1_000_000,
+                timestamp: TIMESTAMP,
+            }
+            .into(),
+            91,
+            "5XA8PPBWkhx4izAFDd6g4SH9m26XMG7ZG6x7Cp25hNmW",
+        ),
+        (
+            lease(1).into(),
+            83,
+            "89ygCVcgYYVBmJMTwQErRqcF6123qEWmdaia4uvpEsov",
+        ),
+        (
+            lease(2).into(),
+            85,
+            "BJkfPVzjV5VBSyZE7q7E5GHBKfNuZyfmFPVJfo7s4YtQ",
+        ),
+        (
+            cancel(1).into(),
+            81,
+            "H61y3MLgDHWU4th3H9HEvAxQcsFt3L7fySgLVvucrdti",
+        ),
+        (
+            cancel(2).into(),
+            83,
+            "7E4V78JcRAtQDvpvKMfkuvimDrfYteCroQ8VBC3GiFGM",
+        ),
+        (
+            CreateAlias {
+                version: 1,
+                sender,
+                alias: alias(),
+                fee: 1_000_000,
+                timestamp: TIMESTAMP,
+            }
+            .into(),
+            64,
+            "BqN61U6NZ6K2b8AC8xFqtWZHncB4KY9fy1dec8pbJoyB",
+        ),
+        (
+            CreateAlias {
+                version: 2,
+                sender,
+                alias: alias(),
+                fee: 1_000_000,
+                timestamp: TIMESTAMP,
+            }
+            .into(),
+            65,
+            "BqN61U6NZ6K2b8AC8xFqtWZHncB4KY9fy1dec8pbJoyB",
+        ),
+        (
+            MassTransfer {
+                version: 1,
+                sender,
+                asset: None,
+                transfers: vec![(address(), 1), (Recipient::Alias(alias()), 2)],
+                timestamp: TIMESTAMP,
+                fee: 2_000_000,
+                attachment: b"pay".to_vec(),
+            }
+            .into(),
+            113,
+            "5a3GBYchc6gcPXJaoNYs8hzf8dMCgdskU3RiokLeFJDN",
+        ),
+        (
+            Data {
+                version: 1,
+                sender,
+                entries: vec![
+                    DataEntry {
+                        key: "int".into(),
+                        value: DataValue::Integer(-5),
+                    },
+                    DataEntry {
+                        key: "bool".into(),
+                        value: DataValue::Boolean(true),
+                    },
+                    DataEntry {
+                        key: "bin".into(),
+                        value: DataValue::Binary(vec![0, 255]),
+                    },
+                    DataEntry {
+                        key: "str".into(),
+                        value: DataValue::String("é".into()),
+                    },
+                ],
+                timestamp: TIMESTAMP,
+                fee: 1_000_000,
+            }
+            .into(),
+            94,
+            "2B6rLmsDSSLsr7GCAXnCaBWqzfdWREB61T42uCnK1zWF",
+        ),
+    ]
+}
+
+#[test]
+fn transfer_layout() {
+    use crate::hex;
+
+    let (transfer, _, _) = transactions().remove(0);
+    let body = "04\
+        a4e09292b651c278b9772c569f5fa9bb13d906b46ab68c9df9dc2b4409f8a209\
+        0000\
+        000001802ba9f400\
+        0000000005f5e100\
+        00000000000f4240\
+        01308d4fca53ee5fc62a1196557e909108412ffab257b05b0249\
+        00054c756e6573";
+
+    assert_eq!(Ok(hex(body)), transfer.body_bytes());
+    assert_eq!(4, transfer.type_id());
+    assert_eq!(1, transfer.version());
+}
+
+#[test]
+fn known_ids_round_trip() {
+    use ed25519_axolotl::encoding::base58;
+
+    for (transaction, len, id) in transactions() {
+        let body = transaction.body_bytes().unwrap();
+        assert_eq!(len, body.len());
+        assert_eq!(id, base58::encode(&transaction.id().unwrap()));
+        assert_eq!(
+            Ok(transaction.clone()),
+            Transaction::from_body_bytes(&body, transaction.version())
+        );
+    }
+}
+
+#[test]
+fn signatures_verify() {
+    use crate::sevens;
+    use ed25519_axolotl::Error;
+
+    for (transaction, _, _) in transactions() {
+        let signed = transaction.sign_with_rng(&keys(), &mut sevens()).unwrap();
+        assert_eq!(1, signed.proofs.len());
+        assert_eq!(Ok(()), signed.verify());
+        assert_eq!(signed.transaction.id(), signed.id());
+
+        let mut forged = signed.clone();
+        forged.transaction = transactions()
+            .into_iter()
+            .map(|(transaction, _, _)| transaction)
+            .find(|other| other.id() != signed.id())
+            .unwrap();
+        assert_eq!(Err(Error::InvalidSignature), forged.verify());
+
+        let mut bare = signed;
+        bare.proofs.clear();
+        assert_eq!(Err(Error::InvalidSignature), bare.verify());
+    }
+}
+
+#[test]
+fn rejects_other_signers() {
+    use crate::sevens;
+    use ed25519_axolotl::Error;
+
+    let (transaction, _, _) = transactions().remove(0);
+    let other = KeyPair::from_seed(&[2; 32]);
+    assert_eq!(
+        Some(Error::SignerMismatch),
+        transaction.sign_with_rng(&other, &mut sevens()).err()
+    );
+}
+
+#[test]
+fn rejects_unsupported_versions() {
+    use ed25519_axolotl::Error;
+
+    let (transaction, _, _) = transactions().remove(0);
+    let Transaction::Transfer(mut transfer) = transaction else {
+        unreachable!()
+    };
+    transfer.version = 3;
+    assert_eq!(
+        Err(Error::UnsupportedTransaction {
+            type_id: 4,
+            version: 3
+        }),
+        Transaction::from(transfer).body_bytes()
+    );
+
+    let (mass, _, _) = transactions().remove(12);
+    let body = mass.body_bytes().unwrap();
+    assert_eq!(
+        Err(Error::UnsupportedTransaction {
+            type_id: 11,
+            version: 2
+        }),
+        Transaction::from_body_bytes(&body, 2)
+    );
+    assert_eq!(
+        Err(Error::UnsupportedTransaction {
+            type_id: 7,
+            version: 1
+        }),
+        Transaction::from_body_bytes(&[7; 40], 1)
+    );
+}
+
+#[test]
+fn rejects_malformed_bodies() {
+    use ed25519_axolotl::Error;
+
+    for (transaction, _, _) in transactions() {
+        let body = transaction.body_bytes().unwrap();
+        let version = transaction.version();
+
+        let mut long = body.clone();
+        long.push(0);
+        assert_eq!(
+            Err(Error::InvalidEncoding),
+            Transaction::from_body_bytes(&long, version)
+        );
+        assert!(Transaction::from_body_bytes(&body[..body.len() - 1], version).is_err());
+    }
+    assert_eq!(
+        Err(Error::InvalidEncoding),
+        Transaction::from_body_bytes(&[], 1)
+    );
+
+    // A count of 65535 data entries with none following.
+    let mut data = vec![12, 1];
+    data.extend_from_slice(keys().pubk.as_bytes());
+    data.extend_from_slice(&[0xff, 0xff]);
+    assert_eq!(
+        Err(Error::InvalidEncoding),
+        Transaction::from_body_bytes(&data, 1)
+    );
+}
+
+#[test]
+fn rejects_oversized_fields() {
+    use ed25519_axolotl::Error;
+
+    let (transaction, _, _) = transactions().remove(0);
+    let Transaction::Transfer(mut transfer) = transaction else {
+        unreachable!()
+    };
+    transfer.attachment = vec![0; 65_536];
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 65_535,
+            actual: 65_536
+        }),
+        Transaction::from(transfer).body_bytes()
+    );
+}
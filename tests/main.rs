@@ -1,2 +1,54 @@
 mod crypto;
+mod encoding;
+mod hash;
+mod hd;
+mod lunes;
+mod mnemonic;
+mod protocol;
+mod timing;
 mod utils;
+
+use ed25519_axolotl::utils::random::{CryptoRng, RngCore};
+
+/// Decode the hexadecimal strings of test vectors
+pub fn hex(text: &str) -> Vec<u8> {
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Generator repeating fixed bytes, to pin the random inputs of known answers
+pub struct Fixed(pub [u8; 64]);
+
+impl RngCore for Fixed {
+    fn next_u32(&mut self) -> u32 {
+        u32::from_le_bytes([self.0[0], self.0[1], self.0[2], self.0[3]])
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.next_u32() as u64
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for (x, y) in dest.iter_mut().zip(self.0.iter().cycle()) {
+            *x = *y;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for Fixed {}
+
+/// Fixed generator of the bytes `i * 7 % 256`
+pub fn sevens() -> Fixed {
+    let mut random = [0u8; 64];
+    for (i, x) in random.iter_mut().enumerate() {
+        *x = (i * 7 % 256) as u8;
+    }
+    Fixed(random)
+}
@@ -0,0 +1,2 @@
+mod bip39;
+mod phrase;
@@ -0,0 +1,94 @@
+#[test]
+fn generate_with_pinned_randomness() {
+    use crate::sevens;
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+
+    // Words of the little endian 11 bit indices of the bytes `i * 7 % 256`.
+    let phrase = SeedPhrase::generate_with(&mut sevens());
+    assert_eq!(
+        "theme peace glory census train potato hedgehog clump unlock quit \
+         immense crew vital replace joy",
+        phrase.expose_phrase()
+    );
+    assert_eq!(SeedPhrase::WORDS, phrase.words().count());
+    assert_eq!(Ok(phrase.clone()), phrase.expose_phrase().parse());
+}
+
+#[test]
+fn generated_phrases_differ() {
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+
+    let a = SeedPhrase::generate();
+    let b = SeedPhrase::generate();
+    assert_ne!(a, b);
+    assert_ne!(a.to_key_pair().pubk, b.to_key_pair().pubk);
+    assert!(a
+        .words()
+        .all(|word| SeedPhrase::from_phrase(&[word; 15].join(" ")).is_ok()));
+}
+
+#[test]
+fn validates_words_and_count() {
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+    use ed25519_axolotl::Error;
+
+    let words = ["abandon"; 15];
+    assert!(SeedPhrase::from_phrase(&words.join(" ")).is_ok());
+    assert!(SeedPhrase::from_phrase(&["zoo"; 15].join(" ")).is_ok());
+
+    for (index, unknown) in [(0, "Abandon"), (7, "aband"), (14, "lunes"), (3, "abandonn")] {
+        let mut typed = words;
+        typed[index] = unknown;
+        assert_eq!(
+            Some(Error::UnknownWord { index }),
+            SeedPhrase::from_phrase(&typed.join(" ")).err()
+        );
+    }
+
+    for count in [0, 1, 12, 14, 16, 24] {
+        assert_eq!(
+            Some(Error::InvalidWordCount {
+                expected: 15,
+                actual: count
+            }),
+            SeedPhrase::from_phrase(&vec!["zoo"; count].join(" ")).err()
+        );
+    }
+}
+
+#[test]
+fn normalizes_whitespace() {
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+
+    let phrase: SeedPhrase = "\t zoo zoo  zoo\nzoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo \n"
+        .parse()
+        .unwrap();
+    assert_eq!(["zoo"; 15].join(" "), phrase.expose_phrase());
+}
+
+#[test]
+fn derives_the_wallet_account() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+
+    const PHRASE: &str = "uncle push human bus echo drastic garden joke sand warfare \
+                          sentence fossil title color combine";
+
+    let phrase = SeedPhrase::from_phrase(PHRASE).unwrap();
+    assert_eq!(
+        "4KxUVD9NtyRJjU3BCvPgJSttoJX7cb3DMdDTNucLN121",
+        phrase.to_key_pair().pubk.to_base58()
+    );
+    assert_eq!(
+        KeyPair::from_lunes_seed_phrase(PHRASE, 0).prvk,
+        phrase.to_key_pair().prvk
+    );
+}
+
+#[test]
+fn never_printed() {
+    use ed25519_axolotl::mnemonic::SeedPhrase;
+
+    let phrase = SeedPhrase::from_phrase(&["zoo"; 15].join(" ")).unwrap();
+    assert_eq!("SeedPhrase(<redacted>)", format!("{phrase:?}"));
+}
@@ -0,0 +1,2 @@
+mod ratchet;
+mod x3dh;
@@ -0,0 +1,248 @@
+use ed25519_axolotl::protocol::ratchet::{Options, Session};
+
+/// Sessions of an initiator and a responder sharing the same secret
+fn sessions(options: Options) -> (Session, Session) {
+    use ed25519_axolotl::crypto::agreement::SharedSecret;
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    let bob = KeyPair::generate();
+    let alice = Session::initiate(&SharedSecret::from([7; 32]), &bob.pubk, options).unwrap();
+    let bob = Session::respond(&SharedSecret::from([7; 32]), bob, options);
+    (alice, bob)
+}
+
+fn encrypted_headers() -> Options {
+    Options {
+        header_encryption: true,
+        ..Options::default()
+    }
+}
+
+#[test]
+fn conversation() {
+    for options in [Options::default(), encrypted_headers()] {
+        let (mut alice, mut bob) = sessions(options);
+
+        for round in 0..4u8 {
+            for i in 0..3u8 {
+                let message = alice.encrypt(&[round, i], b"AD").unwrap();
+                assert_eq!(Ok(vec![round, i]), bob.decrypt(&message, b"AD"));
+            }
+            let message = bob.encrypt(&[0xff, round], b"AD").unwrap();
+            assert_eq!(Ok(vec![0xff, round]), alice.decrypt(&message, b"AD"));
+        }
+    }
+}
+
+#[test]
+fn out_of_order() {
+    for options in [Options::default(), encrypted_headers()] {
+        let (mut alice, mut bob) = sessions(options);
+
+        let first: Vec<_> = (0..4u8)
+            .map(|i| alice.encrypt(&[i], b"").unwrap())
+            .collect();
+        assert_eq!(Ok(vec![3]), bob.decrypt(&first[3], b""));
+        assert_eq!(Ok(vec![1]), bob.decrypt(&first[1], b""));
+
+        // Reply, so the next messages of alice come from a new ratchet key.
+        let reply = bob.encrypt(b"reply", b"").unwrap();
+        assert_eq!(Ok(b"reply".to_vec()), alice.decrypt(&reply, b""));
+        let second: Vec<_> = (4..6u8)
+            .map(|i| alice.encrypt(&[i], b"").unwrap())
+            .collect();
+
+        assert_eq!(Ok(vec![5]), bob.decrypt(&second[1], b""));
+        assert_eq!(Ok(vec![0]), bob.decrypt(&first[0], b""));
+        assert_eq!(Ok(vec![4]), bob.decrypt(&second[0], b""));
+        assert_eq!(Ok(vec![2]), bob.decrypt(&first[2], b""));
+    }
+}
+
+#[test]
+fn rejected() {
+    use ed25519_axolotl::protocol::ratchet::Message;
+    use ed25519_axolotl::Error;
+
+    for options in [Options::default(), encrypted_headers()] {
+        let (mut alice, mut bob) = sessions(options);
+        let message = alice.encrypt(b"Lunes", b"AD").unwrap();
+
+        let mut forged = message.clone();
+        forged.ciphertext[0] ^= 1;
+        assert_eq!(Err(Error::DecryptionFailed), bob.decrypt(&forged, b"AD"));
+        assert_eq!(Err(Error::DecryptionFailed), bob.decrypt(&message, b"DA"));
+
+        let mut forged = message.clone();
+        forged.header[35] ^= 1;
+        assert!(bob.decrypt(&forged, b"AD").is_err());
+
+        let forged = Message {
+            header: message.header.clone(),
+            ciphertext: vec![0; 8],
+        };
+        assert_eq!(Err(Error::DecryptionFailed), bob.decrypt(&forged, b"AD"));
+
+        // Failures leave the session as it was.
+        assert_eq!(Ok(b"Lunes".to_vec()), bob.decrypt(&message, b"AD"));
+        assert_eq!(Err(Error::DecryptionFailed), bob.decrypt(&message, b"AD"));
+    }
+}
+
+#[test]
+fn skip_limits() {
+    use ed25519_axolotl::Error;
+
+    for header_encryption in [false, true] {
+        let options = Options {
+            header_encryption,
+            max_skip: 3,
+            max_stored: 2,
+        };
+        let (mut alice, mut bob) = sessions(options);
+
+        let messages: Vec<_> = (0..8u8)
+            .map(|i| alice.encrypt(&[i], b"").unwrap())
+            .collect();
+        assert_eq!(
+            Err(Error::TooManySkipped { limit: 3 }),
+            bob.decrypt(&messages[4], b"")
+        );
+        assert_eq!(Ok(vec![3]), bob.decrypt(&messages[3], b""));
+
+        // Only the two most recent skipped keys are kept.
+        assert_eq!(Err(Error::DecryptionFailed), bob.decrypt(&messages[0], b""));
+        assert_eq!(Ok(vec![1]), bob.decrypt(&messages[1], b""));
+        assert_eq!(Ok(vec![2]), bob.decrypt(&messages[2], b""));
+        assert_eq!(Ok(vec![7]), bob.decrypt(&messages[7], b""));
+    }
+}
+
+#[test]
+fn responder_sends_after_receiving() {
+    use ed25519_axolotl::Error;
+
+    for options in [Options::default(), encrypted_headers()] {
+        let (mut alice, mut bob) = sessions(options);
+
+        assert_eq!(Some(Error::NoSendingChain), bob.encrypt(b"", b"").err());
+
+        let message = alice.encrypt(b"", b"").unwrap();
+        bob.decrypt(&message, b"").unwrap();
+        assert!(bob.encrypt(b"", b"").is_ok());
+    }
+}
+
+#[test]
+fn known_answers() {
+    use crate::hex;
+    use ed25519_axolotl::crypto::agreement::SharedSecret;
+    use ed25519_axolotl::crypto::keys::KeyPair;
+
+    // Computed with the X25519, HMAC and ChaCha20 of Python, ratchet key from `sevens`.
+    let bob = KeyPair::from_seed(&[2; 32]);
+    let vectors = [
+        (
+            Options::default(),
+            "177d8f231f6cab89ea03f90d2f2d53dd9ba13c78947d5d85b83d494514e0b7140000000000000000",
+            "5ef4fd960328cc754707ea3d134fa54b7bb4462c629d9e034d2e9e0c65810eaaca9adab8c6",
+        ),
+        (
+            encrypted_headers(),
+            "ee3ff50fa5d6773a7f31f770092cc070e2a8e58a7437a4dff683b210c78d26eb\
+             2db5ea77cadd59b0c2b239f70a1ac1a0595386adcb5d692f9e6ce3e02a29545ab90c9800f204f1a1",
+            "5ef4fd960395eda5ee962e0cb4c1ca5c7040d8830d325b2d9dc07cbcdadd0ef3a341002923",
+        ),
+    ];
+
+    for (options, header, ciphertext) in vectors {
+        let secret = SharedSecret::from([7; 32]);
+        let mut alice =
+            Session::initiate_with(&secret, &bob.pubk, options, &mut crate::sevens()).unwrap();
+        let message = alice.encrypt(b"Lunes", b"AD").unwrap();
+
+        assert_eq!(hex(header), message.header);
+        assert_eq!(hex(ciphertext), message.ciphertext);
+    }
+}
+
+#[test]
+fn encrypted_headers_hide_keys() {
+    let (mut alice, _) = sessions(Options::default());
+    let plain = alice.encrypt(b"", b"").unwrap();
+
+    let (mut alice, _) = sessions(encrypted_headers());
+    let first = alice.encrypt(b"", b"").unwrap();
+    let second = alice.encrypt(b"", b"").unwrap();
+
+    assert_eq!(40, plain.header.len());
+    assert_eq!(72, first.header.len());
+    assert_ne!(first.header[32..64], second.header[32..64]);
+}
+
+#[test]
+fn message_bytes() {
+    use ed25519_axolotl::protocol::ratchet::Message;
+    use ed25519_axolotl::Error;
+
+    let (mut alice, _) = sessions(Options::default());
+    let message = alice.encrypt(b"Lunes", b"").unwrap();
+    let bytes = message.to_bytes();
+
+    assert_eq!(40, bytes[0]);
+    assert_eq!(Ok(message), Message::try_from(&bytes[..]));
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 73,
+            actual: 72
+        }),
+        Message::from_bytes(&bytes[..72])
+    );
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 1,
+            actual: 0
+        }),
+        Message::from_bytes(&[])
+    );
+}
+
+#[test]
+fn restored_sessions() {
+    use ed25519_axolotl::Error;
+
+    for options in [Options::default(), encrypted_headers()] {
+        let (mut alice, mut bob) = sessions(options);
+
+        let skipped = alice.encrypt(b"skipped", b"").unwrap();
+        let message = alice.encrypt(b"Lunes", b"").unwrap();
+        bob.decrypt(&message, b"").unwrap();
+
+        let mut alice = Session::from_bytes(&alice.to_bytes()).unwrap();
+        let bytes = bob.to_bytes();
+        let mut bob = Session::from_bytes(&bytes).unwrap();
+        assert_eq!(bytes, bob.to_bytes());
+        assert_eq!(options, bob.options());
+
+        assert_eq!(Ok(b"skipped".to_vec()), bob.decrypt(&skipped, b""));
+        let reply = bob.encrypt(b"reply", b"").unwrap();
+        assert_eq!(Ok(b"reply".to_vec()), alice.decrypt(&reply, b""));
+
+        let mut bad = bytes.clone();
+        bad[0] = 2;
+        assert_eq!(
+            Some(Error::InvalidEncoding),
+            Session::from_bytes(&bad).err()
+        );
+        assert!(Session::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        bad = bytes.clone();
+        bad.push(0);
+        assert_eq!(
+            Some(Error::InvalidLength {
+                expected: bytes.len(),
+                actual: bytes.len() + 1
+            }),
+            Session::from_bytes(&bad).err()
+        );
+    }
+}
@@ -0,0 +1,177 @@
+#[test]
+fn agree_with_one_time_prekey() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::protocol::x3dh::{
+        initiate, respond, OneTimePreKey, PreKeyBundle, SignedPreKey,
+    };
+
+    let alice = KeyPair::generate();
+    let bob = KeyPair::generate();
+    let signed_prekey = SignedPreKey::generate(&bob, 1);
+    let one_time_prekey = OneTimePreKey::generate(2);
+    let bundle = PreKeyBundle::new(&bob, &signed_prekey, Some(&one_time_prekey));
+
+    let (message, sent) = initiate(&alice, &bundle).unwrap();
+    assert_eq!(Some(2), message.one_time_prekey_id);
+
+    let received = respond(&bob, &signed_prekey, Some(&one_time_prekey), &message).unwrap();
+    assert_eq!(sent.secret.as_bytes(), received.secret.as_bytes());
+    assert_eq!(sent.associated_data, received.associated_data);
+    assert_eq!(66, sent.associated_data.len());
+    assert_eq!(alice.pubk.as_bytes(), &sent.associated_data[1..33]);
+}
+
+#[test]
+fn agree_without_one_time_prekey() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::protocol::x3dh::{initiate, respond, PreKeyBundle, SignedPreKey};
+
+    let alice = KeyPair::generate();
+    let bob = KeyPair::generate();
+    let signed_prekey = SignedPreKey::generate(&bob, 1);
+    let bundle = PreKeyBundle::new(&bob, &signed_prekey, None);
+
+    let (message, sent) = initiate(&alice, &bundle).unwrap();
+    let received = respond(&bob, &signed_prekey, None, &message).unwrap();
+
+    assert_eq!(None, message.one_time_prekey_id);
+    assert_eq!(sent.secret.as_bytes(), received.secret.as_bytes());
+}
+
+#[test]
+fn known_answers() {
+    use crate::hex;
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::protocol::x3dh::{
+        initiate_with, OneTimePreKey, PreKeyBundle, SignedPreKey,
+    };
+
+    // Computed with the X25519 and HMAC of Python, ephemeral key from `sevens`.
+    let alice = KeyPair::from_seed(&[1; 32]);
+    let bob = KeyPair::from_seed(&[2; 32]);
+    let signed_prekey = SignedPreKey::new(&bob, 1, KeyPair::from_seed(&[3; 32]));
+    let one_time_prekey = OneTimePreKey {
+        id: 2,
+        keys: KeyPair::from_seed(&[4; 32]),
+    };
+
+    let bundle = PreKeyBundle::new(&bob, &signed_prekey, None);
+    let (message, agreement) = initiate_with(&alice, &bundle, &mut crate::sevens()).unwrap();
+    assert_eq!(
+        hex("177d8f231f6cab89ea03f90d2f2d53dd9ba13c78947d5d85b83d494514e0b714"),
+        message.ephemeral_key.as_bytes()
+    );
+    assert_eq!(
+        hex("69293294b867cf17dccccddd797d4ed91a8235f91a5796f87c0b57d8e6b43398"),
+        agreement.secret.as_bytes()
+    );
+
+    let bundle = PreKeyBundle::new(&bob, &signed_prekey, Some(&one_time_prekey));
+    let (_, agreement) = initiate_with(&alice, &bundle, &mut crate::sevens()).unwrap();
+    assert_eq!(
+        hex("79ad85fa2d5750eae9a8c28873c728e5995c738a5e15140de379a7538525f665"),
+        agreement.secret.as_bytes()
+    );
+}
+
+#[test]
+fn bundle_bytes() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::protocol::x3dh::{OneTimePreKey, PreKeyBundle, SignedPreKey};
+    use ed25519_axolotl::Error;
+
+    let bob = KeyPair::generate();
+    let signed_prekey = SignedPreKey::generate(&bob, 0x01020304);
+    let one_time_prekey = OneTimePreKey::generate(9);
+
+    let bundle = PreKeyBundle::new(&bob, &signed_prekey, None);
+    let bytes = bundle.to_bytes();
+    assert_eq!(132, bytes.len());
+    assert_eq!([1, 2, 3, 4], bytes[32..36]);
+    assert_eq!(Ok(bundle), PreKeyBundle::try_from(&bytes[..]));
+
+    let bundle = PreKeyBundle::new(&bob, &signed_prekey, Some(&one_time_prekey));
+    let bytes = bundle.to_bytes();
+    assert_eq!(168, bytes.len());
+    assert_eq!(Ok(bundle.clone()), PreKeyBundle::from_bytes(&bytes));
+    assert_eq!(Ok(()), bundle.verify());
+
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 132,
+            actual: 131
+        }),
+        PreKeyBundle::from_bytes(&bytes[..131])
+    );
+    assert_eq!(
+        Err(Error::InvalidLength {
+            expected: 168,
+            actual: 150
+        }),
+        PreKeyBundle::from_bytes(&bytes[..150])
+    );
+}
+
+#[test]
+fn forged_bundle() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::protocol::x3dh::{initiate, PreKeyBundle, SignedPreKey};
+    use ed25519_axolotl::Error;
+
+    let alice = KeyPair::generate();
+    let bob = KeyPair::generate();
+    let mallory = KeyPair::generate();
+
+    // Prekey signed by someone else than the bundled identity.
+    let signed_prekey = SignedPreKey::generate(&mallory, 1);
+    let mut bundle = PreKeyBundle::new(&bob, &signed_prekey, None);
+    assert_eq!(Err(Error::InvalidSignature), bundle.verify());
+    assert_eq!(
+        Some(Error::InvalidSignature),
+        initiate(&alice, &bundle).err()
+    );
+
+    // Prekey swapped after signing.
+    bundle = PreKeyBundle::new(&bob, &SignedPreKey::generate(&bob, 1), None);
+    bundle.signed_prekey = mallory.pubk;
+    assert_eq!(
+        Some(Error::InvalidSignature),
+        initiate(&alice, &bundle).err()
+    );
+}
+
+#[test]
+fn unknown_prekeys() {
+    use ed25519_axolotl::crypto::keys::KeyPair;
+    use ed25519_axolotl::protocol::x3dh::{
+        initiate, respond, OneTimePreKey, PreKeyBundle, SignedPreKey,
+    };
+    use ed25519_axolotl::Error;
+
+    let alice = KeyPair::generate();
+    let bob = KeyPair::generate();
+    let signed_prekey = SignedPreKey::generate(&bob, 1);
+    let one_time_prekey = OneTimePreKey::generate(2);
+    let bundle = PreKeyBundle::new(&bob, &signed_prekey, Some(&one_time_prekey));
+    let (message, _) = initiate(&alice, &bundle).unwrap();
+
+    let other_signed_prekey = SignedPreKey::generate(&bob, 3);
+    assert_eq!(
+        Some(Error::UnknownPreKey { id: 1 }),
+        respond(&bob, &other_signed_prekey, Some(&one_time_prekey), &message).err()
+    );
+    assert_eq!(
+        Some(Error::UnknownPreKey { id: 2 }),
+        respond(&bob, &signed_prekey, None, &message).err()
+    );
+    assert_eq!(
+        Some(Error::UnknownPreKey { id: 2 }),
+        respond(
+            &bob,
+            &signed_prekey,
+            Some(&OneTimePreKey::generate(4)),
+            &message
+        )
+        .err()
+    );
+}
@@ -0,0 +1,156 @@
+//! Dudect style timing tests, <https://eprint.iacr.org/2016/1123>.
+//!
+//! A function is timed over one fixed input and over random inputs, randomly
+//! interleaved, and Welch's t-test tells whether both classes are told apart.
+//! The measurements are noisy in debug builds, so the checks of the library
+//! are ignored by default and run with `cargo test --release -- --ignored timing`.
+use ed25519_axolotl::crypto::keys::{KeyPair, PublicKey, SecretKey};
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Above this |t| the classes are considered distinguishable, as in dudect
+const THRESHOLD: f64 = 10.0;
+
+/// Measurements per library check
+const SAMPLES: usize = 20_000;
+
+/// Xorshift generator, so classes and random inputs are reproducible offline
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bytes(&mut self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for chunk in bytes.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next().to_le_bytes());
+        }
+        bytes
+    }
+}
+
+/// Welch's t statistic of two classes, with running means and variances
+#[derive(Default)]
+struct Welch {
+    n: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl Welch {
+    fn push(&mut self, class: usize, x: f64) {
+        self.n[class] += 1.0;
+        let delta = x - self.mean[class];
+        self.mean[class] += delta / self.n[class];
+        self.m2[class] += delta * (x - self.mean[class]);
+    }
+
+    fn t(&self) -> f64 {
+        let v0 = self.m2[0] / (self.n[0] - 1.0);
+        let v1 = self.m2[1] / (self.n[1] - 1.0);
+        (self.mean[0] - self.mean[1]) / (v0 / self.n[0] + v1 / self.n[1]).sqrt()
+    }
+}
+
+/// Largest |t| between the fixed class and the random class, over every
+/// measurement and over the ones below increasing percentiles, which crops
+/// the interruptions of the measurements.
+fn leakage(samples: usize, fixed: [u8; 32], run: impl Fn(&[u8; 32])) -> f64 {
+    let mut rng = Xorshift(0x2545f4914f6cdd1d);
+    let classes: Vec<usize> = (0..samples).map(|_| (rng.next() & 1) as usize).collect();
+    let inputs: Vec<[u8; 32]> = classes
+        .iter()
+        .map(|class| if *class == 0 { fixed } else { rng.bytes() })
+        .collect();
+
+    for input in inputs.iter().take(samples / 100) {
+        run(black_box(input));
+    }
+
+    let times: Vec<f64> = inputs
+        .iter()
+        .map(|input| {
+            let start = Instant::now();
+            run(black_box(input));
+            start.elapsed().as_nanos() as f64
+        })
+        .collect();
+
+    let mut sorted = times.clone();
+    sorted.sort_by(f64::total_cmp);
+    let mut crops = vec![f64::INFINITY];
+    for i in 1..=10 {
+        let p = 1.0 - 0.5f64.powi(i);
+        crops.push(sorted[(p * samples as f64) as usize]);
+    }
+
+    crops
+        .iter()
+        .map(|crop| {
+            let mut welch = Welch::default();
+            for (class, time) in classes.iter().zip(&times) {
+                if time < crop {
+                    welch.push(*class, *time);
+                }
+            }
+            welch.t().abs()
+        })
+        .filter(|t| t.is_finite())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn flags_early_exit() {
+    // Comparison returning at the first differing byte, slowed down per byte.
+    let compare = |x: &[u8; 32]| {
+        for b in x {
+            if *b != 0 {
+                return;
+            }
+            for i in 0..1000 {
+                black_box(i);
+            }
+        }
+    };
+
+    assert!(leakage(2_000, [0; 32], compare) > THRESHOLD);
+}
+
+#[test]
+#[ignore]
+fn from_seed() {
+    let t = leakage(SAMPLES, [0; 32], |seed| {
+        black_box(KeyPair::from_seed(seed));
+    });
+
+    assert!(t < THRESHOLD, "|t| = {t}");
+}
+
+#[test]
+#[ignore]
+fn sign_deterministic() {
+    let t = leakage(SAMPLES, [0; 32], |secret| {
+        black_box(SecretKey::from_bytes(*secret).sign_deterministic(b"Lunes"));
+    });
+
+    assert!(t < THRESHOLD, "|t| = {t}");
+}
+
+#[test]
+#[ignore]
+fn diffie_hellman() {
+    let mut u = [0u8; 32];
+    u[0] = 9;
+    let public_key = PublicKey::from_bytes(u);
+
+    let t = leakage(SAMPLES, [0; 32], |secret| {
+        let _ = black_box(SecretKey::from_bytes(*secret).diffie_hellman(&public_key));
+    });
+
+    assert!(t < THRESHOLD, "|t| = {t}");
+}
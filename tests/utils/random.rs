@@ -4,8 +4,40 @@ fn verify_bytes() {
 
     let x = [1; 32];
     assert_eq!(x.len(), random_bytes(32).len());
-    assert_eq!(
-        true,
-        random_bytes(10000).iter().all(|x| x.ge(&&0) && x.le(&&255))
-    );
+    assert!(random_bytes(10000).iter().all(|x| x.ge(&0) && x.le(&255)));
+}
+
+#[test]
+fn full_byte_range() {
+    use ed25519_axolotl::utils::random::{fill_bytes, random_bytes};
+
+    // All the 256 values show up, 255 included, missing one has a 2^-48 chance
+    let mut seen = [false; 256];
+    random_bytes(10000)
+        .iter()
+        .for_each(|x| seen[*x as usize] = true);
+    assert!(seen.iter().all(|x| *x));
+
+    let mut bytes = [0u8; 10000];
+    fill_bytes(&mut bytes);
+    let mut seen = [false; 256];
+    bytes.iter().for_each(|x| seen[*x as usize] = true);
+    assert!(seen.iter().all(|x| *x));
+}
+
+#[test]
+fn seeded_generator() {
+    use ed25519_axolotl::utils::random::{fill_bytes, fill_bytes_with};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut a = [0u8; 64];
+    let mut b = [0u8; 64];
+    fill_bytes_with(&mut StdRng::seed_from_u64(1), &mut a);
+    fill_bytes_with(&mut StdRng::seed_from_u64(1), &mut b);
+    assert_eq!(a, b);
+
+    fill_bytes(&mut a);
+    fill_bytes(&mut b);
+    assert_ne!(a, b);
 }